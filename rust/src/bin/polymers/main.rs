@@ -0,0 +1,269 @@
+mod test;
+
+use std::collections::HashMap;
+use polymers::physics::
+{
+    AVOGADRO_NUMBER,
+    BOLTZMANN_CONSTANT
+};
+use polymers::physics::single_chain::
+{
+    curve::Curve,
+    ideal::Ideal,
+    fjc::FJC,
+    efjc::EFJC,
+    swfjc::SWFJC
+};
+
+static USAGE: &str = "\
+Tabulates a thermodynamic function of a single-chain model and writes it to stdout.
+
+Usage: polymers --model MODEL --ensemble ENSEMBLE --function FUNCTION --number-of-links N --minimum MIN --maximum MAX [OPTIONS]
+
+Models and ensembles:
+    ideal   isometric, isotensional
+    fjc     isometric, isotensional, modified_canonical
+    efjc    isotensional
+    swfjc   isotensional
+
+Options:
+    --approximation A          exact (default), legendre, asymptotic, asymptotic_legendre,
+                               reduced, reduced_legendre, alternative, alternative_legendre,
+                               strong_potential, weak_potential
+    --function F               force, end_to_end_length, helmholtz_free_energy,
+                               relative_helmholtz_free_energy, gibbs_free_energy,
+                               relative_gibbs_free_energy
+    --number-of-links N        number of links in the chain
+    --link-length L            length of each link in length units (default 1 nm)
+    --hinge-mass M             mass of each hinge in kg/mol (default 1)
+    --link-stiffness K         stiffness of each link in force units per length unit for efjc
+    --well-width W             width of the well in length units for swfjc
+    --potential-stiffness K    stiffness of the potential in force units per length unit for
+                               modified_canonical
+    --temperature T            temperature in K (default 300)
+    --minimum X, --maximum X   range of the end-to-end length, potential distance, or force
+    --points P                 number of evenly-spaced points (default 100)
+    --format F                 csv (default), tsv, or json
+    --length-units U           nm (default), um, or angstrom
+    --force-units U            J/(mol*nm) (default), pN, or nN
+    --energy-units U           J/mol (default), kJ/mol, zJ, or kT
+";
+
+macro_rules! select
+{
+    ($function:expr, $model:expr, $temperature:expr, [$($name:ident),*]) =>
+    {
+        match $function
+        {
+            $(stringify!($name) => Some(Box::new(move |argument: &f64| $model.$name(argument, &$temperature)) as Box<dyn Fn(&f64) -> f64>),)*
+            _ => None
+        }
+    };
+    ($function:expr, $model:expr, $potential_stiffness:expr, $temperature:expr, [$($name:ident),*]) =>
+    {
+        match $function
+        {
+            $(stringify!($name) => Some(Box::new(move |argument: &f64| $model.$name(argument, &$potential_stiffness, &$temperature)) as Box<dyn Fn(&f64) -> f64>),)*
+            _ => None
+        }
+    };
+}
+
+fn main()
+{
+    match run(&std::env::args().skip(1).collect::<Vec<String>>())
+    {
+        Ok(output) => print!("{}", output),
+        Err(message) =>
+        {
+            eprintln!("error: {}", message);
+            std::process::exit(1)
+        }
+    }
+}
+
+fn run(arguments: &[String]) -> Result<String, String>
+{
+    if arguments.is_empty() || arguments.iter().any(|argument| argument == "--help" || argument == "-h")
+    {
+        return Ok(USAGE.to_string())
+    }
+    let options = parse(arguments)?;
+    let curve = tabulate(&options)?;
+    match options.get("format").map(|format| format.as_str()).unwrap_or("csv")
+    {
+        "csv" => Ok(curve.to_csv()),
+        "tsv" => Ok(curve.to_tsv()),
+        "json" => Ok(curve.to_json() + "\n"),
+        format => Err(format!("unknown format {}", format))
+    }
+}
+
+fn parse(arguments: &[String]) -> Result<HashMap<String, String>, String>
+{
+    let mut options = HashMap::new();
+    let mut arguments = arguments.iter();
+    while let Some(argument) = arguments.next()
+    {
+        let name = argument.strip_prefix("--").ok_or(format!("expected an option but found {}", argument))?;
+        let value = arguments.next().ok_or(format!("missing value for --{}", name))?;
+        options.insert(name.replace('-', "_"), value.clone());
+    }
+    Ok(options)
+}
+
+fn required(options: &HashMap<String, String>, name: &str) -> Result<f64, String>
+{
+    options.get(name).ok_or(format!("missing --{}", name.replace('_', "-")))?.parse::<f64>().map_err(|_| format!("invalid value for --{}", name.replace('_', "-")))
+}
+
+fn optional(options: &HashMap<String, String>, name: &str, default: f64) -> Result<f64, String>
+{
+    match options.get(name)
+    {
+        Some(_) => required(options, name),
+        None => Ok(default)
+    }
+}
+
+fn length_scale(units: &str) -> Result<f64, String>
+{
+    match units
+    {
+        "nm" => Ok(1.0),
+        "um" => Ok(1e-3),
+        "angstrom" => Ok(1e1),
+        _ => Err(format!("unknown length units {}", units))
+    }
+}
+
+fn force_scale(units: &str) -> Result<f64, String>
+{
+    match units
+    {
+        "J/(mol*nm)" => Ok(1.0),
+        "pN" => Ok(1e21/AVOGADRO_NUMBER),
+        "nN" => Ok(1e18/AVOGADRO_NUMBER),
+        _ => Err(format!("unknown force units {}", units))
+    }
+}
+
+fn energy_scale(units: &str, temperature: &f64) -> Result<f64, String>
+{
+    match units
+    {
+        "J/mol" => Ok(1.0),
+        "kJ/mol" => Ok(1e-3),
+        "zJ" => Ok(1e21/AVOGADRO_NUMBER),
+        "kT" => Ok(1.0/BOLTZMANN_CONSTANT/temperature),
+        _ => Err(format!("unknown energy units {}", units))
+    }
+}
+
+fn tabulate(options: &HashMap<String, String>) -> Result<Curve, String>
+{
+    let model = options.get("model").ok_or("missing --model")?.as_str();
+    let ensemble = options.get("ensemble").ok_or("missing --ensemble")?.as_str();
+    let approximation = options.get("approximation").map(|approximation| approximation.as_str()).unwrap_or("exact");
+    let function = options.get("function").ok_or("missing --function")?.as_str();
+    let length_units = options.get("length_units").map(|units| units.as_str()).unwrap_or("nm");
+    let force_units = options.get("force_units").map(|units| units.as_str()).unwrap_or("J/(mol*nm)");
+    let energy_units = options.get("energy_units").map(|units| units.as_str()).unwrap_or("J/mol");
+    let temperature = optional(options, "temperature", 300.0)?;
+    let length_scale = length_scale(length_units)?;
+    let force_scale = force_scale(force_units)?;
+    let energy_scale = energy_scale(energy_units, &temperature)?;
+    let number_of_links = required(options, "number_of_links")?;
+    if number_of_links.fract() != 0.0 || !(0.0..=255.0).contains(&number_of_links)
+    {
+        return Err("invalid value for --number-of-links".to_string())
+    }
+    let number_of_links = number_of_links as u8;
+    let link_length = optional(options, "link_length", length_scale)?/length_scale;
+    let hinge_mass = optional(options, "hinge_mass", 1.0)?;
+    let evaluate = match (model, ensemble, approximation)
+    {
+        ("ideal", "isometric", "exact") =>
+        {
+            let model = Ideal::try_init(number_of_links, link_length, hinge_mass).map_err(|error| error.to_string())?;
+            select!(function, model.thermodynamics.isometric, temperature, [force, helmholtz_free_energy, relative_helmholtz_free_energy])
+        },
+        ("ideal", "isotensional", "exact") =>
+        {
+            let model = Ideal::try_init(number_of_links, link_length, hinge_mass).map_err(|error| error.to_string())?;
+            select!(function, model.thermodynamics.isotensional, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy])
+        },
+        ("fjc", _, _) =>
+        {
+            let model = FJC::try_init(number_of_links, link_length, hinge_mass).map_err(|error| error.to_string())?;
+            let thermodynamics = model.thermodynamics;
+            match (ensemble, approximation)
+            {
+                ("isometric", "exact") => select!(function, thermodynamics.isometric, temperature, [force, helmholtz_free_energy, relative_helmholtz_free_energy]),
+                ("isometric", "legendre") => select!(function, thermodynamics.isometric.legendre, temperature, [force, helmholtz_free_energy, relative_helmholtz_free_energy, gibbs_free_energy, relative_gibbs_free_energy]),
+                ("isotensional", "exact") => select!(function, thermodynamics.isotensional, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                ("isotensional", "legendre") => select!(function, thermodynamics.isotensional.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                ("modified_canonical", _) =>
+                {
+                    let potential_stiffness = required(options, "potential_stiffness")?*length_scale/force_scale;
+                    match approximation
+                    {
+                        "exact" => select!(function, thermodynamics.modified_canonical, potential_stiffness, temperature, [end_to_end_length, force, helmholtz_free_energy, relative_helmholtz_free_energy, gibbs_free_energy, relative_gibbs_free_energy]),
+                        "strong_potential" => select!(function, thermodynamics.modified_canonical.asymptotic.strong_potential, potential_stiffness, temperature, [force, helmholtz_free_energy, relative_helmholtz_free_energy]),
+                        "weak_potential" => select!(function, thermodynamics.modified_canonical.asymptotic.weak_potential, potential_stiffness, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                        _ => return Err(format!("unknown approximation {} of the modified_canonical ensemble of the fjc model", approximation))
+                    }
+                },
+                _ => return Err(format!("unknown approximation {} of the {} ensemble of the fjc model", approximation, ensemble))
+            }
+        },
+        ("efjc", "isotensional", _) =>
+        {
+            let link_stiffness = required(options, "link_stiffness")?*length_scale/force_scale;
+            let model = EFJC::try_init(number_of_links, link_length, hinge_mass, link_stiffness).map_err(|error| error.to_string())?;
+            let isotensional = model.thermodynamics.isotensional;
+            match approximation
+            {
+                "exact" => select!(function, isotensional, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                "legendre" => select!(function, isotensional.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                "asymptotic" => select!(function, isotensional.asymptotic, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                "asymptotic_legendre" => select!(function, isotensional.asymptotic.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                "reduced" => select!(function, isotensional.asymptotic.reduced, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                "reduced_legendre" => select!(function, isotensional.asymptotic.reduced.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                "alternative" => select!(function, isotensional.asymptotic.alternative, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                "alternative_legendre" => select!(function, isotensional.asymptotic.alternative.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                _ => return Err(format!("unknown approximation {} of the isotensional ensemble of the efjc model", approximation))
+            }
+        },
+        ("swfjc", "isotensional", _) =>
+        {
+            let well_width = required(options, "well_width")?/length_scale;
+            let model = SWFJC::try_init(number_of_links, link_length, hinge_mass, well_width).map_err(|error| error.to_string())?;
+            match approximation
+            {
+                "exact" => select!(function, model.thermodynamics.isotensional, temperature, [end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy]),
+                "legendre" => select!(function, model.thermodynamics.isotensional.legendre, temperature, [helmholtz_free_energy, relative_helmholtz_free_energy]),
+                _ => return Err(format!("unknown approximation {} of the isotensional ensemble of the swfjc model", approximation))
+            }
+        },
+        _ => return Err(format!("unknown {} ensemble or {} approximation of the {} model", ensemble, approximation, model))
+    }.ok_or(format!("function {} is not available for the {} approximation of the {} ensemble of the {} model", function, approximation, ensemble, model))?;
+    let (abscissa_units, abscissa_scale) = if ensemble == "isotensional" {(force_units, force_scale)} else {(length_units, length_scale)};
+    let (ordinate_units, ordinate_scale) = match function
+    {
+        "force" => (force_units, force_scale),
+        "end_to_end_length" => (length_units, length_scale),
+        _ => (energy_units, energy_scale)
+    };
+    let minimum = required(options, "minimum")?/abscissa_scale;
+    let maximum = required(options, "maximum")?/abscissa_scale;
+    let points = optional(options, "points", 100.0)?;
+    if points.fract() != 0.0 || points < 1.0
+    {
+        return Err("invalid value for --points".to_string())
+    }
+    let mut curve = Curve::init(model, ensemble, approximation, (abscissa_units, ordinate_units)).sweep(evaluate, &minimum, &maximum, points as usize);
+    curve.abscissa.iter_mut().for_each(|abscissa| *abscissa *= abscissa_scale);
+    curve.ordinate.iter_mut().for_each(|ordinate| *ordinate *= ordinate_scale);
+    Ok(curve)
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+use super::*;
+fn arguments(line: &str) -> Vec<String>
+{
+    line.split_whitespace().map(|argument| argument.to_string()).collect()
+}
+#[test]
+fn help()
+{
+    assert_eq!(run(&[]), Ok(USAGE.to_string()));
+    assert_eq!(run(&arguments("--model fjc --help")), Ok(USAGE.to_string()));
+}
+#[test]
+fn isometric_force()
+{
+    let output = run(&arguments("--model fjc --ensemble isometric --approximation legendre --function force --number-of-links 8 --link-length 1 --temperature 300 --minimum 1 --maximum 7 --points 4")).unwrap();
+    let model = FJC::init(8, 1.0, 1.0);
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("model,ensemble,approximation,abscissa,abscissa_units,ordinate,ordinate_units"));
+    for (line, end_to_end_length) in lines.zip([1.0, 3.0, 5.0, 7.0])
+    {
+        let columns: Vec<&str> = line.split(',').collect();
+        assert_eq!(columns[..3], ["fjc", "isometric", "legendre"]);
+        assert_eq!(columns[3].parse::<f64>().unwrap(), end_to_end_length);
+        assert_eq!(columns[5].parse::<f64>().unwrap(), model.thermodynamics.isometric.legendre.force(&end_to_end_length, &300.0));
+    }
+    assert_eq!(output.lines().count(), 5);
+}
+#[test]
+fn units()
+{
+    let output = run(&arguments("--model fjc --ensemble isometric --function relative_helmholtz_free_energy --number-of-links 8 --link-length 10 --minimum 40 --maximum 40 --points 1 --length-units angstrom --energy-units kT --format tsv")).unwrap();
+    let model = FJC::init(8, 1.0, 1.0);
+    let columns: Vec<&str> = output.lines().nth(1).unwrap().split('\t').collect();
+    let relative_helmholtz_free_energy = model.thermodynamics.isometric.relative_helmholtz_free_energy(&4.0, &300.0)/BOLTZMANN_CONSTANT/300.0;
+    assert_eq!([columns[3], columns[4], columns[6]], ["4e1", "angstrom", "kT"]);
+    assert!((columns[5].parse::<f64>().unwrap()/relative_helmholtz_free_energy - 1.0).abs() <= 1e-12);
+}
+#[test]
+fn isotensional_force_units()
+{
+    let output = run(&arguments("--model efjc --ensemble isotensional --approximation asymptotic --function end_to_end_length --number-of-links 8 --link-stiffness 8e2 --minimum 0 --maximum 10 --points 3 --force-units pN")).unwrap();
+    let model = EFJC::init(8, 1.0, 1.0, 8e2/(1e21/AVOGADRO_NUMBER));
+    let columns: Vec<&str> = output.lines().nth(3).unwrap().split(',').collect();
+    assert_eq!(columns[4], "pN");
+    assert_eq!(columns[5].parse::<f64>().unwrap(), model.thermodynamics.isotensional.asymptotic.end_to_end_length(&(10.0*AVOGADRO_NUMBER/1e21), &300.0));
+}
+#[test]
+fn json()
+{
+    let output = run(&arguments("--model swfjc --ensemble isotensional --function gibbs_free_energy --number-of-links 8 --well-width 0.5 --minimum 0 --maximum 100 --points 5 --format json")).unwrap();
+    let curve = Curve::from_json(&output).unwrap();
+    assert_eq!(curve.model, "swfjc");
+    assert_eq!(curve.units, ("J/(mol*nm)".to_string(), "J/mol".to_string()));
+    assert_eq!(curve.ordinate.len(), 5);
+}
+#[test]
+fn modified_canonical()
+{
+    let output = run(&arguments("--model fjc --ensemble modified_canonical --approximation weak_potential --function end_to_end_length --number-of-links 8 --potential-stiffness 1 --minimum 0 --maximum 20 --points 3")).unwrap();
+    let model = FJC::init(8, 1.0, 1.0);
+    let columns: Vec<&str> = output.lines().nth(2).unwrap().split(',').collect();
+    assert_eq!(columns[5].parse::<f64>().unwrap(), model.thermodynamics.modified_canonical.asymptotic.weak_potential.end_to_end_length(&10.0, &1.0, &300.0));
+}
+#[test]
+fn stiffness_units()
+{
+    let output = run(&arguments("--model fjc --ensemble modified_canonical --approximation weak_potential --function end_to_end_length --number-of-links 8 --link-length 10 --potential-stiffness 1 --minimum 100 --maximum 100 --points 1 --length-units angstrom --force-units pN")).unwrap();
+    let model = FJC::init(8, 1.0, 1.0);
+    let columns: Vec<&str> = output.lines().nth(1).unwrap().split(',').collect();
+    let end_to_end_length = model.thermodynamics.modified_canonical.asymptotic.weak_potential.end_to_end_length(&10.0, &(1e1/(1e21/AVOGADRO_NUMBER)), &300.0);
+    assert!((columns[5].parse::<f64>().unwrap()/1e1/end_to_end_length - 1.0).abs() <= 1e-12);
+}
+#[test]
+fn errors()
+{
+    assert_eq!(run(&arguments("--model fjc --ensemble isometric --function force --number-of-links 0 --minimum 0 --maximum 1")), Err("invalid parameter number_of_links = 0 of the FJC model".to_string()));
+    assert_eq!(run(&arguments("--model swfjc --ensemble isotensional --function gibbs_free_energy --number-of-links 8 --well-width -1 --minimum 0 --maximum 1")), Err("invalid parameter well_width = -1 of the SWFJC model".to_string()));
+    assert!(run(&arguments("--model efjc --ensemble isometric --function force --number-of-links 8 --minimum 0 --maximum 1")).is_err());
+    assert!(run(&arguments("--model fjc --ensemble isotensional --function force --number-of-links 8 --minimum 0 --maximum 1")).unwrap_err().starts_with("function force is not available"));
+    assert_eq!(run(&arguments("--model fjc --ensemble")), Err("missing value for --ensemble".to_string()));
+    assert_eq!(run(&arguments("--model fjc --ensemble isometric --function force --minimum 0 --maximum 1")), Err("missing --number-of-links".to_string()));
+    assert_eq!(run(&arguments("--model fjc --ensemble isometric --function force --number-of-links 8 --minimum 0 --maximum 1 --format xml")), Err("unknown format xml".to_string()));
+}
@@ -0,0 +1,148 @@
+mod test;
+
+use std::fmt;
+
+/// The errors returned by the fallible constructors and evaluations of models.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error
+{
+    /// A parameter of a model is outside of its valid range.
+    InvalidParameter
+    {
+        /// The model being initialized.
+        model: &'static str,
+        /// The name of the parameter.
+        argument: &'static str,
+        /// The value of the parameter.
+        value: f64
+    },
+    /// An argument of a function is outside of the domain of the model.
+    OutOfDomain
+    {
+        /// The model being evaluated.
+        model: &'static str,
+        /// The name of the argument.
+        argument: &'static str,
+        /// The value of the argument.
+        value: f64
+    },
+    /// An evaluation of a model did not converge to a number.
+    NonConvergence
+    {
+        /// The model being evaluated.
+        model: &'static str,
+        /// The name of the argument.
+        argument: &'static str,
+        /// The value of the argument.
+        value: f64
+    },
+    /// An evaluation of a model overflowed to an infinite value.
+    Overflow
+    {
+        /// The model being evaluated.
+        model: &'static str,
+        /// The name of the argument.
+        argument: &'static str,
+        /// The value of the argument.
+        value: f64
+    }
+}
+
+/// The implemented functionality of the errors.
+impl Error
+{
+    /// The model that was at fault.
+    pub fn model(&self) -> &'static str
+    {
+        match *self
+        {
+            Error::InvalidParameter{model, ..} => model,
+            Error::OutOfDomain{model, ..} => model,
+            Error::NonConvergence{model, ..} => model,
+            Error::Overflow{model, ..} => model
+        }
+    }
+    /// The parameter or argument that was at fault.
+    pub fn argument(&self) -> &'static str
+    {
+        match *self
+        {
+            Error::InvalidParameter{argument, ..} => argument,
+            Error::OutOfDomain{argument, ..} => argument,
+            Error::NonConvergence{argument, ..} => argument,
+            Error::Overflow{argument, ..} => argument
+        }
+    }
+}
+
+impl fmt::Display for Error
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+    {
+        match *self
+        {
+            Error::InvalidParameter{model, argument, value} => write!(formatter, "invalid parameter {} = {} of the {} model", argument, value, model),
+            Error::OutOfDomain{model, argument, value} => write!(formatter, "argument {} = {} is outside of the domain of the {} model", argument, value, model),
+            Error::NonConvergence{model, argument, value} => write!(formatter, "the {} model did not converge for the argument {} = {}", model, argument, value),
+            Error::Overflow{model, argument, value} => write!(formatter, "the {} model overflowed for the argument {} = {}", model, argument, value)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Returns an invalid-parameter error unless the parameter is positive and finite.
+pub fn positive(model: &'static str, argument: &'static str, value: &f64) -> Result<(), Error>
+{
+    if value > &0.0 && value.is_finite()
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::InvalidParameter{model, argument, value: *value})
+    }
+}
+
+/// Returns an invalid-parameter error unless the number of links is at least the minimum.
+pub fn minimum_number_of_links(model: &'static str, number_of_links: &u8, minimum: u8) -> Result<(), Error>
+{
+    if number_of_links >= &minimum
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::InvalidParameter{model, argument: "number_of_links", value: *number_of_links as f64})
+    }
+}
+
+/// Returns an out-of-domain error unless the argument is at least the lower bound and less than the upper bound.
+pub fn domain(model: &'static str, argument: &'static str, value: &f64, lower: &f64, upper: &f64) -> Result<(), Error>
+{
+    if value >= lower && value < upper
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(Error::OutOfDomain{model, argument, value: *value})
+    }
+}
+
+/// Returns the result of an evaluation, or a non-convergence error if it is not a number and an overflow error if it is infinite.
+pub fn finite(model: &'static str, argument: &'static str, value: &f64, result: f64) -> Result<f64, Error>
+{
+    if result.is_nan()
+    {
+        Err(Error::NonConvergence{model, argument, value: *value})
+    }
+    else if result.is_infinite()
+    {
+        Err(Error::Overflow{model, argument, value: *value})
+    }
+    else
+    {
+        Ok(result)
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(test)]
+use super::*;
+#[test]
+fn positive_parameter()
+{
+    assert_eq!(positive("FJC", "link_length", &1.0), Ok(()));
+    for value in [0.0, -1.0, f64::INFINITY, f64::NAN]
+    {
+        let error = positive("FJC", "link_length", &value).unwrap_err();
+        assert!(matches!(error, Error::InvalidParameter{..}));
+        assert_eq!(error.model(), "FJC");
+        assert_eq!(error.argument(), "link_length");
+    }
+}
+#[test]
+fn number_of_links()
+{
+    assert_eq!(minimum_number_of_links("FJC", &2, 2), Ok(()));
+    assert_eq!(minimum_number_of_links("FJC", &1, 2), Err(Error::InvalidParameter{model: "FJC", argument: "number_of_links", value: 1.0}));
+}
+#[test]
+fn bounded_domain()
+{
+    assert_eq!(domain("FJC", "end_to_end_length", &0.0, &0.0, &1.0), Ok(()));
+    assert_eq!(domain("FJC", "end_to_end_length", &1.0, &0.0, &1.0), Err(Error::OutOfDomain{model: "FJC", argument: "end_to_end_length", value: 1.0}));
+    assert_eq!(domain("FJC", "end_to_end_length", &-1.0, &0.0, &1.0), Err(Error::OutOfDomain{model: "FJC", argument: "end_to_end_length", value: -1.0}));
+    assert!(domain("FJC", "end_to_end_length", &f64::NAN, &0.0, &1.0).is_err());
+}
+#[test]
+fn finite_result()
+{
+    assert_eq!(finite("FJC", "force", &1.0, 2.0), Ok(2.0));
+    assert_eq!(finite("FJC", "force", &1.0, f64::NAN), Err(Error::NonConvergence{model: "FJC", argument: "force", value: 1.0}));
+    assert_eq!(finite("FJC", "force", &1.0, f64::INFINITY), Err(Error::Overflow{model: "FJC", argument: "force", value: 1.0}));
+}
+#[test]
+fn display()
+{
+    let message = Error::InvalidParameter{model: "SWFJC", argument: "well_width", value: -1.0}.to_string();
+    assert!(message.contains("SWFJC"));
+    assert!(message.contains("well_width = -1"));
+}
@@ -1,4 +1,12 @@
 #![doc = include_str!("../README.md")]
 
+/// Mathematical functions and utilities.
+pub mod math;
+
 /// Models for polymer physics.
 pub mod physics;
+
+/// Errors returned by fallible constructors and evaluations.
+pub mod error;
+
+pub use error::Error;
@@ -0,0 +1,132 @@
+mod test;
+
+use std::ops::
+{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg
+};
+
+/// The structure of a complex number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex
+{
+    /// The real part of the complex number.
+    pub re: f64,
+
+    /// The imaginary part of the complex number.
+    pub im: f64
+}
+
+/// The implemented functionality of complex numbers.
+impl Complex
+{
+    /// Initializes and returns a complex number from its real and imaginary parts.
+    pub fn new(re: f64, im: f64) -> Complex
+    {
+        Complex
+        {
+            re,
+            im
+        }
+    }
+    /// The modulus of the complex number.
+    pub fn norm(&self) -> f64
+    {
+        self.re.hypot(self.im)
+    }
+    /// The argument of the complex number on the principal branch.
+    pub fn arg(&self) -> f64
+    {
+        self.im.atan2(self.re)
+    }
+    /// The exponential of the complex number.
+    pub fn exp(&self) -> Complex
+    {
+        let modulus = self.re.exp();
+        Complex::new(modulus*self.im.cos(), modulus*self.im.sin())
+    }
+    /// The natural logarithm of the complex number on the principal branch.
+    pub fn ln(&self) -> Complex
+    {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+    /// The square root of the complex number on the principal branch, which has a nonnegative real part.
+    pub fn sqrt(&self) -> Complex
+    {
+        let modulus = self.norm();
+        let re = (0.5*(modulus + self.re)).sqrt();
+        let im = (0.5*(modulus - self.re)).sqrt();
+        if self.im < 0.0
+        {
+            Complex::new(re, -im)
+        }
+        else
+        {
+            Complex::new(re, im)
+        }
+    }
+    /// The complex number raised to an integer power.
+    pub fn powi(&self, n: i32) -> Complex
+    {
+        let modulus = self.norm().powi(n);
+        let angle = (n as f64)*self.arg();
+        Complex::new(modulus*angle.cos(), modulus*angle.sin())
+    }
+}
+
+impl From<f64> for Complex
+{
+    fn from(re: f64) -> Complex
+    {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl Add for Complex
+{
+    type Output = Complex;
+    fn add(self, other: Complex) -> Complex
+    {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex
+{
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex
+    {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex
+{
+    type Output = Complex;
+    fn mul(self, other: Complex) -> Complex
+    {
+        Complex::new(self.re*other.re - self.im*other.im, self.re*other.im + self.im*other.re)
+    }
+}
+
+impl Div for Complex
+{
+    type Output = Complex;
+    fn div(self, other: Complex) -> Complex
+    {
+        let denominator = other.re.powi(2) + other.im.powi(2);
+        Complex::new((self.re*other.re + self.im*other.im)/denominator, (self.im*other.re - self.re*other.im)/denominator)
+    }
+}
+
+impl Neg for Complex
+{
+    type Output = Complex;
+    fn neg(self) -> Complex
+    {
+        Complex::new(-self.re, -self.im)
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static TOLERANCE: f64 = 1e-12;
+static NUMBER_OF_LOOPS: u32 = 888;
+fn random(rng: &mut rand::rngs::ThreadRng) -> Complex
+{
+    Complex::new(4.0*(0.5 - rng.gen::<f64>()), 4.0*(0.5 - rng.gen::<f64>()))
+}
+#[test]
+fn multiplication_and_division()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let z = random(&mut rng);
+        let w = random(&mut rng);
+        let residual = z*w/w - z;
+        assert!(residual.norm() <= TOLERANCE*z.norm());
+    }
+}
+#[test]
+fn exp_and_ln()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let z = random(&mut rng);
+        let residual = z.exp().ln() - z;
+        assert!(residual.re.abs() <= TOLERANCE);
+        assert!(residual.im.abs() <= TOLERANCE);
+    }
+}
+#[test]
+fn sqrt()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let z = random(&mut rng);
+        let root = z.sqrt();
+        assert!(root.re >= 0.0);
+        assert!((root*root - z).norm() <= TOLERANCE*z.norm());
+    }
+}
+#[test]
+fn powi()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let z = random(&mut rng);
+        let n: i32 = rng.gen_range(0..8);
+        let product = (0..n).fold(Complex::from(1.0), |product, _| product*z);
+        assert!((z.powi(n) - product).norm() <= TOLERANCE*(1.0 + product.norm()));
+    }
+}
@@ -0,0 +1,200 @@
+mod test;
+
+use std::cmp::Ordering;
+use std::iter::Sum;
+use std::ops::
+{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg
+};
+use crate::math::float::Float;
+
+/// The structure of a dual number, a value and its derivative with respect to a single variable, for forward-mode automatic differentiation.
+///
+/// Dual numbers are ordered by their values alone, so that branches taken on the value of an argument are the same as for the value itself.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual
+{
+    /// The value.
+    pub value: f64,
+
+    /// The derivative of the value with respect to the variable.
+    pub derivative: f64
+}
+
+/// The implemented functionality of dual numbers.
+impl Dual
+{
+    /// Initializes and returns a dual number from its value and derivative.
+    pub fn new(value: f64, derivative: f64) -> Dual
+    {
+        Dual
+        {
+            value,
+            derivative
+        }
+    }
+    /// Initializes and returns the variable being differentiated with respect to, which has a unit derivative.
+    pub fn variable(value: f64) -> Dual
+    {
+        Dual::new(value, 1.0)
+    }
+    /// Initializes and returns a constant, which has a vanishing derivative.
+    pub fn constant(value: f64) -> Dual
+    {
+        Dual::new(value, 0.0)
+    }
+    fn chain(&self, value: f64, derivative: f64) -> Dual
+    {
+        Dual::new(value, derivative*self.derivative)
+    }
+}
+
+/// Returns the gradient of the function at the point, evaluating the function once for each of the variables.
+pub fn gradient<F>(function: F, point: &[f64]) -> Vec<f64>
+where F: Fn(&[Dual]) -> Dual
+{
+    (0..point.len()).map(|direction| function(&seed(point, direction)).derivative).collect()
+}
+
+/// Returns the jacobian of the function at the point, with one row for each of the outputs and one column for each of the variables.
+pub fn jacobian<F>(function: F, point: &[f64]) -> Vec<Vec<f64>>
+where F: Fn(&[Dual]) -> Vec<Dual>
+{
+    let columns = (0..point.len()).map(|direction| function(&seed(point, direction))).collect::<Vec<Vec<Dual>>>();
+    let number_of_outputs = columns.first().map(|column| column.len()).unwrap_or(0);
+    (0..number_of_outputs).map(|output| columns.iter().map(|column| column[output].derivative).collect()).collect()
+}
+
+fn seed(point: &[f64], direction: usize) -> Vec<Dual>
+{
+    point.iter().enumerate().map(|(index, value)| if index == direction {Dual::variable(*value)} else {Dual::constant(*value)}).collect()
+}
+
+impl Float for Dual
+{
+    fn from_f64(value: f64) -> Dual
+    {
+        Dual::constant(value)
+    }
+    fn to_f64(self) -> f64
+    {
+        self.value
+    }
+    fn abs(self) -> Dual
+    {
+        if self.value < 0.0
+        {
+            -self
+        }
+        else
+        {
+            self
+        }
+    }
+    fn powi(self, exponent: i32) -> Dual
+    {
+        if exponent == 0
+        {
+            Dual::constant(1.0)
+        }
+        else
+        {
+            self.chain(self.value.powi(exponent), (exponent as f64)*self.value.powi(exponent - 1))
+        }
+    }
+    fn exp(self) -> Dual
+    {
+        let exponential = self.value.exp();
+        self.chain(exponential, exponential)
+    }
+    fn ln(self) -> Dual
+    {
+        self.chain(self.value.ln(), 1.0/self.value)
+    }
+    fn ln_1p(self) -> Dual
+    {
+        self.chain(self.value.ln_1p(), 1.0/(1.0 + self.value))
+    }
+    fn sinh(self) -> Dual
+    {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+    fn tanh(self) -> Dual
+    {
+        let tanh = self.value.tanh();
+        self.chain(tanh, 1.0 - tanh.powi(2))
+    }
+}
+
+impl From<f64> for Dual
+{
+    fn from(value: f64) -> Dual
+    {
+        Dual::constant(value)
+    }
+}
+
+impl PartialOrd for Dual
+{
+    fn partial_cmp(&self, other: &Dual) -> Option<Ordering>
+    {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Add for Dual
+{
+    type Output = Dual;
+    fn add(self, other: Dual) -> Dual
+    {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl Sub for Dual
+{
+    type Output = Dual;
+    fn sub(self, other: Dual) -> Dual
+    {
+        Dual::new(self.value - other.value, self.derivative - other.derivative)
+    }
+}
+
+impl Mul for Dual
+{
+    type Output = Dual;
+    fn mul(self, other: Dual) -> Dual
+    {
+        Dual::new(self.value*other.value, self.derivative*other.value + self.value*other.derivative)
+    }
+}
+
+impl Div for Dual
+{
+    type Output = Dual;
+    fn div(self, other: Dual) -> Dual
+    {
+        Dual::new(self.value/other.value, (self.derivative*other.value - self.value*other.derivative)/other.value.powi(2))
+    }
+}
+
+impl Neg for Dual
+{
+    type Output = Dual;
+    fn neg(self) -> Dual
+    {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl Sum for Dual
+{
+    fn sum<I: Iterator<Item = Dual>>(iter: I) -> Dual
+    {
+        iter.fold(Dual::constant(0.0), |sum, value| sum + value)
+    }
+}
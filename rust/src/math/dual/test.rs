@@ -0,0 +1,55 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static TOLERANCE: f64 = 1e-6;
+static STEP: f64 = 1e-6;
+static NUMBER_OF_LOOPS: u32 = 888;
+fn check<F, G>(dual: F, real: G, x: f64)
+where F: Fn(Dual) -> Dual, G: Fn(f64) -> f64
+{
+    let result = dual(Dual::variable(x));
+    let finite_difference = (real(x + STEP) - real(x - STEP))/(2.0*STEP);
+    assert_eq!(result.value, real(x));
+    assert!((result.derivative - finite_difference).abs() <= TOLERANCE*finite_difference.abs().max(1.0));
+}
+#[test]
+fn arithmetic()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 0.5 + 2.0*rng.gen::<f64>();
+        check(|x| x*x/(x + Dual::constant(1.0)) - -x, |x| x*x/(x + 1.0) + x, x);
+        check(|x| x.powi(3) + x.powi(-2) + x.powi(0), |x| x.powi(3) + x.powi(-2) + 1.0, x);
+        check(|x| [x, x*x, Dual::constant(3.0)].into_iter().sum(), |x| x + x*x + 3.0, x);
+    }
+}
+#[test]
+fn elementary()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 0.5 + 2.0*rng.gen::<f64>();
+        check(|x| x.exp(), |x| x.exp(), x);
+        check(|x| x.ln(), |x| x.ln(), x);
+        check(|x| x.ln_1p(), |x| x.ln_1p(), x);
+        check(|x| x.sinh(), |x| x.sinh(), x);
+        check(|x| x.tanh(), |x| x.tanh(), x);
+        check(|x| (-x).abs(), |x| x.abs(), x);
+    }
+}
+#[test]
+fn ordering()
+{
+    assert!(Dual::new(1.0, 5.0) < Dual::new(2.0, 0.0));
+    assert_eq!(Dual::new(1.0, 5.0).partial_cmp(&Dual::new(1.0, 0.0)), Some(Ordering::Equal));
+}
+#[test]
+fn gradient_and_jacobian()
+{
+    let function = |x: &[Dual]| x[0]*x[1].powi(2) + x[2].exp();
+    assert_eq!(gradient(function, &[2.0, 3.0, 0.0]), vec![9.0, 12.0, 1.0]);
+    let functions = |x: &[Dual]| vec![x[0]*x[1], x[0] - x[1]];
+    assert_eq!(jacobian(functions, &[2.0, 3.0]), vec![vec![3.0, 2.0], vec![1.0, -1.0]]);
+}
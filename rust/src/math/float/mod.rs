@@ -0,0 +1,350 @@
+mod test;
+
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::
+{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg
+};
+
+static LN_2: DoubleDouble = DoubleDouble{high: std::f64::consts::LN_2, low: 2.3190468138462996e-17};
+static EXP_SQUARINGS: i32 = 5;
+static EXP_TERMS: u32 = 20;
+static SINH_TERMS: u32 = 15;
+static TANH_SATURATION: f64 = 40.0;
+
+/// The floating-point arithmetic required of the types that models can be evaluated with.
+pub trait Float: Copy + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> + Sum
+{
+    /// Returns the value nearest to the double-precision number.
+    fn from_f64(value: f64) -> Self;
+    /// Returns the double-precision number nearest to the value.
+    fn to_f64(self) -> f64;
+    /// The absolute value.
+    fn abs(self) -> Self;
+    /// The value raised to an integer power.
+    fn powi(self, exponent: i32) -> Self;
+    /// The exponential of the value.
+    fn exp(self) -> Self;
+    /// The natural logarithm of the value.
+    fn ln(self) -> Self;
+    /// The natural logarithm of one plus the value.
+    fn ln_1p(self) -> Self;
+    /// The hyperbolic sine of the value.
+    fn sinh(self) -> Self;
+    /// The hyperbolic tangent of the value.
+    fn tanh(self) -> Self;
+}
+
+impl Float for f32
+{
+    fn from_f64(value: f64) -> f32
+    {
+        value as f32
+    }
+    fn to_f64(self) -> f64
+    {
+        self as f64
+    }
+    fn abs(self) -> f32
+    {
+        f32::abs(self)
+    }
+    fn powi(self, exponent: i32) -> f32
+    {
+        f32::powi(self, exponent)
+    }
+    fn exp(self) -> f32
+    {
+        f32::exp(self)
+    }
+    fn ln(self) -> f32
+    {
+        f32::ln(self)
+    }
+    fn ln_1p(self) -> f32
+    {
+        f32::ln_1p(self)
+    }
+    fn sinh(self) -> f32
+    {
+        f32::sinh(self)
+    }
+    fn tanh(self) -> f32
+    {
+        f32::tanh(self)
+    }
+}
+
+impl Float for f64
+{
+    fn from_f64(value: f64) -> f64
+    {
+        value
+    }
+    fn to_f64(self) -> f64
+    {
+        self
+    }
+    fn abs(self) -> f64
+    {
+        f64::abs(self)
+    }
+    fn powi(self, exponent: i32) -> f64
+    {
+        f64::powi(self, exponent)
+    }
+    fn exp(self) -> f64
+    {
+        f64::exp(self)
+    }
+    fn ln(self) -> f64
+    {
+        f64::ln(self)
+    }
+    fn ln_1p(self) -> f64
+    {
+        f64::ln_1p(self)
+    }
+    fn sinh(self) -> f64
+    {
+        f64::sinh(self)
+    }
+    fn tanh(self) -> f64
+    {
+        f64::tanh(self)
+    }
+}
+
+/// The structure of a double-double number, the unevaluated sum of two double-precision numbers, which carries about 32 significant digits.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct DoubleDouble
+{
+    /// The leading part, the double-precision number nearest to the value.
+    pub high: f64,
+
+    /// The trailing part, no larger than half of a unit in the last place of the leading part.
+    pub low: f64
+}
+
+/// The implemented functionality of double-double numbers.
+impl DoubleDouble
+{
+    /// Initializes and returns a double-double number from its leading and trailing parts, which are renormalized.
+    pub fn new(high: f64, low: f64) -> DoubleDouble
+    {
+        quick_two_sum(high, low)
+    }
+    fn scale(self, factor: f64) -> DoubleDouble
+    {
+        DoubleDouble{high: self.high*factor, low: self.low*factor}
+    }
+}
+
+impl Float for DoubleDouble
+{
+    fn from_f64(value: f64) -> DoubleDouble
+    {
+        DoubleDouble{high: value, low: 0.0}
+    }
+    fn to_f64(self) -> f64
+    {
+        self.high + self.low
+    }
+    fn abs(self) -> DoubleDouble
+    {
+        if self.high < 0.0
+        {
+            -self
+        }
+        else
+        {
+            self
+        }
+    }
+    fn powi(self, exponent: i32) -> DoubleDouble
+    {
+        let mut base = self;
+        let mut power = DoubleDouble::from_f64(1.0);
+        let mut remaining = exponent.unsigned_abs();
+        while remaining > 0
+        {
+            if remaining % 2 == 1
+            {
+                power = power*base;
+            }
+            base = base*base;
+            remaining /= 2;
+        }
+        if exponent < 0
+        {
+            DoubleDouble::from_f64(1.0)/power
+        }
+        else
+        {
+            power
+        }
+    }
+    fn exp(self) -> DoubleDouble
+    {
+        if self.high > 709.0
+        {
+            return DoubleDouble::from_f64(f64::INFINITY)
+        }
+        if self.high < -745.0
+        {
+            return DoubleDouble::from_f64(0.0)
+        }
+        let k = (self.high/LN_2.high).round();
+        let reduced = (self - LN_2*DoubleDouble::from_f64(k)).scale(0.5_f64.powi(EXP_SQUARINGS));
+        let mut term = DoubleDouble::from_f64(1.0);
+        let mut sum = term;
+        for index in 1..=EXP_TERMS
+        {
+            term = term*reduced/DoubleDouble::from_f64(index as f64);
+            sum = sum + term;
+        }
+        for _ in 0..EXP_SQUARINGS
+        {
+            sum = sum*sum;
+        }
+        sum.scale(2.0_f64.powi(k as i32))
+    }
+    fn ln(self) -> DoubleDouble
+    {
+        if self.high <= 0.0
+        {
+            return DoubleDouble::from_f64(self.high.ln())
+        }
+        let mut logarithm = DoubleDouble::from_f64(self.high.ln());
+        for _ in 0..2
+        {
+            logarithm = logarithm + self*(-logarithm).exp() - DoubleDouble::from_f64(1.0);
+        }
+        logarithm
+    }
+    fn ln_1p(self) -> DoubleDouble
+    {
+        (DoubleDouble::from_f64(1.0) + self).ln()
+    }
+    fn sinh(self) -> DoubleDouble
+    {
+        if self.high.abs() < 0.5
+        {
+            let square = self*self;
+            let mut term = self;
+            let mut sum = term;
+            for index in 1..=SINH_TERMS
+            {
+                term = term*square/DoubleDouble::from_f64(((2*index)*(2*index + 1)) as f64);
+                sum = sum + term;
+            }
+            sum
+        }
+        else
+        {
+            let exponential = self.exp();
+            (exponential - DoubleDouble::from_f64(1.0)/exponential).scale(0.5)
+        }
+    }
+    fn tanh(self) -> DoubleDouble
+    {
+        if self.high.abs() > TANH_SATURATION
+        {
+            DoubleDouble::from_f64(self.high.signum())
+        }
+        else
+        {
+            let exponential = self.exp();
+            self.sinh()/(exponential + DoubleDouble::from_f64(1.0)/exponential).scale(0.5)
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble
+{
+    fn from(value: f64) -> DoubleDouble
+    {
+        DoubleDouble::from_f64(value)
+    }
+}
+
+impl Add for DoubleDouble
+{
+    type Output = DoubleDouble;
+    fn add(self, other: DoubleDouble) -> DoubleDouble
+    {
+        let (high, high_error) = two_sum(self.high, other.high);
+        let (low, low_error) = two_sum(self.low, other.low);
+        let sum = quick_two_sum(high, high_error + low);
+        quick_two_sum(sum.high, sum.low + low_error)
+    }
+}
+
+impl Sub for DoubleDouble
+{
+    type Output = DoubleDouble;
+    fn sub(self, other: DoubleDouble) -> DoubleDouble
+    {
+        self + (-other)
+    }
+}
+
+impl Mul for DoubleDouble
+{
+    type Output = DoubleDouble;
+    fn mul(self, other: DoubleDouble) -> DoubleDouble
+    {
+        let product = self.high*other.high;
+        let error = self.high.mul_add(other.high, -product);
+        quick_two_sum(product, error + self.high*other.low + self.low*other.high)
+    }
+}
+
+impl Div for DoubleDouble
+{
+    type Output = DoubleDouble;
+    fn div(self, other: DoubleDouble) -> DoubleDouble
+    {
+        let first = self.high/other.high;
+        let remainder = self - other*DoubleDouble::from_f64(first);
+        let second = remainder.high/other.high;
+        let remainder = remainder - other*DoubleDouble::from_f64(second);
+        let third = remainder.high/other.high;
+        quick_two_sum(first, second) + DoubleDouble::from_f64(third)
+    }
+}
+
+impl Neg for DoubleDouble
+{
+    type Output = DoubleDouble;
+    fn neg(self) -> DoubleDouble
+    {
+        DoubleDouble{high: -self.high, low: -self.low}
+    }
+}
+
+impl Sum for DoubleDouble
+{
+    fn sum<I: Iterator<Item = DoubleDouble>>(iter: I) -> DoubleDouble
+    {
+        iter.fold(DoubleDouble::from_f64(0.0), |sum, value| sum + value)
+    }
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64)
+{
+    let sum = a + b;
+    let b_virtual = sum - a;
+    (sum, (a - (sum - b_virtual)) + (b - b_virtual))
+}
+
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble
+{
+    let sum = a + b;
+    DoubleDouble{high: sum, low: b - (sum - a)}
+}
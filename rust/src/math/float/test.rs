@@ -0,0 +1,101 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static TOLERANCE: f64 = 1e-30;
+static NUMBER_OF_LOOPS: u32 = 888;
+fn random(rng: &mut rand::rngs::ThreadRng) -> DoubleDouble
+{
+    DoubleDouble::new(4.0*(0.5 - rng.gen::<f64>()), 1e-17*(0.5 - rng.gen::<f64>()))
+}
+#[test]
+fn beyond_double_precision()
+{
+    let one = DoubleDouble::from_f64(1.0);
+    let tiny = DoubleDouble::from_f64(1e-20);
+    assert_eq!((one + tiny - one).to_f64(), 1e-20);
+    assert_eq!((1.0 + 1e-20) - 1.0, 0.0);
+}
+#[test]
+fn addition_and_subtraction()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = random(&mut rng);
+        let y = random(&mut rng);
+        assert!((x + y - y - x).abs().to_f64() <= TOLERANCE*x.abs().to_f64().max(y.abs().to_f64()));
+    }
+}
+#[test]
+fn multiplication_and_division()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = random(&mut rng);
+        let y = random(&mut rng);
+        assert!((x*y/y - x).abs().to_f64() <= TOLERANCE*x.abs().to_f64());
+    }
+}
+#[test]
+fn third()
+{
+    let third = DoubleDouble::from_f64(1.0)/DoubleDouble::from_f64(3.0);
+    assert!((third*DoubleDouble::from_f64(3.0) - DoubleDouble::from_f64(1.0)).abs().to_f64() <= TOLERANCE);
+    assert!(third.low != 0.0);
+}
+#[test]
+fn powi()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = random(&mut rng);
+        let exponent: i32 = rng.gen_range(-12..12);
+        let product = (0..exponent.abs()).fold(DoubleDouble::from_f64(1.0), |product, _| product*x);
+        let expected = if exponent < 0 {DoubleDouble::from_f64(1.0)/product} else {product};
+        assert!((x.powi(exponent) - expected).abs().to_f64() <= 1e2*TOLERANCE*expected.abs().to_f64());
+    }
+}
+#[test]
+fn exp_and_ln()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = random(&mut rng).scale(1e1);
+        assert!((x.exp().ln() - x).abs().to_f64() <= 1e2*TOLERANCE*x.abs().to_f64().max(1.0));
+        assert!((x.exp() - DoubleDouble::from_f64(x.high.exp())).abs().to_f64() <= 1e-15*x.high.exp());
+    }
+    let e = DoubleDouble::from_f64(1.0).exp();
+    assert_eq!(e.high, std::f64::consts::E);
+    assert!((e.low - 1.4456468917292502e-16).abs() <= 1e1*TOLERANCE);
+    assert_eq!(DoubleDouble::from_f64(1e3).exp().to_f64(), f64::INFINITY);
+    assert_eq!(DoubleDouble::from_f64(-1e3).exp().to_f64(), 0.0);
+}
+#[test]
+fn hyperbolic()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = random(&mut rng);
+        let exponential = x.exp();
+        let sinh = (exponential - DoubleDouble::from_f64(1.0)/exponential).scale(0.5);
+        let cosh = (exponential + DoubleDouble::from_f64(1.0)/exponential).scale(0.5);
+        assert!((x.sinh() - sinh).abs().to_f64() <= 1e2*TOLERANCE*cosh.to_f64());
+        assert!((x.tanh() - sinh/cosh).abs().to_f64() <= 1e2*TOLERANCE);
+        assert!(x.high <= -1.0 || (x.ln_1p() - (DoubleDouble::from_f64(1.0) + x).ln()).abs().to_f64() <= TOLERANCE);
+    }
+    assert_eq!(DoubleDouble::from_f64(1e2).tanh().to_f64(), 1.0);
+    assert_eq!(DoubleDouble::from_f64(-1e2).tanh().to_f64(), -1.0);
+}
+#[test]
+fn single_and_double_precision()
+{
+    assert_eq!(<f32 as Float>::from_f64(0.1), 0.1_f32);
+    assert_eq!(Float::powi(2.0_f32, 3), 8.0);
+    assert_eq!(Float::ln(Float::exp(1.0_f64)), 1.0);
+    assert_eq!(Float::abs(-2.0_f64), 2.0);
+    assert_eq!(<f64 as Float>::to_f64(0.5), 0.5);
+}
@@ -0,0 +1,163 @@
+mod test;
+
+static INITIAL_POINTS: usize = 9;
+static MAXIMUM_POINTS: usize = 1 << 20;
+
+/// The structure of a monotone piecewise-cubic Hermite interpolant of a function of a real argument.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interpolant
+{
+    /// The increasing values of the argument at the nodes.
+    pub abscissa: Vec<f64>,
+
+    /// The values of the function at the nodes.
+    pub ordinate: Vec<f64>,
+
+    /// The slopes of the interpolant at the nodes.
+    pub slopes: Vec<f64>
+}
+
+/// The implemented functionality of interpolants.
+impl Interpolant
+{
+    /// Initializes and returns an interpolant of the function from the minimum to the maximum of the argument, adaptively bisecting the intervals until the interpolant at the midpoint of each is within the tolerance of the function, relative to its magnitude where that magnitude exceeds one.
+    ///
+    /// Only the intervals that were bisected, and their neighbors whose slopes at the shared nodes changed as a result, are checked again on each pass.
+    pub fn init<F>(function: F, minimum: &f64, maximum: &f64, tolerance: &f64) -> Interpolant
+    where F: Fn(&f64) -> f64
+    {
+        let step = (maximum - minimum)/((INITIAL_POINTS - 1) as f64);
+        let abscissa = (0..INITIAL_POINTS).map(|index| minimum + (index as f64)*step).collect::<Vec<f64>>();
+        let ordinate = abscissa.iter().map(&function).collect::<Vec<f64>>();
+        let mut interpolant = Interpolant::from_points(abscissa, ordinate);
+        let mut unchecked = vec![true; INITIAL_POINTS - 1];
+        while unchecked.contains(&true)
+        {
+            let mut abscissa = vec![interpolant.abscissa[0]];
+            let mut ordinate = vec![interpolant.ordinate[0]];
+            let mut bisected = Vec::with_capacity(unchecked.len());
+            for index in 1..interpolant.abscissa.len()
+            {
+                let lower = interpolant.abscissa[index - 1];
+                let upper = interpolant.abscissa[index];
+                let midpoint = 0.5*(lower + upper);
+                let mut bisect = false;
+                if unchecked[index - 1] && midpoint > lower && midpoint < upper && interpolant.abscissa.len() + abscissa.len() < MAXIMUM_POINTS
+                {
+                    let value = function(&midpoint);
+                    if (interpolant.evaluate(&midpoint) - value).abs() > tolerance*value.abs().max(1.0)
+                    {
+                        abscissa.push(midpoint);
+                        ordinate.push(value);
+                        bisect = true;
+                    }
+                }
+                abscissa.push(upper);
+                ordinate.push(interpolant.ordinate[index]);
+                bisected.push(bisect);
+            }
+            unchecked = bisected.iter().enumerate().flat_map(|(index, bisect)|
+            {
+                let neighbor = (index > 0 && bisected[index - 1]) || (index + 1 < bisected.len() && bisected[index + 1]);
+                if *bisect
+                {
+                    vec![true, true]
+                }
+                else
+                {
+                    vec![neighbor]
+                }
+            }).collect();
+            interpolant = Interpolant::from_points(abscissa, ordinate);
+        }
+        interpolant
+    }
+    /// Initializes and returns an interpolant through the given nodes, which must have increasing values of the argument, using slopes that preserve the monotonicity of the data.
+    pub fn from_points(abscissa: Vec<f64>, ordinate: Vec<f64>) -> Interpolant
+    {
+        let widths = abscissa.windows(2).map(|pair| pair[1] - pair[0]).collect::<Vec<f64>>();
+        let secants = ordinate.windows(2).zip(widths.iter()).map(|(pair, width)| (pair[1] - pair[0])/width).collect::<Vec<f64>>();
+        let number_of_intervals = widths.len();
+        let mut slopes = vec![0.0; abscissa.len()];
+        if number_of_intervals == 1
+        {
+            slopes = vec![secants[0]; 2];
+        }
+        else if number_of_intervals > 1
+        {
+            for index in 1..number_of_intervals
+            {
+                if secants[index - 1]*secants[index] > 0.0
+                {
+                    let lower_weight = 2.0*widths[index] + widths[index - 1];
+                    let upper_weight = widths[index] + 2.0*widths[index - 1];
+                    slopes[index] = (lower_weight + upper_weight)/(lower_weight/secants[index - 1] + upper_weight/secants[index]);
+                }
+            }
+            slopes[0] = end_slope(&widths[0], &widths[1], &secants[0], &secants[1]);
+            slopes[number_of_intervals] = end_slope(&widths[number_of_intervals - 1], &widths[number_of_intervals - 2], &secants[number_of_intervals - 1], &secants[number_of_intervals - 2]);
+        }
+        Interpolant
+        {
+            abscissa,
+            ordinate,
+            slopes
+        }
+    }
+    /// The smallest value of the argument at the nodes.
+    pub fn minimum(&self) -> f64
+    {
+        self.abscissa[0]
+    }
+    /// The largest value of the argument at the nodes.
+    pub fn maximum(&self) -> f64
+    {
+        self.abscissa[self.abscissa.len() - 1]
+    }
+    /// The value of the interpolant, which extrapolates with the cubic of the nearest interval outside of the nodes.
+    pub fn evaluate(&self, argument: &f64) -> f64
+    {
+        let last = self.abscissa.len() - 1;
+        if last == 0
+        {
+            return self.ordinate[0]
+        }
+        let index = self.abscissa.partition_point(|node| node <= argument).clamp(1, last);
+        let width = self.abscissa[index] - self.abscissa[index - 1];
+        let t = (argument - self.abscissa[index - 1])/width;
+        let secant = (self.ordinate[index] - self.ordinate[index - 1])/width;
+        let lower_slope = self.slopes[index - 1];
+        let upper_slope = self.slopes[index];
+        self.ordinate[index - 1] + t*width*(lower_slope + t*((3.0*secant - 2.0*lower_slope - upper_slope) + t*(lower_slope + upper_slope - 2.0*secant)))
+    }
+    /// Returns the interpolant in JSON format.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String
+    {
+        serde_json::to_string(self).expect("interpolants always serialize")
+    }
+    /// Returns the interpolant read from JSON format, or the error from parsing it.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Interpolant, serde_json::Error>
+    {
+        serde_json::from_str(json)
+    }
+}
+
+fn end_slope(width: &f64, next_width: &f64, secant: &f64, next_secant: &f64) -> f64
+{
+    let slope = ((2.0*width + next_width)*secant - width*next_secant)/(width + next_width);
+    if slope*secant <= 0.0
+    {
+        0.0
+    }
+    else if secant*next_secant <= 0.0 && slope.abs() > 3.0*secant.abs()
+    {
+        3.0*secant
+    }
+    else
+    {
+        slope
+    }
+}
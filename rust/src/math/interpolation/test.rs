@@ -0,0 +1,79 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static NUMBER_OF_LOOPS: u32 = 888;
+#[test]
+fn nodes()
+{
+    let interpolant = Interpolant::init(|x| x.exp(), &-1.0, &2.0, &1e-6);
+    interpolant.abscissa.iter().zip(interpolant.ordinate.iter()).for_each(|(node, value)|
+        assert_eq!(interpolant.evaluate(node), *value)
+    );
+    assert_eq!(interpolant.minimum(), -1.0);
+    assert_eq!(interpolant.maximum(), 2.0);
+}
+#[test]
+fn linear()
+{
+    let interpolant = Interpolant::from_points(vec![0.0, 1.0, 3.0, 4.0], vec![1.0, 3.0, 7.0, 9.0]);
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let argument = 4.0*rng.gen::<f64>();
+        assert!((interpolant.evaluate(&argument) - (1.0 + 2.0*argument)).abs() <= 1e-12);
+    }
+}
+#[test]
+fn tolerance()
+{
+    let mut rng = rand::thread_rng();
+    for tolerance in [1e-4, 1e-6, 1e-8]
+    {
+        let interpolant = Interpolant::init(|x| x/(1.0 - x), &0.0, &0.99, &tolerance);
+        for _ in 0..NUMBER_OF_LOOPS
+        {
+            let argument = 0.99*rng.gen::<f64>();
+            let value = argument/(1.0 - argument);
+            assert!((interpolant.evaluate(&argument) - value).abs() <= 1e1*tolerance*value.max(1.0));
+        }
+    }
+}
+#[test]
+fn adaptive()
+{
+    let interpolant = Interpolant::init(|x| x/(1.0 - x), &0.0, &0.99, &1e-6);
+    let widths = interpolant.abscissa.windows(2).map(|pair| pair[1] - pair[0]).collect::<Vec<f64>>();
+    assert!(widths[widths.len() - 1] < 1e-1*widths[0]);
+}
+#[test]
+fn evaluations()
+{
+    let count = std::cell::Cell::new(0);
+    let interpolant = Interpolant::init(|x| {count.set(count.get() + 1); x/(1.0 - x)}, &0.0, &0.99, &1e-8);
+    assert!(count.get() < 3*interpolant.abscissa.len());
+}
+#[test]
+fn monotone()
+{
+    let interpolant = Interpolant::from_points(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], vec![0.0, 0.0, 0.1, 5.0, 5.0, 5.1]);
+    let mut previous = interpolant.evaluate(&0.0);
+    for index in 1..=1000
+    {
+        let value = interpolant.evaluate(&(5e-3*(index as f64)));
+        assert!(value >= previous);
+        previous = value;
+    }
+}
+#[test]
+fn single_interval()
+{
+    let interpolant = Interpolant::from_points(vec![1.0, 2.0], vec![3.0, 1.0]);
+    assert_eq!(interpolant.evaluate(&1.5), 2.0);
+}
+#[cfg(feature = "serde")]
+#[test]
+fn json()
+{
+    let interpolant = Interpolant::init(|x| x.sin(), &0.0, &1.0, &1e-8);
+    assert_eq!(Interpolant::from_json(&interpolant.to_json()).unwrap(), interpolant);
+}
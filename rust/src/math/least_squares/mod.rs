@@ -0,0 +1,185 @@
+mod test;
+
+static MAXIMUM_ITERATIONS: u32 = 1024;
+static INITIAL_DAMPING: f64 = 1e-3;
+static MAXIMUM_DAMPING: f64 = 1e16;
+
+/// The structure of the minimum of a sum of squared residuals.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minimum
+{
+    /// The values of the parameters at the minimum.
+    pub parameters: Vec<f64>,
+
+    /// The residuals at the minimum.
+    pub residuals: Vec<f64>,
+
+    /// The jacobian of the residuals with respect to the parameters at the minimum, with a row for each residual.
+    pub jacobian: Vec<Vec<f64>>,
+
+    /// The number of iterations taken.
+    pub iterations: u32,
+
+    /// Whether the iterations converged before the maximum number of iterations, rather than stopping there or at the maximum damping.
+    pub converged: bool
+}
+
+/// The implemented functionality of minima of sums of squared residuals.
+impl Minimum
+{
+    /// The sum of the squared residuals.
+    pub fn sum_of_squared_residuals(&self) -> f64
+    {
+        self.residuals.iter().map(|residual| residual.powi(2)).sum()
+    }
+    /// The number of residuals in excess of the number of parameters.
+    pub fn degrees_of_freedom(&self) -> usize
+    {
+        self.residuals.len().saturating_sub(self.parameters.len())
+    }
+    /// The asymptotic covariance of the parameters, the inverse of the normal matrix scaled by the residual variance, or none if the normal matrix is singular or there are no degrees of freedom.
+    pub fn covariance(&self) -> Option<Vec<Vec<f64>>>
+    {
+        if self.degrees_of_freedom() == 0
+        {
+            return None
+        }
+        let variance = self.sum_of_squared_residuals()/(self.degrees_of_freedom() as f64);
+        inverse(&normal_matrix(&self.jacobian)).map(|matrix| matrix.iter().map(|row| row.iter().map(|entry| entry*variance).collect()).collect())
+    }
+}
+
+/// Returns the minimum of the sum of squared residuals using the Levenberg-Marquardt algorithm, starting from the initial values of the parameters.
+///
+/// The jacobian is approximated using central differences, and the damping is scaled by the diagonal of the normal matrix so that the iterations do not depend on the units of each parameter.
+/// Steps where any residual is not finite are rejected like steps that increase the sum of squares, so the residuals may be NaN where the parameters are invalid.
+/// The iterations converge once a step changes each parameter by less than the tolerance relative to its value, and stop without converging if no step decreases the sum of squares below the maximum damping.
+pub fn levenberg_marquardt<F>(residuals: F, initial: &[f64], tolerance: &f64) -> Minimum
+where F: Fn(&[f64]) -> Vec<f64>
+{
+    let mut parameters = initial.to_vec();
+    let mut current = residuals(&parameters);
+    let mut cost = sum_of_squares(&current);
+    let mut damping = INITIAL_DAMPING;
+    let mut converged = false;
+    let mut iterations = 0;
+    while !converged && iterations < MAXIMUM_ITERATIONS
+    {
+        iterations += 1;
+        let jacobian = central_difference(&residuals, &parameters);
+        let normal = normal_matrix(&jacobian);
+        let gradient: Vec<f64> = (0..parameters.len()).map(|column| -jacobian.iter().zip(current.iter()).map(|(row, residual)| row[column]*residual).sum::<f64>()).collect();
+        while damping <= MAXIMUM_DAMPING
+        {
+            let damped: Vec<Vec<f64>> = normal.iter().enumerate().map(|(index, row)|
+            {
+                let mut row = row.clone();
+                row[index] += damping*normal[index][index].max(f64::MIN_POSITIVE);
+                row
+            }).collect();
+            let step = match solve(&damped, &gradient)
+            {
+                Some(step) => step,
+                None =>
+                {
+                    damping *= 10.0;
+                    continue
+                }
+            };
+            converged = step.iter().zip(parameters.iter()).all(|(change, parameter)| change.abs() <= tolerance*parameter.abs().max(f64::MIN_POSITIVE));
+            let trial: Vec<f64> = parameters.iter().zip(step.iter()).map(|(parameter, change)| parameter + change).collect();
+            let trial_residuals = residuals(&trial);
+            let trial_cost = sum_of_squares(&trial_residuals);
+            if trial_cost.is_finite() && trial_cost <= cost
+            {
+                parameters = trial;
+                current = trial_residuals;
+                cost = trial_cost;
+                damping = (0.1*damping).max(f64::EPSILON);
+                break
+            }
+            if converged
+            {
+                break
+            }
+            damping *= 10.0;
+        }
+        if damping > MAXIMUM_DAMPING
+        {
+            break
+        }
+    }
+    let jacobian = central_difference(&residuals, &parameters);
+    Minimum
+    {
+        parameters,
+        residuals: current,
+        jacobian,
+        iterations,
+        converged
+    }
+}
+
+/// Returns the inverse of a square matrix using Gauss-Jordan elimination with partial pivoting, or none if the matrix is singular.
+pub fn inverse(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>>
+{
+    let size = matrix.len();
+    let columns: Option<Vec<Vec<f64>>> = (0..size).map(|column| solve(matrix, &(0..size).map(|row| if row == column {1.0} else {0.0}).collect::<Vec<f64>>())).collect();
+    columns.map(|columns| (0..size).map(|row| columns.iter().map(|column| column[row]).collect()).collect())
+}
+
+/// Returns the solution of a square linear system using Gaussian elimination with partial pivoting, or none if the matrix is singular.
+pub fn solve(matrix: &[Vec<f64>], vector: &[f64]) -> Option<Vec<f64>>
+{
+    let size = vector.len();
+    let mut augmented: Vec<Vec<f64>> = matrix.iter().zip(vector.iter()).map(|(row, entry)| row.iter().chain(std::iter::once(entry)).copied().collect()).collect();
+    for column in 0..size
+    {
+        let pivot = (column..size).max_by(|a, b| augmented[*a][column].abs().total_cmp(&augmented[*b][column].abs()))?;
+        if augmented[pivot][column] == 0.0 || !augmented[pivot][column].is_finite()
+        {
+            return None
+        }
+        augmented.swap(column, pivot);
+        for row in column + 1..size
+        {
+            let factor = augmented[row][column]/augmented[column][column];
+            let pivot_row = augmented[column].clone();
+            augmented[row].iter_mut().zip(pivot_row.iter()).skip(column).for_each(|(entry, pivot)| *entry -= factor*pivot);
+        }
+    }
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev()
+    {
+        solution[row] = (augmented[row][size] - (row + 1..size).map(|index| augmented[row][index]*solution[index]).sum::<f64>())/augmented[row][row];
+    }
+    Some(solution)
+}
+
+fn sum_of_squares(residuals: &[f64]) -> f64
+{
+    residuals.iter().map(|residual| residual.powi(2)).sum()
+}
+
+fn normal_matrix(jacobian: &[Vec<f64>]) -> Vec<Vec<f64>>
+{
+    let size = jacobian.first().map_or(0, |row| row.len());
+    (0..size).map(|a| (0..size).map(|b| jacobian.iter().map(|row| row[a]*row[b]).sum()).collect()).collect()
+}
+
+fn central_difference<F>(residuals: &F, parameters: &[f64]) -> Vec<Vec<f64>>
+where F: Fn(&[f64]) -> Vec<f64>
+{
+    let columns: Vec<Vec<f64>> = (0..parameters.len()).map(|index|
+    {
+        let step = f64::EPSILON.cbrt()*parameters[index].abs().max(f64::EPSILON.cbrt());
+        let mut forward = parameters.to_vec();
+        let mut backward = parameters.to_vec();
+        forward[index] += step;
+        backward[index] -= step;
+        let width = forward[index] - backward[index];
+        residuals(&forward).iter().zip(residuals(&backward).iter()).map(|(upper, lower)| (upper - lower)/width).collect()
+    }).collect();
+    let size = columns.first().map_or(0, |column| column.len());
+    (0..size).map(|row| columns.iter().map(|column| column[row]).collect()).collect()
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static NUMBER_OF_LOOPS: u32 = 88;
+#[test]
+fn solve_and_inverse()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let matrix: Vec<Vec<f64>> = (0..4).map(|row| (0..4).map(|column| if row == column {4.0} else {0.0} + rng.gen::<f64>()).collect()).collect();
+        let solution: Vec<f64> = (0..4).map(|_| 0.5 - rng.gen::<f64>()).collect();
+        let vector: Vec<f64> = matrix.iter().map(|row| row.iter().zip(solution.iter()).map(|(entry, value)| entry*value).sum()).collect();
+        solve(&matrix, &vector).unwrap().iter().zip(solution.iter()).for_each(|(found, value)| assert!((found - value).abs() <= 1e-12));
+        let inverse = inverse(&matrix).unwrap();
+        for (row, matrix_row) in matrix.iter().enumerate()
+        {
+            for column in 0..4
+            {
+                let product: f64 = matrix_row.iter().zip(inverse.iter()).map(|(entry, inverse_row)| entry*inverse_row[column]).sum();
+                assert!((product - if row == column {1.0} else {0.0}).abs() <= 1e-12);
+            }
+        }
+    }
+    assert_eq!(solve(&[vec![1.0, 2.0], vec![2.0, 4.0]], &[1.0, 2.0]), None);
+}
+#[test]
+fn linear()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x: Vec<f64> = (0..25).map(|index| index as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x| 2.0 + 3.0*x + 0.5 - rng.gen::<f64>()).collect();
+        let minimum = levenberg_marquardt(|p| x.iter().zip(y.iter()).map(|(x, y)| p[0] + p[1]*x - y).collect(), &[1.0, 1.0], &1e-12);
+        assert!(minimum.converged);
+        let n = x.len() as f64;
+        let x_mean = x.iter().sum::<f64>()/n;
+        let y_mean = y.iter().sum::<f64>()/n;
+        let sxx = x.iter().map(|x| (x - x_mean).powi(2)).sum::<f64>();
+        let slope = x.iter().zip(y.iter()).map(|(x, y)| (x - x_mean)*(y - y_mean)).sum::<f64>()/sxx;
+        let intercept = y_mean - slope*x_mean;
+        assert!((minimum.parameters[0] - intercept).abs() <= 1e-8);
+        assert!((minimum.parameters[1] - slope).abs() <= 1e-8);
+        let variance = minimum.sum_of_squared_residuals()/(n - 2.0);
+        let covariance = minimum.covariance().unwrap();
+        assert!((covariance[1][1]/(variance/sxx) - 1.0).abs() <= 1e-6);
+        assert!((covariance[0][1]/(-variance*x_mean/sxx) - 1.0).abs() <= 1e-6);
+    }
+}
+#[test]
+fn exponential()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let amplitude = 1.0 + rng.gen::<f64>();
+        let rate = 0.5 + rng.gen::<f64>();
+        let x: Vec<f64> = (0..50).map(|index| 0.1*(index as f64)).collect();
+        let y: Vec<f64> = x.iter().map(|x| amplitude*(-rate*x).exp()).collect();
+        let minimum = levenberg_marquardt(|p| x.iter().zip(y.iter()).map(|(x, y)| p[0]*(-p[1]*x).exp() - y).collect(), &[1.0, 1.0], &1e-12);
+        assert!(minimum.converged);
+        assert!((minimum.parameters[0]/amplitude - 1.0).abs() <= 1e-8);
+        assert!((minimum.parameters[1]/rate - 1.0).abs() <= 1e-8);
+    }
+}
+#[test]
+fn invalid_region()
+{
+    let x: Vec<f64> = (1..20).map(|index| index as f64).collect();
+    let y: Vec<f64> = x.iter().map(|x| 0.01*x.ln()).collect();
+    let minimum = levenberg_marquardt(|p| x.iter().zip(y.iter()).map(|(x, y)| p[0]*x.ln() - y + p[0].ln() - 0.01_f64.ln()).collect(), &[1.0], &1e-12);
+    assert!(minimum.converged);
+    assert!((minimum.parameters[0] - 0.01).abs() <= 1e-10);
+}
+#[test]
+fn maximum_damping()
+{
+    let minimum = levenberg_marquardt(|p| if p[0] == 1.0 {vec![1.0, 1.0]} else {vec![f64::NAN, f64::NAN]}, &[1.0], &1e-12);
+    assert!(!minimum.converged);
+    assert_eq!(minimum.parameters, vec![1.0]);
+}
@@ -0,0 +1,26 @@
+/// Complex numbers and elementary functions of complex arguments.
+pub mod complex;
+
+/// Dual numbers for forward-mode automatic differentiation.
+pub mod dual;
+
+/// Floating-point types that models can be evaluated with, including a double-double type for extended precision.
+pub mod float;
+
+/// Monotone piecewise-cubic interpolation of tabulated functions.
+pub mod interpolation;
+
+/// Nonlinear least-squares minimization using the Levenberg-Marquardt algorithm.
+pub mod least_squares;
+
+/// Seeded pseudorandom number generation for reproducible sampling.
+pub mod random;
+
+/// Root-finding algorithms for nonlinear scalar equations.
+pub mod roots;
+
+/// Markov chain Monte Carlo sampling of densities and estimation of their normalizations.
+pub mod sampling;
+
+/// Special functions of real arguments.
+pub mod special;
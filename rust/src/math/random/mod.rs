@@ -0,0 +1,80 @@
+mod test;
+
+use std::f64::consts::PI;
+
+/// The structure of a seeded pseudorandom number generator using the xoshiro256** algorithm.
+///
+/// The same seed always produces the same sequence, so sampling is reproducible.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Random
+{
+    state: [u64; 4]
+}
+
+/// The implemented functionality of pseudorandom number generators.
+impl Random
+{
+    /// Initializes and returns a generator, expanding the seed into its state using the splitmix64 algorithm.
+    pub fn init(seed: u64) -> Random
+    {
+        let mut seed = seed;
+        let mut state = [0; 4];
+        for entry in state.iter_mut()
+        {
+            seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            *entry = z ^ (z >> 31);
+        }
+        Random
+        {
+            state
+        }
+    }
+    /// Returns the next 64 pseudorandom bits.
+    pub fn next_u64(&mut self) -> u64
+    {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let shifted = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= shifted;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+    /// Returns a pseudorandom number uniformly distributed on the half-open interval from zero to one.
+    pub fn uniform(&mut self) -> f64
+    {
+        ((self.next_u64() >> 11) as f64)/((1_u64 << 53) as f64)
+    }
+    /// Returns a pseudorandom number with the standard normal distribution, using the Box-Muller transform.
+    pub fn normal(&mut self) -> f64
+    {
+        let radius = (-2.0*(1.0 - self.uniform()).ln()).sqrt();
+        radius*(2.0*PI*self.uniform()).cos()
+    }
+    /// Returns a pseudorandom number between minus one and one with density proportional to the exponential of the tilt times the number, by inverting the cumulative distribution.
+    ///
+    /// This is the distribution of the cosine of the angle between a freely-jointed link and an applied force, with the tilt being the nondimensional force.
+    pub fn tilted_uniform(&mut self, tilt: &f64) -> f64
+    {
+        let uniform = self.uniform();
+        if tilt == &0.0
+        {
+            2.0*uniform - 1.0
+        }
+        else
+        {
+            let magnitude = tilt.abs();
+            tilt.signum()*(1.0 + ((1.0 - uniform)*(-2.0*magnitude).exp_m1()).ln_1p()/magnitude)
+        }
+    }
+    /// Returns a pseudorandom index uniformly distributed below the length.
+    pub fn index(&mut self, length: usize) -> usize
+    {
+        ((self.uniform()*(length as f64)) as usize).min(length - 1)
+    }
+}
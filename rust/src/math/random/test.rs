@@ -0,0 +1,55 @@
+#![cfg(test)]
+use super::*;
+static NUMBER_OF_SAMPLES: usize = 88888;
+#[test]
+fn reproducible()
+{
+    let mut first = Random::init(8);
+    let mut second = Random::init(8);
+    let mut third = Random::init(9);
+    let sequence: Vec<u64> = (0..8).map(|_| first.next_u64()).collect();
+    assert_eq!(sequence, (0..8).map(|_| second.next_u64()).collect::<Vec<u64>>());
+    assert_ne!(sequence, (0..8).map(|_| third.next_u64()).collect::<Vec<u64>>());
+}
+#[test]
+fn uniform()
+{
+    let mut random = Random::init(88);
+    let samples: Vec<f64> = (0..NUMBER_OF_SAMPLES).map(|_| random.uniform()).collect();
+    assert!(samples.iter().all(|sample| (0.0..1.0).contains(sample)));
+    let mean = samples.iter().sum::<f64>()/(NUMBER_OF_SAMPLES as f64);
+    let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>()/(NUMBER_OF_SAMPLES as f64);
+    assert!((mean - 0.5).abs() <= 1e-2);
+    assert!((variance - 1.0/12.0).abs() <= 1e-2);
+}
+#[test]
+fn normal()
+{
+    let mut random = Random::init(888);
+    let samples: Vec<f64> = (0..NUMBER_OF_SAMPLES).map(|_| random.normal()).collect();
+    let mean = samples.iter().sum::<f64>()/(NUMBER_OF_SAMPLES as f64);
+    let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>()/(NUMBER_OF_SAMPLES as f64);
+    assert!(mean.abs() <= 1e-2);
+    assert!((variance - 1.0).abs() <= 2e-2);
+}
+#[test]
+fn index()
+{
+    let mut random = Random::init(8888);
+    let mut counts = [0; 8];
+    (0..NUMBER_OF_SAMPLES).for_each(|_| counts[random.index(8)] += 1);
+    assert!(counts.iter().all(|count| ((*count as f64)/(NUMBER_OF_SAMPLES as f64) - 0.125).abs() <= 1e-2));
+}
+#[test]
+fn tilted_uniform()
+{
+    let mut random = Random::init(88888);
+    for tilt in [-50.0, -1.0, 0.0, 1e-12, 1.0, 50.0]
+    {
+        let samples: Vec<f64> = (0..NUMBER_OF_SAMPLES).map(|_| random.tilted_uniform(&tilt)).collect();
+        assert!(samples.iter().all(|sample| (-1.0..=1.0).contains(sample)));
+        let mean = samples.iter().sum::<f64>()/(NUMBER_OF_SAMPLES as f64);
+        let langevin = if tilt == 0.0 {0.0} else {1.0/tilt.tanh() - 1.0/tilt};
+        assert!((mean - langevin).abs() <= 1e-2);
+    }
+}
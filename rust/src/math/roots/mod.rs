@@ -0,0 +1,197 @@
+mod test;
+
+use crate::math::float::Float;
+
+static MAXIMUM_ITERATIONS: u32 = 1024;
+
+/// Returns bounds bracketing the root of an increasing function, searching outward from the guess within the open interval between the lower and upper limits.
+///
+/// Toward a finite limit the search moves halfway to the limit each step, and toward an infinite limit it doubles its step, so the limits themselves are never evaluated.
+pub fn bracket<F>(function: F, guess: &f64, lower_limit: &f64, upper_limit: &f64) -> (f64, f64)
+where F: Fn(&f64) -> f64
+{
+    let mut lower = *guess;
+    let mut upper = *guess;
+    let mut step = if guess == &0.0 {1.0} else {guess.abs()};
+    if function(guess) < 0.0
+    {
+        for _ in 0..MAXIMUM_ITERATIONS
+        {
+            let next = if upper_limit.is_finite() {0.5*(upper + upper_limit)} else {upper + step};
+            if next == upper || &next == upper_limit
+            {
+                break
+            }
+            lower = upper;
+            upper = next;
+            step *= 2.0;
+            if function(&upper) >= 0.0
+            {
+                break
+            }
+        }
+    }
+    else
+    {
+        for _ in 0..MAXIMUM_ITERATIONS
+        {
+            let next = if lower_limit.is_finite() {0.5*(lower + lower_limit)} else {lower - step};
+            if next == lower || &next == lower_limit
+            {
+                break
+            }
+            upper = lower;
+            lower = next;
+            step *= 2.0;
+            if function(&lower) <= 0.0
+            {
+                break
+            }
+        }
+    }
+    (lower, upper)
+}
+
+/// Returns the root of a function bracketed by the lower and upper bounds using Brent's method.
+///
+/// The function is evaluated at the bounds, which must bracket a root; inverse quadratic interpolation and secant steps are taken when they stay within the bracket and converge quickly enough, with bisection otherwise, so convergence is guaranteed.
+pub fn brent<F>(function: F, lower: &f64, upper: &f64) -> f64
+where F: Fn(&f64) -> f64
+{
+    let mut a = *lower;
+    let mut b = *upper;
+    let mut c = *upper;
+    let mut d = 0.0;
+    let mut e = 0.0;
+    let mut function_a = function(&a);
+    let mut function_b = function(&b);
+    if function_a == 0.0
+    {
+        return a
+    }
+    let mut function_c = function_b;
+    for _ in 0..MAXIMUM_ITERATIONS
+    {
+        if function_b == 0.0
+        {
+            break
+        }
+        if function_b.signum() == function_c.signum()
+        {
+            c = a;
+            function_c = function_a;
+            d = b - a;
+            e = d;
+        }
+        if function_c.abs() < function_b.abs()
+        {
+            a = b;
+            b = c;
+            c = a;
+            function_a = function_b;
+            function_b = function_c;
+            function_c = function_a;
+        }
+        let tolerance = 2.0*f64::EPSILON*b.abs() + 0.5*f64::MIN_POSITIVE;
+        let midpoint = 0.5*(c - b);
+        if midpoint.abs() <= tolerance
+        {
+            break
+        }
+        if e.abs() >= tolerance && function_a.abs() > function_b.abs()
+        {
+            let s = function_b/function_a;
+            let (mut p, mut q) = if a == c
+            {
+                (2.0*midpoint*s, 1.0 - s)
+            }
+            else
+            {
+                let q = function_a/function_c;
+                let r = function_b/function_c;
+                (s*(2.0*midpoint*q*(q - r) - (b - a)*(r - 1.0)), (q - 1.0)*(r - 1.0)*(s - 1.0))
+            };
+            if p > 0.0
+            {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0*p < (3.0*midpoint*q - (tolerance*q).abs()).min((e*q).abs())
+            {
+                e = d;
+                d = p/q;
+            }
+            else
+            {
+                d = midpoint;
+                e = d;
+            }
+        }
+        else
+        {
+            d = midpoint;
+            e = d;
+        }
+        a = b;
+        function_a = function_b;
+        b += if d.abs() > tolerance {d} else {tolerance.copysign(midpoint)};
+        function_b = function(&b);
+    }
+    b
+}
+
+/// Returns the root of an increasing function on the open interval between the lower and upper bounds using Newton's method safeguarded by bisection.
+///
+/// Newton steps that would leave the current bracket or fail to halve it are replaced by bisection steps, so convergence is guaranteed, and the bounds themselves are never evaluated.
+pub fn newton<F, G>(function: F, derivative: G, lower: &f64, upper: &f64) -> f64
+where F: Fn(&f64) -> f64, G: Fn(&f64) -> f64
+{
+    let mut lower = *lower;
+    let mut upper = *upper;
+    let mut root = 0.5*(lower + upper);
+    let mut step = upper - lower;
+    for _ in 0..MAXIMUM_ITERATIONS
+    {
+        let value = function(&root);
+        if value == 0.0
+        {
+            break
+        }
+        else if value < 0.0
+        {
+            lower = root;
+        }
+        else
+        {
+            upper = root;
+        }
+        let newton_root = root - value/derivative(&root);
+        let previous_step = step;
+        let next = if newton_root > lower && newton_root < upper && (newton_root - root).abs() < 0.5*previous_step.abs()
+        {
+            newton_root
+        }
+        else
+        {
+            0.5*(lower + upper)
+        };
+        step = next - root;
+        if next == root || step.abs() <= f64::EPSILON*root.abs()
+        {
+            root = next;
+            break
+        }
+        root = next;
+    }
+    root
+}
+
+/// Returns the root of a function in any floating-point type, refining the root found in double precision with a single Newton step using the derivative there.
+///
+/// One step recovers the full precision of extended types from a converged double-precision root, and propagates the derivatives of dual numbers through the root as the implicit function theorem requires.
+pub fn refine<F, G>(function: G, root: &f64, derivative: &f64) -> F
+where F: Float, G: Fn(&F) -> F
+{
+    let root = F::from_f64(*root);
+    root - function(&root)/F::from_f64(*derivative)
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static NUMBER_OF_LOOPS: u32 = 888;
+#[test]
+fn bracket_infinite()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let root = 1e3*(0.5 - rng.gen::<f64>());
+        let guess = 2.0*(0.5 - rng.gen::<f64>());
+        let (lower, upper) = bracket(|x| x - root, &guess, &f64::NEG_INFINITY, &f64::INFINITY);
+        assert!(lower <= root && root <= upper);
+    }
+}
+#[test]
+fn bracket_singular_limits()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let target = 1e3*rng.gen::<f64>();
+        let guess = rng.gen::<f64>();
+        let function = |x: &f64| x/(1.0 - x) - target;
+        let (lower, upper) = bracket(function, &guess, &0.0, &1.0);
+        assert!(lower > 0.0 && upper < 1.0);
+        assert!(function(&lower) <= 0.0 && function(&upper) >= 0.0);
+    }
+}
+#[test]
+fn brent_cubic()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let root = 2.0*(0.5 - rng.gen::<f64>());
+        let found = brent(|x| (x - root).powi(3) + (x - root), &-1.0, &1.0);
+        assert!((found - root).abs() <= 1e-12);
+    }
+}
+#[test]
+fn brent_steep()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let target = 1e6*rng.gen::<f64>();
+        let function = |x: &f64| x/(1.0 - x).powi(4) - target;
+        let (lower, upper) = bracket(function, &0.5, &0.0, &1.0);
+        let found = brent(function, &lower, &upper);
+        assert!((function(&found)/target).abs() <= 1e-9);
+    }
+}
+#[test]
+fn newton_cubic()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let root = 2.0*(0.5 - rng.gen::<f64>());
+        let found = newton(|x| (x - root).powi(3) + (x - root), |x| 3.0*(x - root).powi(2) + 1.0, &-1.0, &1.0);
+        assert!((found - root).abs() <= 1e-12);
+    }
+}
+#[test]
+fn newton_singular_bounds()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let target = 1e6*rng.gen::<f64>();
+        let found = newton(|x| x/(1.0 - x) - target, |x| 1.0/(1.0 - x).powi(2), &0.0, &1.0);
+        assert!((found/(1.0 - found) - target).abs() <= 1e-9*(1.0 + target));
+    }
+}
+#[test]
+fn refine_dual()
+{
+    use crate::math::dual::Dual;
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let value = 1e2*rng.gen::<f64>();
+        let root = brent(|x| x.powi(3) - value, &0.0, &5.0);
+        let refined: Dual = refine(|x: &Dual| x.powi(3) - Dual::variable(value), &root, &(3.0*root.powi(2)));
+        assert!((refined.value/value.cbrt() - 1.0).abs() <= 1e-12);
+        assert!((refined.derivative*3.0*value.cbrt().powi(2) - 1.0).abs() <= 1e-12);
+    }
+}
@@ -0,0 +1,114 @@
+mod test;
+use crate::math::random::Random;
+
+static STRETCH: f64 = 2.0;
+
+/// The structure of a chain of samples drawn by an ensemble of walkers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chain
+{
+    /// The positions of every walker after each step, ordered by step and then by walker.
+    pub samples: Vec<Vec<f64>>,
+
+    /// The logarithm of the density at each sample.
+    pub ln_densities: Vec<f64>,
+
+    /// The fraction of proposed moves that were accepted.
+    pub acceptance_fraction: f64
+}
+
+/// The implemented functionality of chains of samples.
+impl Chain
+{
+    /// The positions of the walkers after the last step, from which the chain can be continued.
+    pub fn walkers(&self, number_of_walkers: usize) -> Vec<Vec<f64>>
+    {
+        self.samples[self.samples.len() - number_of_walkers..].to_vec()
+    }
+    /// Returns the chain without the samples of the steps in the burn-in.
+    pub fn discard(&self, number_of_walkers: usize, burn_in: usize) -> Chain
+    {
+        let start = (number_of_walkers*burn_in).min(self.samples.len());
+        Chain
+        {
+            samples: self.samples[start..].to_vec(),
+            ln_densities: self.ln_densities[start..].to_vec(),
+            acceptance_fraction: self.acceptance_fraction
+        }
+    }
+}
+
+/// Returns a chain of samples of the density, given by its logarithm, using the affine-invariant ensemble sampler of Goodman and Weare with stretch moves.
+///
+/// Each step moves every walker in turn along the line through another walker chosen at random, so the sampler adapts to linear correlations and differences of scale between the parameters without tuning.
+/// The logarithm of the density may be negative infinity or NaN outside of the support, and moves there are always rejected.
+pub fn ensemble<F>(ln_density: F, walkers: &[Vec<f64>], steps: &usize, random: &mut Random) -> Chain
+where F: Fn(&[f64]) -> f64
+{
+    let number_of_walkers = walkers.len();
+    let dimension = walkers[0].len();
+    let mut positions = walkers.to_vec();
+    let mut current: Vec<f64> = positions.iter().map(|position| ln_density(position)).collect();
+    let mut samples = Vec::with_capacity(steps*number_of_walkers);
+    let mut ln_densities = Vec::with_capacity(steps*number_of_walkers);
+    let mut accepted = 0;
+    for _ in 0..*steps
+    {
+        for walker in 0..number_of_walkers
+        {
+            let other = (walker + 1 + random.index(number_of_walkers - 1)) % number_of_walkers;
+            let stretch = ((STRETCH - 1.0)*random.uniform() + 1.0).powi(2)/STRETCH;
+            let proposal: Vec<f64> = positions[other].iter().zip(positions[walker].iter()).map(|(anchor, position)| anchor + stretch*(position - anchor)).collect();
+            let proposed = ln_density(&proposal);
+            if random.uniform().ln() < ((dimension - 1) as f64)*stretch.ln() + proposed - current[walker]
+            {
+                positions[walker] = proposal;
+                current[walker] = proposed;
+                accepted += 1;
+            }
+            samples.push(positions[walker].clone());
+            ln_densities.push(current[walker]);
+        }
+    }
+    Chain
+    {
+        samples,
+        ln_densities,
+        acceptance_fraction: (accepted as f64)/((steps*number_of_walkers).max(1) as f64)
+    }
+}
+
+/// Returns the logarithm of the evidence, the integral of the likelihood over the normalized prior, using stepping-stone sampling.
+///
+/// The ensemble sampler is run at each inverse temperature of a ladder ascending from zero to one as the fifth power of evenly spaced points, starting from the walkers and continuing from those of the previous inverse temperature, and discarding the burn-in.
+/// The ratio of the evidences at consecutive inverse temperatures is the mean over the samples at the lower one of the likelihood raised to their difference, which is accurate even where the likelihood is negligible over most of the prior.
+pub fn stepping_stone<F, G>(ln_prior: F, ln_likelihood: G, walkers: &[Vec<f64>], temperatures: &usize, steps: &usize, burn_in: &usize, random: &mut Random) -> f64
+where F: Fn(&[f64]) -> f64, G: Fn(&[f64]) -> f64
+{
+    let ladder: Vec<f64> = (0..=*temperatures).map(|index| ((index as f64)/(*temperatures as f64)).powi(5)).collect();
+    let mut positions = walkers.to_vec();
+    let mut ln_evidence = 0.0;
+    for index in 0..*temperatures
+    {
+        let inverse_temperature = ladder[index];
+        let chain = ensemble(|position|
+        {
+            let ln_prior = ln_prior(position);
+            if ln_prior.is_finite() && inverse_temperature > 0.0 {ln_prior + inverse_temperature*ln_likelihood(position)} else {ln_prior}
+        }, &positions, steps, random);
+        positions = chain.walkers(walkers.len());
+        let samples = chain.discard(walkers.len(), *burn_in).samples;
+        ln_evidence += ln_mean_exp(&samples.iter().map(|sample| (ladder[index + 1] - inverse_temperature)*ln_likelihood(sample)).collect::<Vec<f64>>());
+    }
+    ln_evidence
+}
+
+fn ln_mean_exp(values: &[f64]) -> f64
+{
+    let maximum = values.iter().fold(f64::NEG_INFINITY, |maximum, value| value.max(maximum));
+    if maximum == f64::NEG_INFINITY
+    {
+        return maximum
+    }
+    maximum + (values.iter().map(|value| (value - maximum).exp()).sum::<f64>()/(values.len() as f64)).ln()
+}
@@ -0,0 +1,49 @@
+#![cfg(test)]
+use super::*;
+use std::f64::consts::PI;
+static NUMBER_OF_WALKERS: usize = 16;
+static NUMBER_OF_STEPS: usize = 2000;
+fn initial(random: &mut Random) -> Vec<Vec<f64>>
+{
+    (0..NUMBER_OF_WALKERS).map(|_| vec![1.0 + 1e-2*random.normal(), 1e-2*random.normal()]).collect()
+}
+#[test]
+fn correlated_gaussian()
+{
+    let mut random = Random::init(8);
+    let correlation: f64 = 0.9;
+    let ln_density = |x: &[f64]| -0.5*(x[0].powi(2) - 2.0*correlation*x[0]*x[1]/1e1 + (x[1]/1e1).powi(2))/(1.0 - correlation.powi(2));
+    let walkers = initial(&mut random);
+    let chain = ensemble(ln_density, &walkers, &NUMBER_OF_STEPS, &mut random).discard(NUMBER_OF_WALKERS, NUMBER_OF_STEPS/4);
+    let count = chain.samples.len() as f64;
+    let mean: Vec<f64> = (0..2).map(|index| chain.samples.iter().map(|sample| sample[index]).sum::<f64>()/count).collect();
+    let covariance = |a: usize, b: usize| chain.samples.iter().map(|sample| (sample[a] - mean[a])*(sample[b] - mean[b])).sum::<f64>()/count;
+    assert!(mean[0].abs() <= 1e-1);
+    assert!(mean[1].abs() <= 1e0);
+    assert!((covariance(0, 0) - 1.0).abs() <= 1e-1);
+    assert!((covariance(1, 1)/1e2 - 1.0).abs() <= 1e-1);
+    assert!((covariance(0, 1)/1e1 - correlation).abs() <= 1e-1);
+    assert!(chain.acceptance_fraction > 0.2 && chain.acceptance_fraction < 0.9);
+}
+#[test]
+fn support()
+{
+    let mut random = Random::init(88);
+    let ln_density = |x: &[f64]| if x.iter().all(|x| x > &0.0) {-x.iter().sum::<f64>()} else {f64::NEG_INFINITY};
+    let walkers: Vec<Vec<f64>> = (0..NUMBER_OF_WALKERS).map(|_| vec![1.0 + 1e-1*random.uniform(), 1.0 + 1e-1*random.uniform()]).collect();
+    let chain = ensemble(ln_density, &walkers, &NUMBER_OF_STEPS, &mut random);
+    assert!(chain.samples.iter().all(|sample| sample.iter().all(|x| x > &0.0)));
+    assert!(chain.ln_densities.iter().all(|ln_density| ln_density.is_finite()));
+    assert_eq!(chain.walkers(NUMBER_OF_WALKERS).len(), NUMBER_OF_WALKERS);
+}
+#[test]
+fn evidence()
+{
+    let mut random = Random::init(888);
+    let width = 1e1;
+    let ln_prior = |x: &[f64]| if x.iter().all(|x| x.abs() < 0.5*width) {-2.0*width.ln()} else {f64::NEG_INFINITY};
+    let ln_likelihood = |x: &[f64]| -0.5*x.iter().map(|x| x.powi(2)).sum::<f64>();
+    let walkers: Vec<Vec<f64>> = (0..NUMBER_OF_WALKERS).map(|_| vec![width*(random.uniform() - 0.5), width*(random.uniform() - 0.5)]).collect();
+    let ln_evidence = stepping_stone(ln_prior, ln_likelihood, &walkers, &16, &NUMBER_OF_STEPS, &(NUMBER_OF_STEPS/4), &mut random);
+    assert!((ln_evidence - (2.0*PI/width.powi(2)).ln()).abs() <= 1e-1);
+}
@@ -0,0 +1,231 @@
+#![cfg(test)]
+use super::*;
+use rand::Rng;
+static NUMBER_OF_LOOPS: u32 = 888;
+#[test]
+fn langevin_odd()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e1*rng.gen::<f64>();
+        assert_eq!(langevin(&-x), -langevin(&x));
+        assert_eq!(langevin_derivative(&-x), langevin_derivative(&x));
+    }
+}
+#[test]
+fn langevin_series_continuity()
+{
+    let below = 0.5 - 1e-15;
+    let above = 0.5 + 1e-15;
+    assert!((langevin(&below)/langevin(&above) - 1.0).abs() <= 1e-13);
+    assert!((langevin_derivative(&below)/langevin_derivative(&above) - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn langevin_derivative_finite_difference()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e1*rng.gen::<f64>();
+        let h = 1e-6;
+        let finite_difference = (langevin(&(x + h)) - langevin(&(x - h)))/(2.0*h);
+        assert!((finite_difference - langevin_derivative(&x)).abs() <= 1e-8);
+    }
+}
+#[test]
+fn inverse_langevin_round_trip()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let y = 2.0*(0.5 - rng.gen::<f64>());
+        assert!((langevin(&inverse_langevin(&y))/y - 1.0).abs() <= 1e-13);
+        let x = 1e2*(0.5 - rng.gen::<f64>());
+        assert!((inverse_langevin(&langevin(&x))/x - 1.0).abs() <= 1e-10);
+    }
+}
+#[test]
+fn inverse_langevin_series_continuity()
+{
+    let below = 1e-3 - 1e-18;
+    let above = 1e-3 + 1e-18;
+    assert!((inverse_langevin(&below)/inverse_langevin(&above) - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn inverse_langevin_exact()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let y = 2.0*(0.5 - rng.gen::<f64>());
+        assert_eq!(InverseLangevin::Exact.evaluate(&y), inverse_langevin(&y));
+    }
+}
+#[test]
+fn inverse_langevin_approximants_maximum_error()
+{
+    for (approximant, maximum_error) in [(InverseLangevin::Cohen, 4.94e-2), (InverseLangevin::Jedynak, 1.52e-2), (InverseLangevin::Kroger, 2.8e-3), (InverseLangevin::Petrosyan, 1.8e-3), (InverseLangevin::Bergstrom, 6.4e-4), (InverseLangevin::Rational, 8.3e-4)]
+    {
+        let error = (1..10000).map(|index| 1e-4*(index as f64)).map(|y| (approximant.evaluate(&y)/inverse_langevin(&y) - 1.0).abs()).fold(0.0, f64::max);
+        assert!(error <= maximum_error);
+        assert!(error >= 0.9*maximum_error);
+        assert_eq!(approximant.evaluate(&-0.5), -approximant.evaluate(&0.5));
+    }
+}
+#[test]
+fn langevin_second_derivative_finite_difference()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e1*rng.gen::<f64>();
+        let h = 1e-6;
+        let finite_difference = (langevin_derivative(&(x + h)) - langevin_derivative(&(x - h)))/(2.0*h);
+        assert!((finite_difference - langevin_second_derivative(&x)).abs() <= 1e-8);
+    }
+    let below = 0.5 - 1e-15;
+    let above = 0.5 + 1e-15;
+    assert!((langevin_second_derivative(&below)/langevin_second_derivative(&above) - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn langevin_extreme()
+{
+    assert_eq!(langevin(&0.0), 0.0);
+    assert_eq!(langevin_derivative(&0.0), 1.0/3.0);
+    assert_eq!(langevin_second_derivative(&0.0), 0.0);
+    assert!((langevin(&1e-300)/1e-300*3.0 - 1.0).abs() <= 1e-15);
+    assert!((langevin(&1e300) - 1.0).abs() <= 1e-15);
+    assert!((langevin_derivative(&1e3)*1e6 - 1.0).abs() <= 1e-15);
+    assert!((langevin_second_derivative(&1e3)*5e8 + 1.0).abs() <= 1e-15);
+    assert!((inverse_langevin(&(1.0 - 2.0_f64.powi(-40)))/2.0_f64.powi(40) - 1.0).abs() <= 1e-10);
+}
+#[test]
+fn ln_sinhc_naive()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e2*(0.5 - rng.gen::<f64>());
+        assert!((ln_sinhc(&x) - (x.sinh()/x).ln()).abs() <= 1e-13*(1.0 + x.abs()));
+        assert_eq!(ln_sinhc(&-x), ln_sinhc(&x));
+    }
+    let below = 0.5 - 1e-15;
+    let above = 0.5 + 1e-15;
+    assert!((ln_sinhc(&below)/ln_sinhc(&above) - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn ln_sinhc_extreme()
+{
+    assert_eq!(ln_sinhc(&0.0), 0.0);
+    assert!((ln_sinhc(&1e-100)/1e-200*6.0 - 1.0).abs() <= 1e-15);
+    let x = 1e5;
+    assert!((ln_sinhc(&x)/(x - 2.0_f64.ln() - x.ln()) - 1.0).abs() <= 1e-15);
+    assert!(ln_sinhc(&1e300_f64).is_finite());
+}
+#[test]
+fn bernoulli_values()
+{
+    for (n, value) in [(0, 1.0), (1, -0.5), (2, 1.0/6.0), (4, -1.0/30.0), (6, 1.0/42.0), (8, -1.0/30.0), (10, 5.0/66.0), (12, -691.0/2730.0), (14, 7.0/6.0), (20, -174611.0/330.0)]
+    {
+        assert!((bernoulli(&n)/value - 1.0).abs() <= 1e-14);
+    }
+    for n in (3..=255).step_by(2)
+    {
+        assert_eq!(bernoulli(&n), 0.0);
+    }
+}
+#[test]
+fn bernoulli_series()
+{
+    let factorial = |n: u8| (1..=n).map(|index| index as f64).product::<f64>();
+    for n in 1..=10
+    {
+        let coefficient = 2.0_f64.powi(2*n as i32)*bernoulli(&(2*n))/factorial(2*n);
+        assert!((LANGEVIN_SERIES[(n - 1) as usize]/coefficient - 1.0).abs() <= 1e-14);
+        assert!((LANGEVIN_DERIVATIVE_SERIES[(n - 1) as usize]/coefficient/((2*n - 1) as f64) - 1.0).abs() <= 1e-14);
+        assert!((LN_SINHC_SERIES[(n - 1) as usize]/coefficient*((2*n) as f64) - 1.0).abs() <= 1e-14);
+    }
+}
+#[test]
+fn erf_values()
+{
+    for (x, value) in [(1e-10, 1.1283791670955126e-10), (0.1, 0.1124629160182849), (0.5, 0.5204998778130465), (1.0, 0.8427007929497149), (2.0, 0.9953222650189527), (6.0, 1.0)]
+    {
+        assert!((erf(&x)/value - 1.0).abs() <= 1e-14);
+        assert_eq!(erf(&-x), -erf(&x));
+    }
+    let below = 0.5 - 1e-15;
+    let above = 0.5 + 1e-15;
+    assert!((erf(&below)/erf(&above) - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn erfc_values()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e1*(0.5 - rng.gen::<f64>());
+        assert!((erf(&x) + erfc(&x) - 1.0).abs() <= 1e-15);
+        assert!((erfc(&x) + erfc(&-x) - 2.0).abs() <= 1e-15);
+    }
+    assert!((erfc(&10.0)/2.088487583762545e-45 - 1.0).abs() <= 1e-13);
+}
+#[test]
+fn erf_extreme()
+{
+    assert_eq!(erf(&0.0), 0.0);
+    assert_eq!(erf(&1e3), 1.0);
+    assert_eq!(erf(&-1e3), -1.0);
+    assert_eq!(erfc(&-1e3), 2.0);
+    assert_eq!(erfc(&1e3), 0.0);
+    assert!((erfcx(&1e3)*PI.sqrt()*1e3 - 1.0).abs() <= 1e-6);
+    assert!((erfcx(&1e8)*PI.sqrt()*1e8 - 1.0).abs() <= 1e-15);
+}
+#[test]
+fn bessel_values()
+{
+    for (x, i0, i1) in [(1e-3, 0.9990007495835156, 0.0004995003123542213), (0.5, 0.6450352704491501, 0.1564208031848717), (1.0, 0.46575960759364043, 0.20791041534970844), (10.0, 0.1278333371634286, 0.12126268138445552), (29.9, 0.07326921904600191, 0.07203337491186879), (30.1, 0.07302329413106094, 0.07179985435101434), (50.0, 0.05656162664745419, 0.0559931238928954), (1e3, 0.012617240455891257, 0.012610930256928629)]
+    {
+        assert!((bessel_i0_scaled(&x)/i0 - 1.0).abs() <= 1e-14);
+        assert!((bessel_i1_scaled(&x)/i1 - 1.0).abs() <= 1e-14);
+        assert_eq!(bessel_i0_scaled(&-x), bessel_i0_scaled(&x));
+        assert_eq!(bessel_i1_scaled(&-x), -bessel_i1_scaled(&x));
+        assert!((ln_bessel_i0(&x) - x - i0.ln()).abs() <= 1e-13*x);
+    }
+    assert!((ln_bessel_i0(&(1.0 - 1e-15))/ln_bessel_i0(&(1.0 + 1e-15)) - 1.0).abs() <= 1e-13);
+    assert!((ln_bessel_i0(&1e-5)/2.499999999984375e-11 - 1.0).abs() <= 1e-14);
+    assert_eq!(ln_bessel_i0(&0.0), 0.0);
+    assert_eq!(bessel_i0_scaled(&0.0), 1.0);
+    assert_eq!(bessel_i1_scaled(&0.0), 0.0);
+}
+#[test]
+fn bessel_ratio_derivative_finite_difference()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let x = 1e1*rng.gen::<f64>();
+        let h = 1e-6;
+        let finite_difference = (bessel_ratio(&(x + h)) - bessel_ratio(&(x - h)))/(2.0*h);
+        assert!((finite_difference - bessel_ratio_derivative(&x)).abs() <= 1e-8);
+    }
+    let below = 1e-3 - 1e-15;
+    let above = 1e-3 + 1e-15;
+    assert!((bessel_ratio(&below)/bessel_ratio(&above) - 1.0).abs() <= 1e-11);
+    assert!((bessel_ratio_derivative(&below)/bessel_ratio_derivative(&above) - 1.0).abs() <= 1e-11);
+}
+#[test]
+fn inverse_bessel_ratio_round_trip()
+{
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUMBER_OF_LOOPS
+    {
+        let y = 2.0*rng.gen::<f64>() - 1.0;
+        assert!((bessel_ratio(&inverse_bessel_ratio(&y)) - y).abs() <= 1e-14);
+    }
+    for y in [1e-5, 1e-4 + 1e-15, 0.5, 0.999, 0.999999]
+    {
+        assert!((bessel_ratio(&inverse_bessel_ratio(&y))/y - 1.0).abs() <= 1e-14);
+    }
+}
@@ -0,0 +1,155 @@
+mod test;
+use std::f64::consts::PI;
+use crate::math::roots::brent;
+use crate::physics::Constants;
+use crate::physics::single_chain::Isometric;
+use super::Solvent;
+
+static NUMBER_OF_HEIGHTS: usize = 256;
+
+/// The structure of the Alexander-de Gennes model of a polymer brush.
+///
+/// Every chain is stretched to the height of the brush, within which the links are uniformly distributed, so the free energy of each chain is the relative helmholtz free energy of the single-chain model at the height plus the free energy of mixing the links with the solvent within the area per chain.
+/// The equilibrium height balances the force on each chain with the osmotic pressure times the area per chain, and is the height of least free energy when the osmotic pressure is not monotonic in a poor solvent.
+pub struct AlexanderDeGennes<T>
+{
+    /// The single-chain model of each chain in the brush, in the isometric ensemble.
+    pub model: T,
+
+    /// The number of links in each chain.
+    pub number_of_links: u8,
+
+    /// The length of each link in units of nm, which also sets the volume of each link.
+    pub link_length: f64,
+
+    /// The number of chains grafted per unit area in units of 1/nm².
+    pub grafting_density: f64,
+
+    /// The quality of the solvent.
+    pub solvent: Solvent,
+
+    /// The temperature in units of K.
+    pub temperature: f64,
+
+    /// The physical constants used by the model.
+    pub constants: Constants
+}
+
+/// The implemented functionality of the Alexander-de Gennes model of a polymer brush.
+impl<T: Isometric> AlexanderDeGennes<T>
+{
+    /// Initializes and returns an instance of the Alexander-de Gennes model of a polymer brush at the temperature.
+    pub fn init(model: T, number_of_links: u8, link_length: f64, grafting_density: f64, solvent: Solvent, temperature: f64) -> AlexanderDeGennes<T>
+    {
+        AlexanderDeGennes::init_with_constants(model, number_of_links, link_length, grafting_density, solvent, temperature, Constants::default())
+    }
+    /// Initializes and returns an instance of the Alexander-de Gennes model of a polymer brush at the temperature, using the physical constants.
+    pub fn init_with_constants(model: T, number_of_links: u8, link_length: f64, grafting_density: f64, solvent: Solvent, temperature: f64, constants: Constants) -> AlexanderDeGennes<T>
+    {
+        AlexanderDeGennes
+        {
+            model,
+            number_of_links,
+            link_length,
+            grafting_density,
+            solvent,
+            temperature,
+            constants
+        }
+    }
+    /// The equilibrium height of the brush, which is NaN if the links cannot fit within the maximum end-to-end length of the chains.
+    pub fn height(&self) -> f64
+    {
+        let minimum = self.minimum_height();
+        let maximum = self.model.maximum_end_to_end_length();
+        if minimum >= maximum
+        {
+            return f64::NAN
+        }
+        let mut upper = (self.number_of_links as f64)*self.link_length;
+        if maximum.is_finite()
+        {
+            upper = maximum*(1.0 - 1e-12);
+        }
+        else
+        {
+            while self.free_energy_derivative(&upper) <= 0.0
+            {
+                upper *= 2.0;
+            }
+        }
+        let lower = minimum*(1.0 + 1e-12);
+        let heights = (0..=NUMBER_OF_HEIGHTS).map(|index| lower*(upper/lower).powf((index as f64)/(NUMBER_OF_HEIGHTS as f64))).collect::<Vec<f64>>();
+        let derivatives = heights.iter().map(|height| self.free_energy_derivative(height)).collect::<Vec<f64>>();
+        heights.windows(2).zip(derivatives.windows(2)).filter(|(_, derivative)| derivative[0] <= 0.0 && derivative[1] > 0.0).map(|(height, _)| brent(|height| self.free_energy_derivative(height), &height[0], &height[1])).fold((f64::NAN, f64::INFINITY), |(minimizer, minimum), height|
+        {
+            let free_energy = self.free_energy_per_chain(&height);
+            if free_energy < minimum
+            {
+                (height, free_energy)
+            }
+            else
+            {
+                (minimizer, minimum)
+            }
+        }).0
+    }
+    /// The volume fraction of links as a function of the distance from the grafting surface, which is uniform within the brush.
+    pub fn volume_fraction(&self, distance: &f64) -> f64
+    {
+        let height = self.height();
+        if distance < &height
+        {
+            self.minimum_height()/height
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The free energy of each chain as a function of the height of the brush, relative to that of a free chain at zero end-to-end length.
+    pub fn free_energy_per_chain(&self, height: &f64) -> f64
+    {
+        self.model.relative_helmholtz_free_energy(height, &self.temperature) + self.constants.boltzmann_constant*self.temperature*height*self.solvent.free_energy_density(&(self.minimum_height()/height))/self.grafting_density/self.link_length.powi(3)
+    }
+    /// The pressure between two identical brushes as a function of the separation between the grafting surfaces, where each brush is compressed to half of the separation.
+    pub fn pressure(&self, separation: &f64) -> f64
+    {
+        let height = 0.5*separation;
+        if height < self.height()
+        {
+            -self.grafting_density*self.free_energy_derivative(&height)
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The interaction energy per unit area between two identical brushes as a function of the separation between the grafting surfaces.
+    pub fn interaction_energy(&self, separation: &f64) -> f64
+    {
+        let equilibrium_height = self.height();
+        let height = 0.5*separation;
+        if height < equilibrium_height
+        {
+            2.0*self.grafting_density*(self.free_energy_per_chain(&height) - self.free_energy_per_chain(&equilibrium_height))
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The force between a sphere and a plane grafted with identical brushes as a function of the separation between the grafting surfaces and the radius of the sphere, using the Derjaguin approximation.
+    pub fn force(&self, separation: &f64, radius: &f64) -> f64
+    {
+        2.0*PI*radius*self.interaction_energy(separation)
+    }
+    fn minimum_height(&self) -> f64
+    {
+        (self.number_of_links as f64)*self.grafting_density*self.link_length.powi(3)
+    }
+    fn free_energy_derivative(&self, height: &f64) -> f64
+    {
+        self.model.force(height, &self.temperature) - self.constants.boltzmann_constant*self.temperature*self.solvent.osmotic_pressure(&(self.minimum_height()/height))/self.grafting_density/self.link_length.powi(3)
+    }
+}
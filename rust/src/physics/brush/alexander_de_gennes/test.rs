@@ -0,0 +1,59 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::ideal::thermodynamics::isometric::Ideal;
+use crate::physics::single_chain::fjc::thermodynamics::isometric::legendre::FJC;
+static REL_TOL: f64 = 1e-6;
+static NUMBER_OF_LINKS: u8 = 25;
+static LINK_LENGTH: f64 = 1.0;
+static HINGE_MASS: f64 = 1.0;
+static TEMPERATURE: f64 = 300.0;
+#[test]
+fn equilibrium()
+{
+    for flory_huggins_parameter in [0.0, 0.5, 0.8]
+    {
+        let model = FJC::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+        let brush = AlexanderDeGennes::init(model, NUMBER_OF_LINKS, LINK_LENGTH, 0.1, Solvent::init(flory_huggins_parameter), TEMPERATURE);
+        let height = brush.height();
+        assert!(height > brush.minimum_height() && height < (NUMBER_OF_LINKS as f64)*LINK_LENGTH);
+        let scale = BOLTZMANN_CONSTANT*TEMPERATURE/LINK_LENGTH;
+        assert!(brush.free_energy_derivative(&height).abs() <= REL_TOL*scale);
+        let free_energy = brush.free_energy_per_chain(&height);
+        assert!(brush.free_energy_per_chain(&(0.99*height)) > free_energy);
+        assert!(brush.free_energy_per_chain(&(1.01*height)) > free_energy);
+    }
+}
+#[test]
+fn scaling()
+{
+    let number_of_links = NUMBER_OF_LINKS;
+    let link_length = LINK_LENGTH;
+    let grafting_density = 1e-3/link_length.powi(2);
+    let solvent = Solvent::athermal();
+    let model = Ideal::init(number_of_links, link_length, HINGE_MASS);
+    let brush = AlexanderDeGennes::init(model, number_of_links, link_length, grafting_density, solvent, TEMPERATURE);
+    let height = (number_of_links as f64)*link_length*(grafting_density*link_length.powi(2)*(1.0 - 2.0*solvent.flory_huggins_parameter)/6.0).powf(1.0/3.0);
+    assert!((brush.height()/height - 1.0).abs() <= 1e-1);
+    assert!((brush.volume_fraction(&(0.5*height))*brush.height()/brush.minimum_height() - 1.0).abs() <= REL_TOL);
+    assert_eq!(brush.volume_fraction(&(2.0*height)), 0.0);
+}
+#[test]
+fn pressure()
+{
+    let model = FJC::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+    let brush = AlexanderDeGennes::init(model, NUMBER_OF_LINKS, LINK_LENGTH, 0.1, Solvent::init(0.3), TEMPERATURE);
+    let height = brush.height();
+    assert_eq!(brush.pressure(&(2.5*height)), 0.0);
+    assert_eq!(brush.interaction_energy(&(2.5*height)), 0.0);
+    for separation in [height, 1.5*height, 1.8*height]
+    {
+        let pressure = brush.pressure(&separation);
+        assert!(pressure > 0.0);
+        let h = 1e-6*height;
+        let derivative = (brush.interaction_energy(&(separation + h)) - brush.interaction_energy(&(separation - h)))/(2.0*h);
+        assert!((derivative/pressure + 1.0).abs() <= 1e-3);
+        let radius = 1e3*LINK_LENGTH;
+        assert!((brush.force(&separation, &radius)/brush.interaction_energy(&separation)/(2.0*PI*radius) - 1.0).abs() <= REL_TOL);
+    }
+}
@@ -0,0 +1,92 @@
+mod test;
+
+/// The Alexander-de Gennes model of a polymer brush, where every chain is uniformly stretched to the height of the brush.
+pub mod alexander_de_gennes;
+
+/// The strong-stretching model of a polymer brush, where the self-consistent potential is solved for numerically.
+pub mod strong_stretching;
+
+use crate::math::roots::brent;
+
+/// The structure of the quality of the solvent of a polymer brush, described by the Flory-Huggins theory with each link occupying a site of volume equal to the link length cubed.
+///
+/// The free energy density, exchange chemical potential, and osmotic pressure are in units of the thermal energy per site and are relative to those of pure solvent, so that each vanishes with the volume fraction of links.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Solvent
+{
+    /// The Flory-Huggins interaction parameter between the links and the solvent, which is zero for an athermal solvent, less than one half for a good solvent, one half for a theta solvent, and greater than one half for a poor solvent.
+    pub flory_huggins_parameter: f64
+}
+
+/// The implemented functionality of the solvent.
+impl Solvent
+{
+    /// Initializes and returns the solvent with the Flory-Huggins interaction parameter.
+    pub fn init(flory_huggins_parameter: f64) -> Solvent
+    {
+        Solvent
+        {
+            flory_huggins_parameter
+        }
+    }
+    /// The athermal solvent, where the Flory-Huggins interaction parameter is zero.
+    pub fn athermal() -> Solvent
+    {
+        Solvent::init(0.0)
+    }
+    /// The theta solvent, where the Flory-Huggins interaction parameter is one half and the second virial coefficient vanishes.
+    pub fn theta() -> Solvent
+    {
+        Solvent::init(0.5)
+    }
+    /// The nondimensional free energy density of mixing as a function of the volume fraction of links.
+    pub fn free_energy_density(&self, volume_fraction: &f64) -> f64
+    {
+        (1.0 - volume_fraction)*(-volume_fraction).ln_1p() + volume_fraction - self.flory_huggins_parameter*volume_fraction.powi(2)
+    }
+    /// The nondimensional exchange chemical potential of a link as a function of the volume fraction of links.
+    pub fn exchange_chemical_potential(&self, volume_fraction: &f64) -> f64
+    {
+        -(-volume_fraction).ln_1p() - 2.0*self.flory_huggins_parameter*volume_fraction
+    }
+    /// The nondimensional osmotic pressure as a function of the volume fraction of links.
+    pub fn osmotic_pressure(&self, volume_fraction: &f64) -> f64
+    {
+        -(-volume_fraction).ln_1p() - volume_fraction - self.flory_huggins_parameter*volume_fraction.powi(2)
+    }
+    /// The volume fraction of links at which the osmotic pressure vanishes, which is nonzero only in a poor solvent, where it is the volume fraction at the edge of a collapsed brush.
+    pub fn edge_volume_fraction(&self) -> f64
+    {
+        if self.flory_huggins_parameter > 0.5
+        {
+            brent(|volume_fraction| self.osmotic_pressure(volume_fraction), &self.spinodal_volume_fraction(), &(1.0 - (-2.0 - self.flory_huggins_parameter).exp()))
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The volume fraction of links as a function of the nondimensional exchange chemical potential, on the branch of volume fractions exceeding the spinodal, which is every volume fraction unless the solvent is poor.
+    pub fn volume_fraction(&self, exchange_chemical_potential: &f64) -> f64
+    {
+        if exchange_chemical_potential <= &0.0 && self.flory_huggins_parameter <= 0.5
+        {
+            0.0
+        }
+        else
+        {
+            brent(|volume_fraction| self.exchange_chemical_potential(volume_fraction) - exchange_chemical_potential, &self.spinodal_volume_fraction(), &(1.0 - (-exchange_chemical_potential - 2.0*self.flory_huggins_parameter.max(0.0)).exp()))
+        }
+    }
+    fn spinodal_volume_fraction(&self) -> f64
+    {
+        if self.flory_huggins_parameter > 0.5
+        {
+            1.0 - 0.5/self.flory_huggins_parameter
+        }
+        else
+        {
+            0.0
+        }
+    }
+}
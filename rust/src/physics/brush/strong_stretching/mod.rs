@@ -0,0 +1,223 @@
+mod test;
+use std::f64::consts::PI;
+use crate::math::interpolation::Interpolant;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::Constants;
+use crate::physics::single_chain::Isometric;
+use super::Solvent;
+
+static NUMBER_OF_POINTS: usize = 1000;
+
+static NUMBER_OF_INTERVALS: usize = 64;
+
+static MAXIMUM_DOUBLINGS: usize = 64;
+
+/// The structure of the strong-stretching model of a polymer brush.
+///
+/// Each chain follows the classical path that minimizes its elastic free energy, given per link by the relative helmholtz free energy of the single-chain model, in the self-consistent potential that is the exchange chemical potential of the links with the solvent, and every path must take the number of links to reach the grafting surface regardless of the distance of its free end, where the tension vanishes.
+/// This equal-time condition fixes the decrease of the potential from the grafting surface as a function of the distance, which is parabolic for Gaussian chains and is otherwise solved for numerically upon initialization using product integration on a grid of potentials graded toward the grafting surface, after which the volume fraction of links follows from the potential, and the height from the number of links per unit area.
+/// In a poor solvent, the volume fraction drops to zero at the edge of the brush from the volume fraction at which the osmotic pressure vanishes.
+pub struct StrongStretching<T>
+{
+    /// The single-chain model of each chain in the brush, in the isometric ensemble.
+    pub model: T,
+
+    /// The number of links in each chain.
+    pub number_of_links: u8,
+
+    /// The length of each link in units of nm, which also sets the volume of each link.
+    pub link_length: f64,
+
+    /// The number of chains grafted per unit area in units of 1/nm².
+    pub grafting_density: f64,
+
+    /// The quality of the solvent.
+    pub solvent: Solvent,
+
+    /// The temperature in units of K.
+    pub temperature: f64,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    potentials: Vec<f64>,
+
+    distances: Vec<f64>,
+
+    derivatives: Vec<f64>,
+
+    edge_potential: f64,
+
+    potential: Interpolant
+}
+
+/// The implemented functionality of the strong-stretching model of a polymer brush.
+impl<T: Isometric> StrongStretching<T>
+{
+    /// Initializes and returns an instance of the strong-stretching model of a polymer brush at the temperature, solving for the self-consistent potential.
+    pub fn init(model: T, number_of_links: u8, link_length: f64, grafting_density: f64, solvent: Solvent, temperature: f64) -> StrongStretching<T>
+    {
+        StrongStretching::init_with_constants(model, number_of_links, link_length, grafting_density, solvent, temperature, Constants::default())
+    }
+    /// Initializes and returns an instance of the strong-stretching model of a polymer brush at the temperature, solving for the self-consistent potential, using the physical constants.
+    pub fn init_with_constants(model: T, number_of_links: u8, link_length: f64, grafting_density: f64, solvent: Solvent, temperature: f64, constants: Constants) -> StrongStretching<T>
+    {
+        let amount = (number_of_links as f64)*grafting_density*link_length.powi(2);
+        let edge_chemical_potential = solvent.exchange_chemical_potential(&solvent.edge_volume_fraction());
+        let mut maximum_potential = 1.0;
+        let mut edge_potential = f64::NAN;
+        let (mut potentials, mut distances, mut derivatives) = solve(&model, &number_of_links, &link_length, &temperature, &constants, &maximum_potential);
+        for _ in 0..MAXIMUM_DOUBLINGS
+        {
+            let residual = |potential: &f64| amount_within(&potentials, &derivatives, &solvent, &(edge_chemical_potential + potential), potential) - amount;
+            if residual(&maximum_potential) >= 0.0
+            {
+                edge_potential = brent(residual, &0.0, &maximum_potential);
+                break
+            }
+            maximum_potential *= 2.0;
+            (potentials, distances, derivatives) = solve(&model, &number_of_links, &link_length, &temperature, &constants, &maximum_potential);
+        }
+        StrongStretching
+        {
+            model,
+            number_of_links,
+            link_length,
+            grafting_density,
+            solvent,
+            temperature,
+            constants,
+            potential: Interpolant::from_points(distances.clone(), potentials.clone()),
+            potentials,
+            distances,
+            derivatives,
+            edge_potential
+        }
+    }
+    /// The equilibrium height of the brush, which is NaN if the links cannot fit within the maximum end-to-end length of the chains.
+    pub fn height(&self) -> f64
+    {
+        self.nondimensional_distance(&self.edge_potential)*self.link_length
+    }
+    /// The self-consistent potential as a function of the distance from the grafting surface, relative to its value at the grafting surface.
+    pub fn potential(&self, distance: &f64) -> f64
+    {
+        -self.potential.evaluate(&(distance/self.link_length))*self.constants.boltzmann_constant*self.temperature
+    }
+    /// The volume fraction of links as a function of the distance from the grafting surface.
+    pub fn volume_fraction(&self, distance: &f64) -> f64
+    {
+        if distance < &self.height()
+        {
+            self.solvent.volume_fraction(&(self.solvent.exchange_chemical_potential(&self.solvent.edge_volume_fraction()) + self.edge_potential - self.potential.evaluate(&(distance/self.link_length))))
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The pressure between two identical brushes as a function of the separation between the grafting surfaces, where each brush is compressed to half of the separation.
+    pub fn pressure(&self, separation: &f64) -> f64
+    {
+        if separation >= &(2.0*self.height())
+        {
+            return 0.0
+        }
+        let edge_potential = self.potential.evaluate(&(0.5*separation/self.link_length));
+        let edge_chemical_potential = self.solvent.exchange_chemical_potential(&self.solvent.edge_volume_fraction());
+        let amount = self.amount();
+        let residual = |surface_potential: &f64| amount_within(&self.potentials, &self.derivatives, &self.solvent, surface_potential, &edge_potential) - amount;
+        let (lower, upper) = bracket(residual, &(edge_chemical_potential + self.edge_potential), &(edge_chemical_potential + edge_potential), &f64::INFINITY);
+        let surface_potential = brent(residual, &lower, &upper);
+        self.solvent.osmotic_pressure(&self.solvent.volume_fraction(&(surface_potential - edge_potential)))*self.constants.boltzmann_constant*self.temperature/self.link_length.powi(3)
+    }
+    /// The interaction energy per unit area between two identical brushes as a function of the separation between the grafting surfaces, which integrates the pressure using Simpson's rule.
+    pub fn interaction_energy(&self, separation: &f64) -> f64
+    {
+        let range = 2.0*self.height() - separation;
+        if range > 0.0
+        {
+            let step = range/(NUMBER_OF_INTERVALS as f64);
+            (0..=NUMBER_OF_INTERVALS).map(|index|
+            {
+                let weight = if index == 0 || index == NUMBER_OF_INTERVALS {1.0} else if index % 2 == 1 {4.0} else {2.0};
+                weight*self.pressure(&(separation + index as f64*step))
+            }).sum::<f64>()*step/3.0
+        }
+        else
+        {
+            0.0
+        }
+    }
+    /// The force between a sphere and a plane grafted with identical brushes as a function of the separation between the grafting surfaces and the radius of the sphere, using the Derjaguin approximation.
+    pub fn force(&self, separation: &f64, radius: &f64) -> f64
+    {
+        2.0*PI*radius*self.interaction_energy(separation)
+    }
+    fn amount(&self) -> f64
+    {
+        (self.number_of_links as f64)*self.grafting_density*self.link_length.powi(2)
+    }
+    fn nondimensional_distance(&self, potential: &f64) -> f64
+    {
+        let index = self.potentials.partition_point(|node| node <= potential).clamp(1, self.derivatives.len()) - 1;
+        self.distances[index] + self.derivatives[index]*(potential - self.potentials[index])
+    }
+}
+
+/// Returns the number of links per unit area in units of the link length squared within the potential from the grafting surface, given the potential at the grafting surface.
+fn amount_within(potentials: &[f64], derivatives: &[f64], solvent: &Solvent, surface_potential: &f64, potential: &f64) -> f64
+{
+    potentials.windows(2).zip(derivatives.iter()).take_while(|(bounds, _)| &bounds[0] < potential).map(|(bounds, derivative)|
+    {
+        let upper = bounds[1].min(*potential);
+        derivative*(upper - bounds[0])*solvent.volume_fraction(&(surface_potential - 0.5*(bounds[0] + upper)))
+    }).sum()
+}
+
+/// Solves the equal-time condition up to the maximum nondimensional potential, returning the potentials at the nodes, the nondimensional distances from the grafting surface at the nodes, and the derivatives of the distance with respect to the potential within each interval.
+///
+/// The integral of the derivative against the inverse of the stretch of a link is the number of links, and the integral of the inverse of the stretch over the potential is the nondimensional tension, so the integral over each interval is a difference of tensions, which are tabulated on the grid and interpolated as functions of the square root of the potential.
+fn solve<T: Isometric>(model: &T, number_of_links: &u8, link_length: &f64, temperature: &f64, constants: &Constants, maximum_potential: &f64) -> (Vec<f64>, Vec<f64>, Vec<f64>)
+{
+    let number_of_links = *number_of_links as f64;
+    let thermal_energy = constants.boltzmann_constant*temperature;
+    let maximum_stretch = model.maximum_end_to_end_length()/number_of_links/link_length;
+    let decrease = |stretch: &f64|
+    {
+        let end_to_end_length = number_of_links*link_length*stretch;
+        (stretch*model.force(&end_to_end_length, temperature)*link_length - model.relative_helmholtz_free_energy(&end_to_end_length, temperature)/number_of_links)/thermal_energy
+    };
+    let mut stretch = 0.0;
+    let roots = (0..=NUMBER_OF_POINTS).map(|index| maximum_potential.sqrt()*(index as f64)/(NUMBER_OF_POINTS as f64)).collect::<Vec<f64>>();
+    let tensions = roots.iter().map(|root|
+    {
+        if root > &0.0
+        {
+            let potential = root.powi(2);
+            let (lower, upper) = bracket(|stretch| decrease(stretch) - potential, &stretch, &0.0, &maximum_stretch);
+            stretch = brent(|stretch| decrease(stretch) - potential, &lower, &upper);
+        }
+        model.force(&(number_of_links*link_length*stretch), temperature)*link_length/thermal_energy
+    }).collect::<Vec<f64>>();
+    let tension = Interpolant::from_points(roots.clone(), tensions);
+    let kernel = |difference: f64| tension.evaluate(&difference.max(0.0).sqrt());
+    let potentials = roots.iter().map(|root| root.powi(2)).collect::<Vec<f64>>();
+    let mut derivatives: Vec<f64> = Vec::with_capacity(NUMBER_OF_POINTS);
+    for index in 1..=NUMBER_OF_POINTS
+    {
+        let potential = potentials[index];
+        let sum = derivatives.iter().zip(potentials.windows(2)).map(|(derivative, bounds)| derivative*(kernel(potential - bounds[0]) - kernel(potential - bounds[1]))).sum::<f64>();
+        derivatives.push((number_of_links - sum)/kernel(potential - potentials[index - 1]));
+    }
+    let mut distances = vec![0.0; NUMBER_OF_POINTS + 1];
+    for index in 0..NUMBER_OF_POINTS
+    {
+        distances[index + 1] = distances[index] + derivatives[index]*(potentials[index + 1] - potentials[index]);
+    }
+    (potentials, distances, derivatives)
+}
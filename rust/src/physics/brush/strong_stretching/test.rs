@@ -0,0 +1,63 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::ideal::thermodynamics::isometric::Ideal;
+use crate::physics::single_chain::fjc::thermodynamics::isometric::legendre::FJC;
+static NUMBER_OF_LINKS: u8 = 25;
+static LINK_LENGTH: f64 = 1.0;
+static HINGE_MASS: f64 = 1.0;
+static TEMPERATURE: f64 = 300.0;
+#[test]
+fn parabolic()
+{
+    let model = Ideal::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+    let brush = StrongStretching::init(model, NUMBER_OF_LINKS, LINK_LENGTH, 0.1, Solvent::athermal(), TEMPERATURE);
+    let height = brush.height();
+    for fraction in [0.25, 0.5, 1.0]
+    {
+        let distance = fraction*height;
+        let potential = -3.0*PI.powi(2)*distance.powi(2)/8.0/(NUMBER_OF_LINKS as f64).powi(2)/LINK_LENGTH.powi(2)*BOLTZMANN_CONSTANT*TEMPERATURE;
+        assert!((brush.potential(&distance)/potential - 1.0).abs() <= 1e-3);
+    }
+}
+#[test]
+fn scaling()
+{
+    let grafting_density = 1e-3/LINK_LENGTH.powi(2);
+    let model = Ideal::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+    let brush = StrongStretching::init(model, NUMBER_OF_LINKS, LINK_LENGTH, grafting_density, Solvent::athermal(), TEMPERATURE);
+    let height = (NUMBER_OF_LINKS as f64)*LINK_LENGTH*(4.0*grafting_density*LINK_LENGTH.powi(2)/PI.powi(2)).powf(1.0/3.0);
+    assert!((brush.height()/height - 1.0).abs() <= 1e-1);
+}
+#[test]
+fn normalization()
+{
+    for flory_huggins_parameter in [0.0, 0.5, 0.8]
+    {
+        let model = FJC::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+        let brush = StrongStretching::init(model, NUMBER_OF_LINKS, LINK_LENGTH, 0.1, Solvent::init(flory_huggins_parameter), TEMPERATURE);
+        let height = brush.height();
+        assert!(height < (NUMBER_OF_LINKS as f64)*LINK_LENGTH);
+        let number_of_points = 1000;
+        let step = height/(number_of_points as f64);
+        let amount = (0..number_of_points).map(|index| brush.volume_fraction(&((index as f64 + 0.5)*step))*step).sum::<f64>();
+        assert!((amount/brush.amount()/LINK_LENGTH - 1.0).abs() <= 1e-3);
+        assert_eq!(brush.volume_fraction(&(1.01*height)), 0.0);
+    }
+}
+#[test]
+fn pressure()
+{
+    let model = FJC::init(NUMBER_OF_LINKS, LINK_LENGTH, HINGE_MASS);
+    let brush = StrongStretching::init(model, NUMBER_OF_LINKS, LINK_LENGTH, 0.1, Solvent::init(0.3), TEMPERATURE);
+    let height = brush.height();
+    assert_eq!(brush.pressure(&(2.5*height)), 0.0);
+    assert_eq!(brush.interaction_energy(&(2.5*height)), 0.0);
+    let pressures = [height, 1.5*height, 1.8*height].map(|separation| brush.pressure(&separation));
+    assert!(pressures[0] > pressures[1] && pressures[1] > pressures[2] && pressures[2] > 0.0);
+    let h = 1e-3*height;
+    let derivative = (brush.interaction_energy(&(1.5*height + h)) - brush.interaction_energy(&(1.5*height - h)))/(2.0*h);
+    assert!((derivative/pressures[1] + 1.0).abs() <= 1e-2);
+    let radius = 1e3*LINK_LENGTH;
+    assert!((brush.force(&height, &radius)/brush.interaction_energy(&height)/(2.0*PI*radius) - 1.0).abs() <= 1e-6);
+}
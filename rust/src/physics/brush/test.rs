@@ -0,0 +1,40 @@
+#![cfg(test)]
+use super::*;
+static ABS_TOL: f64 = 1e-8;
+static REL_TOL: f64 = 1e-6;
+#[test]
+fn osmotic_pressure()
+{
+    for flory_huggins_parameter in [0.0, 0.3, 0.5, 0.8]
+    {
+        let solvent = Solvent::init(flory_huggins_parameter);
+        for volume_fraction in [1e-3, 0.1, 0.5, 0.9]
+        {
+            let osmotic_pressure = volume_fraction*solvent.exchange_chemical_potential(&volume_fraction) - solvent.free_energy_density(&volume_fraction);
+            assert!((solvent.osmotic_pressure(&volume_fraction) - osmotic_pressure).abs() <= ABS_TOL);
+            let h = 1e-6;
+            let derivative = (solvent.free_energy_density(&(volume_fraction + h)) - solvent.free_energy_density(&(volume_fraction - h)))/(2.0*h);
+            assert!((solvent.exchange_chemical_potential(&volume_fraction) - derivative).abs() <= REL_TOL);
+        }
+    }
+}
+#[test]
+fn volume_fraction()
+{
+    for flory_huggins_parameter in [0.0, 0.3, 0.5]
+    {
+        let solvent = Solvent::init(flory_huggins_parameter);
+        assert_eq!(solvent.edge_volume_fraction(), 0.0);
+        for volume_fraction in [1e-3, 0.1, 0.5, 0.9]
+        {
+            assert!((solvent.volume_fraction(&solvent.exchange_chemical_potential(&volume_fraction))/volume_fraction - 1.0).abs() <= REL_TOL);
+        }
+    }
+    let solvent = Solvent::init(0.8);
+    let edge_volume_fraction = solvent.edge_volume_fraction();
+    assert!(edge_volume_fraction > 0.0 && solvent.osmotic_pressure(&edge_volume_fraction).abs() <= ABS_TOL);
+    for volume_fraction in [edge_volume_fraction, 0.9]
+    {
+        assert!((solvent.volume_fraction(&solvent.exchange_chemical_potential(&volume_fraction))/volume_fraction - 1.0).abs() <= REL_TOL);
+    }
+}
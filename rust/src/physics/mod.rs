@@ -1,8 +1,80 @@
 /// Single-chain models for polymer physics.
 pub mod single_chain;
 
+/// Polymer brushes of grafted single chains.
+pub mod brush;
+
+/// Quantities with units, which convert to and from the units of the physical constants used by the models.
+pub mod units;
+
 /// The Boltzmann constant in units of J/(mol⋅K).
 pub static BOLTZMANN_CONSTANT: f64 = 8.314462618;
 
+/// The Avogadro number in units of 1/mol.
+pub static AVOGADRO_NUMBER: f64 = 6.02214076e23;
+
 /// The Planck constant in units of J⋅ns/mol.
-pub static PLANCK_CONSTANT: f64 = 0.06350779923502961;
\ No newline at end of file
+pub static PLANCK_CONSTANT: f64 = 0.06350779923502961;
+
+/// The physical constants of a system of units, which the models are initialized with.
+///
+/// Parameters involving energies, such as link stiffnesses, are given in the energy units of the constants, while the Planck constant is the reduced Planck constant in units for which the hinge masses in kg/mol are consistent with the energies, so that the masses are given in kg/mol in every system of units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constants
+{
+    /// The Boltzmann constant in units of energy per K.
+    pub boltzmann_constant: f64,
+
+    /// The reduced Planck constant in units of energy times ns.
+    pub planck_constant: f64,
+
+    /// The unit of energy in units of J/mol, used to convert to and from quantities with units.
+    pub energy_unit: f64
+}
+
+/// The implemented functionality of the physical constants.
+impl Constants
+{
+    /// Initializes and returns the physical constants, such as updated values of the Boltzmann and reduced Planck constants.
+    pub fn init(boltzmann_constant: f64, planck_constant: f64) -> Constants
+    {
+        Constants::init_with_energy_unit(boltzmann_constant, planck_constant, 1.0)
+    }
+    /// Initializes and returns the physical constants in a system of units whose unit of energy is the given number of J/mol.
+    pub fn init_with_energy_unit(boltzmann_constant: f64, planck_constant: f64, energy_unit: f64) -> Constants
+    {
+        Constants
+        {
+            boltzmann_constant,
+            planck_constant,
+            energy_unit
+        }
+    }
+    /// The physical constants in molar units, where energies are in J/mol, lengths in nm, and forces in J/(mol⋅nm).
+    pub fn molar() -> Constants
+    {
+        Constants::init(BOLTZMANN_CONSTANT, PLANCK_CONSTANT)
+    }
+    /// The physical constants in molecular units, where energies are in pN⋅nm, lengths in nm, and forces in pN.
+    pub fn molecular() -> Constants
+    {
+        let scale = 1e21/AVOGADRO_NUMBER;
+        Constants::init_with_energy_unit(scale*BOLTZMANN_CONSTANT, scale.sqrt()*PLANCK_CONSTANT, 1.0/scale)
+    }
+    /// The physical constants in units reduced by the thermal energy at the temperature, where energies are in units of the thermal energy, lengths in nm, and forces in units of the thermal energy per nm, when evaluated at that temperature.
+    pub fn reduced(temperature: &f64) -> Constants
+    {
+        let scale = 1.0/BOLTZMANN_CONSTANT/temperature;
+        Constants::init_with_energy_unit(scale*BOLTZMANN_CONSTANT, scale.sqrt()*PLANCK_CONSTANT, 1.0/scale)
+    }
+}
+
+/// The physical constants default to molar units.
+impl Default for Constants
+{
+    fn default() -> Constants
+    {
+        Constants::molar()
+    }
+}
@@ -0,0 +1,101 @@
+mod test;
+use rayon::prelude::*;
+use crate::error::Error;
+use crate::physics::single_chain::
+{
+    Isometric,
+    Isotensional
+};
+
+/// Returns the function evaluated in parallel at each of the arguments, in the same order as the arguments.
+pub fn evaluate<F>(function: F, arguments: &[f64]) -> Vec<f64>
+where F: Fn(&f64) -> f64 + Sync
+{
+    arguments.par_iter().map(&function).collect()
+}
+
+/// Returns the function evaluated in parallel at each of the arguments and temperatures, in the same order as the arguments.
+///
+/// A slice of length one is used for all of the entries of the other, and otherwise the slices must have the same length, or an error is returned.
+pub fn evaluate_with_temperatures<F>(function: F, arguments: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+where F: Fn(&f64, &f64) -> f64 + Sync
+{
+    let length = broadcast_length(&[("arguments", arguments.len()), ("temperatures", temperatures.len())])?;
+    Ok((0..length).into_par_iter().map(|index| function(broadcast(arguments, index), broadcast(temperatures, index))).collect())
+}
+
+/// Returns the function evaluated in parallel at each of the potential distances, potential stiffnesses, and temperatures, in the same order as the potential distances.
+///
+/// A slice of length one is used for all of the entries of the others, and otherwise the slices must have the same length, or an error is returned.
+pub fn evaluate_with_potential<F>(function: F, potential_distances: &[f64], potential_stiffnesses: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+where F: Fn(&f64, &f64, &f64) -> f64 + Sync
+{
+    let length = broadcast_length(&[("potential_distances", potential_distances.len()), ("potential_stiffnesses", potential_stiffnesses.len()), ("temperatures", temperatures.len())])?;
+    Ok((0..length).into_par_iter().map(|index| function(broadcast(potential_distances, index), broadcast(potential_stiffnesses, index), broadcast(temperatures, index))).collect())
+}
+
+/// The thermodynamic functions in the isometric ensemble evaluated in parallel at slices of end-to-end lengths and temperatures, which are broadcast as in [`evaluate_with_temperatures`].
+pub trait IsometricBatch: Isometric + Sync
+{
+    /// The expected force at each of the applied end-to-end lengths and temperatures.
+    fn force_batch(&self, end_to_end_lengths: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|end_to_end_length, temperature| self.force(end_to_end_length, temperature), end_to_end_lengths, temperatures)
+    }
+    /// The helmholtz free energy at each of the applied end-to-end lengths and temperatures.
+    fn helmholtz_free_energy_batch(&self, end_to_end_lengths: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|end_to_end_length, temperature| self.helmholtz_free_energy(end_to_end_length, temperature), end_to_end_lengths, temperatures)
+    }
+    /// The relative helmholtz free energy at each of the applied end-to-end lengths and temperatures.
+    fn relative_helmholtz_free_energy_batch(&self, end_to_end_lengths: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|end_to_end_length, temperature| self.relative_helmholtz_free_energy(end_to_end_length, temperature), end_to_end_lengths, temperatures)
+    }
+}
+
+impl<T: Isometric + Sync + ?Sized> IsometricBatch for T {}
+
+/// The thermodynamic functions in the isotensional ensemble evaluated in parallel at slices of forces and temperatures, which are broadcast as in [`evaluate_with_temperatures`].
+pub trait IsotensionalBatch: Isotensional + Sync
+{
+    /// The expected end-to-end length at each of the applied forces and temperatures.
+    fn end_to_end_length_batch(&self, forces: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|force, temperature| self.end_to_end_length(force, temperature), forces, temperatures)
+    }
+    /// The gibbs free energy at each of the applied forces and temperatures.
+    fn gibbs_free_energy_batch(&self, forces: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|force, temperature| self.gibbs_free_energy(force, temperature), forces, temperatures)
+    }
+    /// The relative gibbs free energy at each of the applied forces and temperatures.
+    fn relative_gibbs_free_energy_batch(&self, forces: &[f64], temperatures: &[f64]) -> Result<Vec<f64>, Error>
+    {
+        evaluate_with_temperatures(|force, temperature| self.relative_gibbs_free_energy(force, temperature), forces, temperatures)
+    }
+}
+
+impl<T: Isotensional + Sync + ?Sized> IsotensionalBatch for T {}
+
+fn broadcast_length(lengths: &[(&'static str, usize)]) -> Result<usize, Error>
+{
+    let length = lengths.iter().map(|(_, length)| *length).find(|length| length != &1).unwrap_or(1);
+    match lengths.iter().find(|(_, other)| other != &length && other != &1)
+    {
+        Some((argument, other)) => Err(Error::InvalidParameter{model: "Batch", argument, value: *other as f64}),
+        None => Ok(length)
+    }
+}
+
+fn broadcast(values: &[f64], index: usize) -> &f64
+{
+    if values.len() == 1
+    {
+        &values[0]
+    }
+    else
+    {
+        &values[index]
+    }
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::
+{
+    efjc::EFJC,
+    fjc::FJC,
+    swfjc::SWFJC,
+    ideal::Ideal,
+    polydisperse::
+    {
+        Distribution,
+        Polydisperse
+    }
+};
+use crate::physics::single_chain::test::Parameters;
+use rand::Rng;
+fn arguments(scale: f64) -> Vec<f64>
+{
+    let mut rng = rand::thread_rng();
+    (0..888).map(|_| scale*rng.gen::<f64>()).collect()
+}
+#[test]
+fn evaluate_order()
+{
+    let parameters = Parameters::default();
+    let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let nondimensional_end_to_end_lengths_per_link = arguments(parameters.nondimensional_end_to_end_length_per_link_scale);
+    let batch = evaluate(|gamma| model.thermodynamics.isometric.legendre.nondimensional_force(gamma), &nondimensional_end_to_end_lengths_per_link);
+    nondimensional_end_to_end_lengths_per_link.iter().zip(batch.iter()).for_each(|(gamma, nondimensional_force)|
+        assert_eq!(model.thermodynamics.isometric.legendre.nondimensional_force(gamma), *nondimensional_force)
+    );
+}
+#[test]
+fn isometric()
+{
+    let parameters = Parameters::default();
+    let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let end_to_end_lengths = arguments(0.99*(parameters.number_of_links_minimum as f64)*parameters.link_length_reference);
+    let temperatures = arguments(parameters.temperature_scale).iter().map(|temperature| parameters.temperature_reference + temperature).collect::<Vec<f64>>();
+    let forces = model.thermodynamics.isometric.force_batch(&end_to_end_lengths, &temperatures).unwrap();
+    let helmholtz_free_energies = model.thermodynamics.isometric.legendre.helmholtz_free_energy_batch(&end_to_end_lengths, &temperatures).unwrap();
+    let relative_helmholtz_free_energies = model.thermodynamics.isometric.relative_helmholtz_free_energy_batch(&end_to_end_lengths, &[parameters.temperature_reference]).unwrap();
+    for (index, (end_to_end_length, temperature)) in end_to_end_lengths.iter().zip(temperatures.iter()).enumerate()
+    {
+        assert_eq!(forces[index], model.thermodynamics.isometric.force(end_to_end_length, temperature));
+        assert_eq!(helmholtz_free_energies[index], model.thermodynamics.isometric.legendre.helmholtz_free_energy(end_to_end_length, temperature));
+        assert_eq!(relative_helmholtz_free_energies[index], model.thermodynamics.isometric.relative_helmholtz_free_energy(end_to_end_length, &parameters.temperature_reference));
+    }
+}
+#[test]
+fn isotensional()
+{
+    let parameters = Parameters::default();
+    let efjc = EFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference);
+    let swfjc = SWFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference);
+    let ideal = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let forces = arguments(parameters.nondimensional_force_scale*crate::physics::BOLTZMANN_CONSTANT*parameters.temperature_reference/parameters.link_length_reference);
+    let temperatures = [parameters.temperature_reference];
+    let end_to_end_lengths = efjc.thermodynamics.isotensional.asymptotic.end_to_end_length_batch(&forces, &temperatures).unwrap();
+    let gibbs_free_energies = swfjc.thermodynamics.isotensional.gibbs_free_energy_batch(&forces, &temperatures).unwrap();
+    let relative_gibbs_free_energies = ideal.thermodynamics.isotensional.relative_gibbs_free_energy_batch(&forces, &temperatures).unwrap();
+    for (index, force) in forces.iter().enumerate()
+    {
+        assert_eq!(end_to_end_lengths[index], efjc.thermodynamics.isotensional.asymptotic.end_to_end_length(force, &temperatures[0]));
+        assert_eq!(gibbs_free_energies[index], swfjc.thermodynamics.isotensional.gibbs_free_energy(force, &temperatures[0]));
+        assert_eq!(relative_gibbs_free_energies[index], ideal.thermodynamics.isotensional.relative_gibbs_free_energy(force, &temperatures[0]));
+    }
+}
+#[test]
+fn polydisperse()
+{
+    let parameters = Parameters::default();
+    let polydisperse = Polydisperse::init(&Distribution::Poisson{mean_number_of_links: 8.0}, 1, 16, |number_of_links| FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isotensional);
+    let forces = arguments(1e2);
+    let end_to_end_lengths = polydisperse.end_to_end_length_batch(&forces, &[parameters.temperature_reference]).unwrap();
+    forces.iter().zip(end_to_end_lengths.iter()).for_each(|(force, end_to_end_length)|
+        assert_eq!(polydisperse.end_to_end_length(force, &parameters.temperature_reference), *end_to_end_length)
+    );
+}
+#[test]
+fn modified_canonical()
+{
+    let parameters = Parameters::default();
+    let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let potential_distances = arguments((parameters.number_of_links_minimum as f64)*parameters.link_length_reference);
+    let potential_stiffnesses = arguments(1e2);
+    let forces = evaluate_with_potential(|potential_distance, potential_stiffness, temperature| model.thermodynamics.modified_canonical.asymptotic.strong_potential.force(potential_distance, potential_stiffness, temperature), &potential_distances, &potential_stiffnesses, &[parameters.temperature_reference]).unwrap();
+    for (index, (potential_distance, potential_stiffness)) in potential_distances.iter().zip(potential_stiffnesses.iter()).enumerate()
+    {
+        assert_eq!(forces[index], model.thermodynamics.modified_canonical.asymptotic.strong_potential.force(potential_distance, potential_stiffness, &parameters.temperature_reference));
+    }
+}
+#[test]
+fn empty()
+{
+    assert!(evaluate(|x| *x, &[]).is_empty());
+    assert!(evaluate_with_temperatures(|x, _| *x, &[], &[300.0]).unwrap().is_empty());
+}
+#[test]
+fn broadcast_arguments()
+{
+    assert_eq!(evaluate_with_temperatures(|x, temperature| x*temperature, &[2.0], &[300.0, 310.0]), Ok(vec![600.0, 620.0]));
+    assert_eq!(evaluate_with_temperatures(|x, temperature| x*temperature, &[2.0], &[300.0]), Ok(vec![600.0]));
+    assert_eq!(evaluate_with_potential(|x, stiffness, temperature| x*stiffness*temperature, &[2.0], &[1.0, 3.0], &[300.0]), Ok(vec![600.0, 1800.0]));
+}
+#[test]
+fn mismatched()
+{
+    assert_eq!(evaluate_with_temperatures(|x, temperature| x*temperature, &[1.0, 2.0, 3.0], &[300.0, 310.0]), Err(Error::InvalidParameter{model: "Batch", argument: "temperatures", value: 2.0}));
+    assert_eq!(evaluate_with_temperatures(|x, temperature| x*temperature, &[], &[300.0, 310.0]), Err(Error::InvalidParameter{model: "Batch", argument: "temperatures", value: 2.0}));
+    assert_eq!(evaluate_with_potential(|x, stiffness, temperature| x*stiffness*temperature, &[1.0, 2.0], &[1.0], &[300.0, 310.0, 320.0]), Err(Error::InvalidParameter{model: "Batch", argument: "temperatures", value: 3.0}));
+}
@@ -0,0 +1,170 @@
+mod test;
+use std::f64::consts::PI;
+use crate::physics::Constants;
+use crate::math::random::Random;
+use crate::math::special::ln_sinhc;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+use super::Geometry;
+
+static NUMBER_OF_SAMPLES: usize = 10000;
+
+static NUMBER_OF_TRIALS: usize = 8;
+
+/// The structure of the FJC model confined within impenetrable walls.
+///
+/// Chains are grown link by link from a starting hinge uniformly distributed within the walls using Rosenbluth sampling, where each link is chosen among several trial orientations drawn from the distribution of a free link under the applied force along the axis, and the weight of the chain is multiplied by the fraction of trials within the walls.
+/// The mean weight of the chains is then the probability that the chain does not cross the walls relative to the free chain under the same force, and the weighted mean of the end-to-end length along the axis is its expected value for the confined chain.
+/// The pseudorandom number generator is reseeded upon each evaluation, so that the functions are deterministic and vary smoothly with the force apart from the sampling error.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The geometry of the confining walls.
+    pub geometry: Geometry,
+
+    /// The number of chains sampled upon each evaluation.
+    pub number_of_samples: usize,
+
+    /// The seed of the pseudorandom number generator.
+    pub seed: u64,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64
+}
+
+/// The implemented functionality of the confined FJC model.
+impl FJC
+{
+    /// Initializes and returns an instance of the confined FJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, geometry, Constants::default())
+    }
+    /// Initializes and returns an instance of the confined FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry, constants: Constants) -> FJC
+    {
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            geometry,
+            number_of_samples: NUMBER_OF_SAMPLES,
+            seed: 0,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length
+        }
+    }
+    /// Initializes and returns an instance of the confined FJC model, or an error if a parameter or the geometry is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry) -> Result<FJC, Error>
+    {
+        minimum_number_of_links("FJC", &number_of_links, 1)?;
+        positive("FJC", "link_length", &link_length)?;
+        positive("FJC", "hinge_mass", &hinge_mass)?;
+        geometry.validate()?;
+        Ok(FJC::init(number_of_links, link_length, hinge_mass, geometry))
+    }
+    /// The probability that a chain with one end uniformly distributed within the walls does not cross them.
+    pub fn survival_probability(&self) -> f64
+    {
+        self.sample(&0.0).0
+    }
+    /// The confinement free energy as a function of the temperature, which is the increase in the free energy of the chain upon confinement.
+    pub fn confinement_free_energy(&self, temperature: &f64) -> f64
+    {
+        self.nondimensional_confinement_free_energy()*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional confinement free energy, which is independent of the temperature for the FJC.
+    pub fn nondimensional_confinement_free_energy(&self) -> f64
+    {
+        -self.survival_probability().ln()
+    }
+    /// The expected end-to-end length along the axis as a function of the applied force along the axis and temperature, which is NaN for the sphere.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional end-to-end length per link along the axis as a function of the applied nondimensional force along the axis, which is NaN for the sphere.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        if self.geometry.has_axis()
+        {
+            self.sample(nondimensional_force).1
+        }
+        else
+        {
+            f64::NAN
+        }
+    }
+    /// The gibbs free energy as a function of the applied force along the axis and temperature, which is NaN for the sphere.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
+    }
+    /// The gibbs free energy as a function of the applied force along the axis and temperature, relative to that of the unconfined chain at zero force, which is NaN for the sphere.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional gibbs free energy as a function of the applied nondimensional force along the axis, relative to that of the unconfined chain at zero force, which is NaN for the sphere.
+    pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
+    {
+        if self.geometry.has_axis()
+        {
+            -self.number_of_links_f64*ln_sinhc(nondimensional_force) - self.sample(nondimensional_force).0.ln()
+        }
+        else
+        {
+            f64::NAN
+        }
+    }
+    /// Returns the survival probability and the expected nondimensional end-to-end length per link along the axis, given the nondimensional force along the axis.
+    fn sample(&self, nondimensional_force: &f64) -> (f64, f64)
+    {
+        let mut random = Random::init(self.seed);
+        let mut trials = [[0.0; 3]; NUMBER_OF_TRIALS];
+        let (total_weight, total_length) = (0..self.number_of_samples).fold((0.0, 0.0), |(total_weight, total_length), _|
+        {
+            let mut position = self.geometry.sample(&mut random);
+            let start = position[0];
+            let mut weight = 1.0;
+            for _ in 0..self.number_of_links
+            {
+                trials.iter_mut().for_each(|trial|
+                {
+                    let cosine = random.tilted_uniform(nondimensional_force);
+                    let sine = (1.0 - cosine.powi(2)).max(0.0).sqrt();
+                    let angle = 2.0*PI*random.uniform();
+                    *trial = [position[0] + self.link_length*cosine, position[1] + self.link_length*sine*angle.cos(), position[2] + self.link_length*sine*angle.sin()];
+                });
+                let allowed = trials.iter().filter(|trial| self.geometry.contains(trial)).collect::<Vec<&[f64; 3]>>();
+                if allowed.is_empty()
+                {
+                    weight = 0.0;
+                    break
+                }
+                weight *= allowed.len() as f64/(NUMBER_OF_TRIALS as f64);
+                position = *allowed[random.index(allowed.len())];
+            }
+            (total_weight + weight, total_length + weight*(position[0] - start))
+        });
+        (total_weight/(self.number_of_samples as f64), total_length/total_weight/self.contour_length)
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Cylinder{radius: 1.0}).is_ok());
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width: 0.0}).err(), Some(Error::InvalidParameter{model: "Slit", argument: "width", value: 0.0}));
+    }
+}
+mod confinement
+{
+    use super::*;
+    #[test]
+    fn single_link()
+    {
+        let parameters = Parameters::default();
+        let link_length = parameters.link_length_reference;
+        let size = 2.0*link_length;
+        for (geometry, expected) in [(Geometry::Slit{width: size}, 1.0 - link_length/2.0/size), (Geometry::Sphere{radius: size}, 1.0 - 0.75*link_length/size + (link_length/size).powi(3)/16.0)]
+        {
+            let mut model = FJC::init(1, link_length, parameters.hinge_mass_reference, geometry);
+            model.number_of_samples = 100000;
+            assert!((model.survival_probability() - expected).abs() <= 3e-3);
+        }
+    }
+    #[test]
+    fn ideal()
+    {
+        let parameters = Parameters::default();
+        let width = 40.0*parameters.link_length_reference;
+        let fjc = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width});
+        let ideal = crate::physics::single_chain::confined::ideal::Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width});
+        assert!((fjc.nondimensional_confinement_free_energy()/ideal.nondimensional_confinement_free_energy() - 1.0).abs() <= parameters.rel_tol_thermodynamic_limit);
+    }
+}
+mod stretching
+{
+    use super::*;
+    use crate::math::special::langevin;
+    #[test]
+    fn alignment()
+    {
+        let parameters = Parameters::default();
+        let nondimensional_force = 1.0;
+        for geometry in [Geometry::Slit{width: 0.5*parameters.link_length_reference}, Geometry::Cylinder{radius: 0.5*parameters.link_length_reference}]
+        {
+            let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, geometry);
+            assert!(model.nondimensional_end_to_end_length_per_link(&nondimensional_force) > langevin(&nondimensional_force));
+            assert!(model.nondimensional_relative_gibbs_free_energy(&nondimensional_force) > -model.number_of_links_f64*ln_sinhc(&nondimensional_force));
+        }
+    }
+    #[test]
+    fn free()
+    {
+        let parameters = Parameters::default();
+        let mut model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Cylinder{radius: 1e6});
+        model.number_of_samples = 100000;
+        for nondimensional_force in [0.0, parameters.nondimensional_force_small, 1.0]
+        {
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force) - langevin(&nondimensional_force)).abs() <= 1e-2);
+        }
+    }
+    #[test]
+    fn sphere()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Sphere{radius: parameters.link_length_reference});
+        assert!(model.end_to_end_length(&1.0, &parameters.temperature_reference).is_nan());
+        assert!(model.gibbs_free_energy(&1.0, &parameters.temperature_reference).is_nan());
+        assert!(model.nondimensional_confinement_free_energy().is_finite());
+    }
+}
@@ -0,0 +1,224 @@
+mod test;
+use std::f64::consts::PI;
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+use super::Geometry;
+
+static BESSEL_J0_ZEROS: [f64; 10] = [2.404825557695773, 5.520078110286311, 8.653727912911013, 11.791534439014281, 14.930917708487787, 18.071063967910924, 21.21163662987926, 24.352471530749302, 27.493479132040257, 30.634606468431976];
+
+/// The structure of the ideal chain model confined within impenetrable walls.
+///
+/// The Gaussian random walk of the chain is absorbed by the walls, so the probability that a chain with one end uniformly distributed within the walls avoids them is an expansion in the eigenfunctions of the Laplacian with Dirichlet conditions, which is summed directly for strong confinement and replaced by its short-chain asymptotic expansion for weak confinement.
+/// The motion along the axis of a slit or cylinder separates from the confined motion across it, so stretching along the axis is unaffected by confinement apart from the additive confinement free energy.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The geometry of the confining walls.
+    pub geometry: Geometry,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64
+}
+
+/// The implemented functionality of the confined ideal chain model.
+impl Ideal
+{
+    /// Initializes and returns an instance of the confined ideal chain model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, geometry, Constants::default())
+    }
+    /// Initializes and returns an instance of the confined ideal chain model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            geometry,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length
+        }
+    }
+    /// Initializes and returns an instance of the confined ideal chain model, or an error if a parameter or the geometry is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, geometry: Geometry) -> Result<Ideal, Error>
+    {
+        minimum_number_of_links("Ideal", &number_of_links, 1)?;
+        positive("Ideal", "link_length", &link_length)?;
+        positive("Ideal", "hinge_mass", &hinge_mass)?;
+        geometry.validate()?;
+        Ok(Ideal::init(number_of_links, link_length, hinge_mass, geometry))
+    }
+    /// The probability that a chain with one end uniformly distributed within the walls does not cross them.
+    pub fn survival_probability(&self) -> f64
+    {
+        self.ln_survival_probability().exp()
+    }
+    /// The natural logarithm of the survival probability, which remains finite under strong confinement.
+    pub fn ln_survival_probability(&self) -> f64
+    {
+        match self.geometry
+        {
+            Geometry::Slit{width} =>
+            {
+                let time = self.number_of_links_f64*self.link_length.powi(2)/6.0/width.powi(2);
+                if time < 5e-3
+                {
+                    (1.0 - 4.0*(time/PI).sqrt()).ln()
+                }
+                else
+                {
+                    eigenfunction_expansion(|index| (2*index + 1) as f64*PI, |eigenvalue| 8.0/eigenvalue.powi(2), &time)
+                }
+            },
+            Geometry::Cylinder{radius} =>
+            {
+                let time = self.number_of_links_f64*self.link_length.powi(2)/6.0/radius.powi(2);
+                if time < 1e-6
+                {
+                    (1.0 - 4.0*(time/PI).sqrt() + time + time.powf(1.5)/3.0/PI.sqrt()).ln()
+                }
+                else
+                {
+                    eigenfunction_expansion(bessel_j0_zero, |eigenvalue| 4.0/eigenvalue.powi(2), &time)
+                }
+            },
+            Geometry::Sphere{radius} =>
+            {
+                let time = self.number_of_links_f64*self.link_length.powi(2)/6.0/radius.powi(2);
+                if time < 1e-2
+                {
+                    (1.0 - 6.0*(time/PI).sqrt() + 3.0*time).ln()
+                }
+                else
+                {
+                    eigenfunction_expansion(|index| (index + 1) as f64*PI, |eigenvalue| 6.0/eigenvalue.powi(2), &time)
+                }
+            }
+        }
+    }
+    /// The confinement free energy as a function of the temperature, which is the increase in the free energy of the chain upon confinement.
+    pub fn confinement_free_energy(&self, temperature: &f64) -> f64
+    {
+        self.nondimensional_confinement_free_energy()*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional confinement free energy, which is independent of the temperature for the ideal chain.
+    pub fn nondimensional_confinement_free_energy(&self) -> f64
+    {
+        -self.ln_survival_probability()
+    }
+    /// The expected end-to-end length along the axis as a function of the applied force along the axis and temperature, which is NaN for the sphere.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional end-to-end length per link along the axis as a function of the applied nondimensional force along the axis, which is NaN for the sphere.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        if self.geometry.has_axis()
+        {
+            nondimensional_force/3.0
+        }
+        else
+        {
+            f64::NAN
+        }
+    }
+    /// The expected force along the axis as a function of the applied end-to-end length along the axis and temperature, which is NaN for the sphere.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    /// The expected nondimensional force along the axis as a function of the applied nondimensional end-to-end length per link along the axis, which is NaN for the sphere.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        if self.geometry.has_axis()
+        {
+            3.0*nondimensional_end_to_end_length_per_link
+        }
+        else
+        {
+            f64::NAN
+        }
+    }
+    /// The gibbs free energy as a function of the applied force along the axis and temperature, which is NaN for the sphere.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
+    }
+    /// The gibbs free energy as a function of the applied force along the axis and temperature, relative to that of the unconfined chain at zero force, which is NaN for the sphere.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional gibbs free energy as a function of the applied nondimensional force along the axis, relative to that of the unconfined chain at zero force, which is NaN for the sphere.
+    pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
+    {
+        -self.number_of_links_f64*nondimensional_force*self.nondimensional_end_to_end_length_per_link(nondimensional_force)/2.0 + self.nondimensional_confinement_free_energy()
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length along the axis and temperature, relative to that of the unconfined chain at zero end-to-end length, which is NaN for the sphere.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_helmholtz_free_energy(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional helmholtz free energy as a function of the applied nondimensional end-to-end length per link along the axis, relative to that of the unconfined chain at zero end-to-end length, which is NaN for the sphere.
+    pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        self.number_of_links_f64*nondimensional_end_to_end_length_per_link*self.nondimensional_force(nondimensional_end_to_end_length_per_link)/2.0 + self.nondimensional_confinement_free_energy()
+    }
+}
+
+/// The natural logarithm of the expansion of the survival probability over the eigenvalues of the Laplacian in units of the inverse width or radius with their weights, summed relative to the leading term until the terms are negligible.
+fn eigenfunction_expansion<E, W>(eigenvalue: E, weight: W, time: &f64) -> f64
+where
+    E: Fn(usize) -> f64,
+    W: Fn(&f64) -> f64
+{
+    let leading = eigenvalue(0).powi(2)*time;
+    let mut sum = 0.0;
+    for index in 0..
+    {
+        let value = eigenvalue(index);
+        let exponent = value.powi(2)*time - leading;
+        sum += weight(&value)*(-exponent).exp();
+        if exponent > 40.0
+        {
+            break
+        }
+    }
+    sum.ln() - leading
+}
+
+/// The zeros of the Bessel function of the first kind of order zero, tabulated for the first few and otherwise given by the asymptotic expansion of McMahon.
+fn bessel_j0_zero(index: usize) -> f64
+{
+    if index < BESSEL_J0_ZEROS.len()
+    {
+        BESSEL_J0_ZEROS[index]
+    }
+    else
+    {
+        let beta = (index as f64 + 0.75)*PI;
+        let inverse = 1.0/(8.0*beta);
+        beta + inverse*(1.0 - inverse.powi(2)*(124.0/3.0 - inverse.powi(2)*(120928.0/15.0 - inverse.powi(2)*401743168.0/105.0)))
+    }
+}
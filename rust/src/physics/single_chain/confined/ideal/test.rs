@@ -0,0 +1,107 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(Ideal::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width: 1.0}).is_ok());
+        assert_eq!(Ideal::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Sphere{radius: -1.0}).err(), Some(Error::InvalidParameter{model: "Sphere", argument: "radius", value: -1.0}));
+    }
+}
+mod confinement
+{
+    use super::*;
+    #[test]
+    fn weak()
+    {
+        let parameters = Parameters::default();
+        for geometry in [Geometry::Slit{width: 1e6}, Geometry::Cylinder{radius: 1e6}, Geometry::Sphere{radius: 1e6}]
+        {
+            let model = Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, geometry);
+            assert!(model.nondimensional_confinement_free_energy().abs() <= 1e-4);
+        }
+    }
+    #[test]
+    fn continuity()
+    {
+        let parameters = Parameters::default();
+        for (time, geometry) in [(5e-3, Geometry::Slit{width: 1.0}), (1e-6, Geometry::Cylinder{radius: 1.0}), (1e-2, Geometry::Sphere{radius: 1.0})]
+        {
+            let number_of_links = 1;
+            let link_length = |time: f64| (6.0*time).sqrt();
+            let below = Ideal::init(number_of_links, link_length(time*(1.0 - 1e-9)), parameters.hinge_mass_reference, geometry).survival_probability();
+            let above = Ideal::init(number_of_links, link_length(time*(1.0 + 1e-9)), parameters.hinge_mass_reference, geometry).survival_probability();
+            assert!((below/above - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn strong()
+    {
+        let parameters = Parameters::default();
+        let width = 0.1*parameters.link_length_reference;
+        let model = Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width});
+        let asymptote = PI.powi(2)*(parameters.number_of_links_maximum as f64)*parameters.link_length_reference.powi(2)/6.0/width.powi(2) - (8.0/PI.powi(2)).ln();
+        assert!((model.confinement_free_energy(&parameters.temperature_reference)/BOLTZMANN_CONSTANT/parameters.temperature_reference/asymptote - 1.0).abs() <= parameters.rel_tol);
+    }
+    #[test]
+    fn ordering()
+    {
+        let parameters = Parameters::default();
+        let size = 2.0*parameters.link_length_reference;
+        let slit = Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width: 2.0*size}).nondimensional_confinement_free_energy();
+        let cylinder = Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Cylinder{radius: size}).nondimensional_confinement_free_energy();
+        let sphere = Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Sphere{radius: size}).nondimensional_confinement_free_energy();
+        assert!(slit > 0.0 && slit < cylinder && cylinder < sphere);
+    }
+}
+mod stretching
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn gibbs()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Cylinder{radius: 2.0*parameters.link_length_reference});
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = parameters.nondimensional_force_small*rng.gen::<f64>()*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let h = 1e-6*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let derivative = (model.gibbs_free_energy(&(force + h), &temperature) - model.gibbs_free_energy(&(force - h), &temperature))/(2.0*h);
+            assert!((derivative + model.end_to_end_length(&force, &temperature)).abs() <= parameters.rel_tol*model.contour_length);
+        }
+    }
+    #[test]
+    fn legendre()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Slit{width: parameters.link_length_reference});
+            let nondimensional_force = parameters.nondimensional_force_small*rng.gen::<f64>();
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force);
+            let legendre = model.nondimensional_relative_gibbs_free_energy(&nondimensional_force) + model.number_of_links_f64*nondimensional_force*nondimensional_end_to_end_length_per_link;
+            assert!((legendre/model.nondimensional_relative_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn sphere()
+    {
+        let parameters = Parameters::default();
+        let model = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Geometry::Sphere{radius: parameters.link_length_reference});
+        assert!(model.end_to_end_length(&1.0, &parameters.temperature_reference).is_nan());
+        assert!(model.nondimensional_force(&0.5).is_nan());
+        assert!(model.nondimensional_confinement_free_energy().is_finite());
+    }
+}
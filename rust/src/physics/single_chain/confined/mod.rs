@@ -0,0 +1,87 @@
+mod test;
+
+/// The ideal single-chain model confined within a slit, cylinder, or sphere.
+pub mod ideal;
+
+/// The freely-jointed chain (FJC) single-chain model confined within a slit, cylinder, or sphere.
+pub mod fjc;
+
+use std::f64::consts::PI;
+use crate::math::random::Random;
+use crate::error::
+{
+    Error,
+    positive
+};
+
+/// The geometries of impenetrable walls confining a chain, where the axis of the slit and cylinder is along the first coordinate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Geometry
+{
+    /// The region between two parallel planar walls separated by the width in units of nm, which are normal to the third coordinate.
+    Slit
+    {
+        width: f64
+    },
+    /// The interior of a cylinder with the radius in units of nm.
+    Cylinder
+    {
+        radius: f64
+    },
+    /// The interior of a spherical cavity with the radius in units of nm, which has no axis.
+    Sphere
+    {
+        radius: f64
+    }
+}
+
+impl Geometry
+{
+    /// Returns an error if the width or radius is not positive.
+    pub fn validate(&self) -> Result<(), Error>
+    {
+        match self
+        {
+            Geometry::Slit{width} => positive("Slit", "width", width),
+            Geometry::Cylinder{radius} => positive("Cylinder", "radius", radius),
+            Geometry::Sphere{radius} => positive("Sphere", "radius", radius)
+        }
+    }
+    /// Whether the geometry has an axis along which the chain can be stretched.
+    pub fn has_axis(&self) -> bool
+    {
+        !matches!(self, Geometry::Sphere{..})
+    }
+    /// Whether the position is within the walls.
+    pub fn contains(&self, position: &[f64; 3]) -> bool
+    {
+        match self
+        {
+            Geometry::Slit{width} => position[2] >= 0.0 && &position[2] <= width,
+            Geometry::Cylinder{radius} => position[1].powi(2) + position[2].powi(2) <= radius.powi(2),
+            Geometry::Sphere{radius} => position.iter().map(|coordinate| coordinate.powi(2)).sum::<f64>() <= radius.powi(2)
+        }
+    }
+    /// Returns a position uniformly distributed over the cross-section of the slit or cylinder at the origin of the axis, or over the interior of the sphere.
+    pub fn sample(&self, random: &mut Random) -> [f64; 3]
+    {
+        match self
+        {
+            Geometry::Slit{width} => [0.0, 0.0, width*random.uniform()],
+            Geometry::Cylinder{radius} =>
+            {
+                let distance = radius*random.uniform().sqrt();
+                let angle = 2.0*PI*random.uniform();
+                [0.0, distance*angle.cos(), distance*angle.sin()]
+            },
+            Geometry::Sphere{radius} => loop
+            {
+                let position = [0; 3].map(|_| radius*(2.0*random.uniform() - 1.0));
+                if self.contains(&position)
+                {
+                    break position
+                }
+            }
+        }
+    }
+}
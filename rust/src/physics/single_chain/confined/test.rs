@@ -0,0 +1,30 @@
+#![cfg(test)]
+use super::*;
+#[test]
+fn validate()
+{
+    assert!(Geometry::Slit{width: 1.0}.validate().is_ok());
+    assert_eq!(Geometry::Cylinder{radius: 0.0}.validate(), Err(Error::InvalidParameter{model: "Cylinder", argument: "radius", value: 0.0}));
+    assert_eq!(Geometry::Sphere{radius: -1.0}.validate(), Err(Error::InvalidParameter{model: "Sphere", argument: "radius", value: -1.0}));
+}
+#[test]
+fn sample()
+{
+    let mut random = Random::init(0);
+    for geometry in [Geometry::Slit{width: 2.0}, Geometry::Cylinder{radius: 2.0}, Geometry::Sphere{radius: 2.0}]
+    {
+        let number_of_samples = 100000;
+        let samples = (0..number_of_samples).map(|_| geometry.sample(&mut random)).collect::<Vec<[f64; 3]>>();
+        assert!(samples.iter().all(|position| geometry.contains(position)));
+        let fraction = samples.iter().filter(|position| geometry.contains(&position.map(|coordinate| 2.0*coordinate))).count() as f64/(number_of_samples as f64);
+        let expected = match geometry
+        {
+            Geometry::Slit{..} => 0.5,
+            Geometry::Cylinder{..} => 0.25,
+            Geometry::Sphere{..} => 0.125
+        };
+        assert!((fraction - expected).abs() <= 4.0*(expected*(1.0 - expected)/(number_of_samples as f64)).sqrt());
+    }
+    assert!(Geometry::Slit{width: 1.0}.has_axis());
+    assert!(!Geometry::Sphere{radius: 1.0}.has_axis());
+}
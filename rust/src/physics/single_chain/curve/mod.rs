@@ -0,0 +1,84 @@
+mod test;
+
+/// The structure of a curve of a thermodynamic function evaluated over a range of its argument.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curve
+{
+    /// The values of the argument.
+    pub abscissa: Vec<f64>,
+
+    /// The values of the function.
+    pub ordinate: Vec<f64>,
+
+    /// The units of the argument and of the function, such as `("nm", "pN")`.
+    pub units: (String, String),
+
+    /// The model that was evaluated, such as `"FJC"`.
+    pub model: String,
+
+    /// The thermodynamic ensemble, such as `"isometric"`.
+    pub ensemble: String,
+
+    /// The approximation, such as `"legendre"`, or `"exact"` if there is none.
+    pub approximation: String
+}
+
+/// The implemented functionality of curves.
+impl Curve
+{
+    /// Initializes and returns an empty curve with the given description.
+    pub fn init(model: &str, ensemble: &str, approximation: &str, units: (&str, &str)) -> Curve
+    {
+        Curve
+        {
+            abscissa: Vec::new(),
+            ordinate: Vec::new(),
+            units: (units.0.to_string(), units.1.to_string()),
+            model: model.to_string(),
+            ensemble: ensemble.to_string(),
+            approximation: approximation.to_string()
+        }
+    }
+    /// Returns the curve with the function evaluated at a number of evenly-spaced points from the minimum to the maximum of the argument, inclusive.
+    pub fn sweep<F>(mut self, function: F, minimum: &f64, maximum: &f64, number_of_points: usize) -> Curve
+    where F: Fn(&f64) -> f64
+    {
+        let step = if number_of_points > 1 {(maximum - minimum)/((number_of_points - 1) as f64)} else {0.0};
+        self.abscissa = (0..number_of_points).map(|index| minimum + (index as f64)*step).collect();
+        self.ordinate = self.abscissa.iter().map(function).collect();
+        self
+    }
+    /// Returns the curve in CSV format, with a header and one row per point that also records the units, model, ensemble, and approximation.
+    pub fn to_csv(&self) -> String
+    {
+        self.to_delimited(",")
+    }
+    /// Returns the curve in TSV format, with the same columns as the CSV format.
+    pub fn to_tsv(&self) -> String
+    {
+        self.to_delimited("\t")
+    }
+    fn to_delimited(&self, delimiter: &str) -> String
+    {
+        let mut table = ["model", "ensemble", "approximation", "abscissa", "abscissa_units", "ordinate", "ordinate_units"].join(delimiter) + "\n";
+        for (abscissa, ordinate) in self.abscissa.iter().zip(self.ordinate.iter())
+        {
+            table.push_str(&[self.model.clone(), self.ensemble.clone(), self.approximation.clone(), format!("{:e}", abscissa), self.units.0.clone(), format!("{:e}", ordinate), self.units.1.clone()].join(delimiter));
+            table.push('\n');
+        }
+        table
+    }
+    /// Returns the curve in JSON format.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String
+    {
+        serde_json::to_string(self).expect("curves always serialize")
+    }
+    /// Returns the curve read from JSON format, or the error from parsing it.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Curve, serde_json::Error>
+    {
+        serde_json::from_str(json)
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::fjc::FJC;
+use crate::physics::single_chain::test::Parameters;
+fn curve() -> Curve
+{
+    let parameters = Parameters::default();
+    let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let contour_length = (model.number_of_links as f64)*model.link_length;
+    Curve::init("FJC", "isometric", "legendre", ("nm", "pN")).sweep(|end_to_end_length| model.thermodynamics.isometric.legendre.force(end_to_end_length, &parameters.temperature_reference), &0.0, &(0.9*contour_length), 10)
+}
+#[test]
+fn sweep()
+{
+    let parameters = Parameters::default();
+    let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    let curve = curve();
+    assert_eq!(curve.abscissa.len(), 10);
+    assert_eq!(curve.ordinate.len(), 10);
+    assert_eq!(curve.abscissa[0], 0.0);
+    assert!((curve.abscissa[9]/(0.9*(model.number_of_links as f64)*model.link_length) - 1.0).abs() <= parameters.rel_tol);
+    for (abscissa, ordinate) in curve.abscissa.iter().zip(curve.ordinate.iter())
+    {
+        assert_eq!(ordinate, &model.thermodynamics.isometric.legendre.force(abscissa, &parameters.temperature_reference));
+    }
+}
+#[test]
+fn single_point()
+{
+    let curve = Curve::init("FJC", "isotensional", "exact", ("pN", "nm")).sweep(|force| 2.0*force, &1.0, &3.0, 1);
+    assert_eq!(curve.abscissa, vec![1.0]);
+    assert_eq!(curve.ordinate, vec![2.0]);
+}
+#[test]
+fn csv()
+{
+    let curve = curve();
+    let csv = curve.to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("model,ensemble,approximation,abscissa,abscissa_units,ordinate,ordinate_units"));
+    for (line, (abscissa, ordinate)) in lines.zip(curve.abscissa.iter().zip(curve.ordinate.iter()))
+    {
+        let columns: Vec<&str> = line.split(',').collect();
+        assert_eq!(columns[..3], ["FJC", "isometric", "legendre"]);
+        assert_eq!(columns[3].parse::<f64>().unwrap(), *abscissa);
+        assert_eq!(columns[4], "nm");
+        assert_eq!(columns[5].parse::<f64>().unwrap(), *ordinate);
+        assert_eq!(columns[6], "pN");
+    }
+    assert_eq!(csv.lines().count(), 11);
+}
+#[cfg(feature = "serde")]
+#[test]
+fn json()
+{
+    let curve = curve();
+    assert_eq!(Curve::from_json(&curve.to_json()).unwrap(), curve);
+    assert!(Curve::from_json("{}").is_err());
+}
@@ -1,24 +1,82 @@
 mod test;
 pub mod thermodynamics;
+use crate::physics::Constants;
+use crate::math::float::Float;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
 pub struct EFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub link_stiffness: f64,
     pub thermodynamics: self::thermodynamics::EFJC
 }
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameters<F = f64>
+{
+    pub number_of_links: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Constants,
+    pub link_length: F,
+    pub hinge_mass: F,
+    pub link_stiffness: F
+}
 impl EFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
     {
         EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             link_stiffness,
-            thermodynamics: self::thermodynamics::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
+            thermodynamics: self::thermodynamics::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants),
+        }
+    }
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<EFJC, Error>
+    {
+        minimum_number_of_links("EFJC", &number_of_links, 1)?;
+        positive("EFJC", "link_length", &link_length)?;
+        positive("EFJC", "hinge_mass", &hinge_mass)?;
+        positive("EFJC", "link_stiffness", &link_stiffness)?;
+        Ok(EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
+    pub fn parameters(&self) -> Parameters
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: self.link_length,
+            hinge_mass: self.hinge_mass,
+            link_stiffness: self.link_stiffness
+        }
+    }
+}
+impl<F: Float> Parameters<F>
+{
+    fn cast<G: Float>(&self) -> Parameters<G>
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: G::from_f64(self.link_length.to_f64()),
+            hinge_mass: G::from_f64(self.hinge_mass.to_f64()),
+            link_stiffness: G::from_f64(self.link_stiffness.to_f64())
         }
     }
 }
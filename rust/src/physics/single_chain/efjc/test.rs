@@ -70,3 +70,69 @@ mod base
         }
     }
 }
+mod error
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(EFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).is_ok());
+        assert_eq!(EFJC::try_init(0, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).err(), Some(Error::InvalidParameter{model: "EFJC", argument: "number_of_links", value: 0.0}));
+        assert_eq!(EFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, -parameters.link_stiffness_reference).err(), Some(Error::InvalidParameter{model: "EFJC", argument: "link_stiffness", value: -parameters.link_stiffness_reference}));
+    }
+}
+mod parameters
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = EFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).parameters();
+        assert_eq!(EFJC::init(model_parameters.number_of_links, model_parameters.link_length, model_parameters.hinge_mass, model_parameters.link_stiffness).parameters(), model_parameters);
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = EFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).parameters();
+        let json = serde_json::to_string(&model_parameters).unwrap();
+        assert_eq!(serde_json::from_str::<super::super::Parameters>(&json).unwrap(), model_parameters);
+    }
+}
+mod constants
+{
+    use super::*;
+    use crate::physics::
+    {
+        AVOGADRO_NUMBER,
+        BOLTZMANN_CONSTANT,
+        Constants
+    };
+    use crate::physics::single_chain::test::check_constants;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let constants = Constants::molecular();
+        let model = EFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference, constants);
+        assert_eq!(model.constants, constants);
+        assert_eq!(model.thermodynamics.isotensional.constants, constants);
+        assert_eq!(model.parameters().constants, constants);
+        assert_eq!(EFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).constants, Constants::molar());
+    }
+    #[test]
+    fn conventions()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let scale = AVOGADRO_NUMBER/1e21;
+        let thermal_energy = BOLTZMANN_CONSTANT*temperature;
+        let molar = EFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).thermodynamics.isotensional;
+        let molecular = EFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference/scale, Constants::molecular()).thermodynamics.isotensional;
+        let reduced = EFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference/thermal_energy, Constants::reduced(&temperature)).thermodynamics.isotensional;
+        check_constants(&molar, &molecular, &reduced, &temperature, &parameters.rel_tol);
+    }
+}
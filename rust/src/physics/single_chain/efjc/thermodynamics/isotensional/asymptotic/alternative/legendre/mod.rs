@@ -1,47 +1,54 @@
 mod test;
-use crate::physics::
+use crate::physics::Constants;
+use std::f64::consts::PI;
+use crate::math::special::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    langevin,
+    langevin_derivative,
+    ln_sinhc
 };
-use std::f64::consts::PI;
 pub struct EFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub link_stiffness: f64,
     number_of_links_f64: f64
 }
 impl EFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
     {
         EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             link_stiffness,
             number_of_links_f64: number_of_links as f64
         }
     }
     pub fn helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.helmholtz_free_energy(force, temperature) - self.helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.helmholtz_free_energy(force, temperature) - self.helmholtz_free_energy(&0.0, temperature)
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.helmholtz_free_energy_per_link(force, temperature) - self.helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.helmholtz_free_energy_per_link(force, temperature) - self.helmholtz_free_energy_per_link(&0.0, temperature)
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
@@ -49,14 +56,14 @@ impl EFJC
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -(nondimensional_force.sinh()/nondimensional_force).ln() - (0.5*nondimensional_force.powi(2) + nondimensional_force/nondimensional_force.tanh())/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature) - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln() + nondimensional_force/nondimensional_force.tanh() - 1.0 + nondimensional_force*(nondimensional_force + 1.0/nondimensional_force.tanh() - nondimensional_force/nondimensional_force.sinh().powi(2))/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature)
+        -ln_sinhc(nondimensional_force) - (0.5*nondimensional_force.powi(2) + (1.0 + nondimensional_force*langevin(nondimensional_force)))/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln() + nondimensional_force*langevin(nondimensional_force) + nondimensional_force*(nondimensional_force + langevin(nondimensional_force) + nondimensional_force*langevin_derivative(nondimensional_force))/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature)
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy(&ZERO, temperature)
+        self.nondimensional_helmholtz_free_energy(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy(&0.0, temperature)
     }
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy_per_link(&ZERO, temperature)
+        self.nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy_per_link(&0.0, temperature)
     }
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::efjc::thermodynamics::isotensional::test::Parameters;
 mod base
 {
@@ -282,7 +283,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let helmholtz_free_energy = model.helmholtz_free_energy(&force, &temperature);
-            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&0.0, &temperature);
             let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&force, &temperature);
             let residual_abs = &helmholtz_free_energy - &helmholtz_free_energy_0 - &relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&helmholtz_free_energy_0;
@@ -306,7 +307,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let helmholtz_free_energy_per_link = model.helmholtz_free_energy_per_link(&force, &temperature);
-            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&0.0, &temperature);
             let relative_helmholtz_free_energy_per_link = model.relative_helmholtz_free_energy_per_link(&force, &temperature);
             let residual_abs = &helmholtz_free_energy_per_link - &helmholtz_free_energy_per_link_0 - &relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&helmholtz_free_energy_per_link_0;
@@ -329,7 +330,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy = model.nondimensional_helmholtz_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy = model.nondimensional_relative_helmholtz_free_energy(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_helmholtz_free_energy - &nondimensional_helmholtz_free_energy_0 - &nondimensional_relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_0;
@@ -352,7 +353,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy_per_link = model.nondimensional_helmholtz_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy_per_link = model.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_helmholtz_free_energy_per_link - &nondimensional_helmholtz_free_energy_per_link_0 - &nondimensional_relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_per_link_0;
@@ -378,8 +379,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_0.abs() <= ZERO);
+            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -395,8 +396,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -412,8 +413,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&ZERO, &temperature);
-            assert!(nondimensional_relative_helmholtz_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -429,8 +430,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&ZERO, &temperature);
-            assert!(nondimensional_relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
 }
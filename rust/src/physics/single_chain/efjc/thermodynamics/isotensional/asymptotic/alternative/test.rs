@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::efjc::thermodynamics::isotensional::test::Parameters;
 mod base
 {
@@ -373,7 +374,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy = model.gibbs_free_energy(&force, &temperature);
-            let gibbs_free_energy_0 = model.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_0 = model.gibbs_free_energy(&0.0, &temperature);
             let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&force, &temperature);
             let residual_abs = &gibbs_free_energy - &gibbs_free_energy_0 - &relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&gibbs_free_energy_0;
@@ -397,7 +398,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy_per_link = model.gibbs_free_energy_per_link(&force, &temperature);
-            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&0.0, &temperature);
             let relative_gibbs_free_energy_per_link = model.relative_gibbs_free_energy_per_link(&force, &temperature);
             let residual_abs = &gibbs_free_energy_per_link - &gibbs_free_energy_per_link_0 - &relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&gibbs_free_energy_per_link_0;
@@ -420,7 +421,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy = model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy - &nondimensional_gibbs_free_energy_0 - &nondimensional_relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_0;
@@ -443,7 +444,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy_per_link = model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy_per_link = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy_per_link - &nondimensional_gibbs_free_energy_per_link_0 - &nondimensional_relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_per_link_0;
@@ -469,8 +470,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -486,8 +487,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -503,8 +504,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -520,8 +521,50 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_per_link_0, 0.0);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link, &temperature)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
         }
     }
 }
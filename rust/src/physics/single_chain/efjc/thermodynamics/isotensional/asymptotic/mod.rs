@@ -4,16 +4,33 @@ pub mod reduced;
 pub mod legendre;
 use crate::physics::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    Constants,
+    single_chain::
+    {
+        Isotensional,
+        ln_hinge_partition_function
+    }
 };
+use crate::physics::single_chain::efjc::Parameters;
+use crate::math::float::Float;
 use std::f64::consts::PI;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::math::special::
+{
+    langevin,
+    langevin_derivative,
+    ln_sinhc
+};
 pub struct EFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub link_stiffness: f64,
     number_of_links_f64: f64,
     pub alternative: self::alternative::EFJC,
@@ -23,72 +40,143 @@ pub struct EFJC
 impl EFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
     {
         EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             link_stiffness,
             number_of_links_f64: number_of_links as f64,
-            alternative: self::alternative::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
-            reduced: self::reduced::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness),
-            legendre: self::legendre::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
+            alternative: self::alternative::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants),
+            reduced: self::reduced::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants),
+            legendre: self::legendre::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants)
         }
     }
+    fn nondimensional_link_stiffness(&self, temperature: &f64) -> f64
+    {
+        self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature
+    }
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.link_length*self.nondimensional_end_to_end_length(&(force*self.link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+        self.link_length*self.nondimensional_end_to_end_length(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)
     }
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.link_length*self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/BOLTZMANN_CONSTANT/temperature), temperature)
+        self.link_length*self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)
     }
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*(1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/nondimensional_link_stiffness*(1.0 + (nondimensional_force.tanh() - 1.0/nondimensional_force.tanh() + 1.0/nondimensional_force)/(nondimensional_force.tanh() + nondimensional_force/nondimensional_link_stiffness)))
+        self.number_of_links_f64*self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature)
     }
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature)*(1.0 + (nondimensional_force.tanh() - 1.0/nondimensional_force.tanh() + 1.0/nondimensional_force)/(nondimensional_force.tanh() + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature)))
+        nondimensional_end_to_end_length_per_link(&self.nondimensional_link_stiffness(temperature), nondimensional_force)
+    }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.number_of_links_f64/self.link_length), temperature)*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let function = |nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature) - nondimensional_end_to_end_length_per_link;
+        let (lower, upper) = bracket(function, &(3.0*nondimensional_end_to_end_length_per_link), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
     }
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*BOLTZMANN_CONSTANT*temperature*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness - (1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness).ln() - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        self.number_of_links_f64*self.gibbs_free_energy_per_link(force, temperature)
     }
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        BOLTZMANN_CONSTANT*temperature*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness - (1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness).ln() - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        self.nondimensional_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy(force, temperature) - self.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.number_of_links_f64*self.relative_gibbs_free_energy_per_link(force, temperature)
     }
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy_per_link(force, temperature) - self.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness - (1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness).ln() - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        self.number_of_links_f64*self.nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature)
     }
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        -(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness - (1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness).ln() - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        nondimensional_gibbs_free_energy_per_link(&self.nondimensional_link_stiffness(temperature), nondimensional_force) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy(&ZERO, temperature)
+        self.number_of_links_f64*self.nondimensional_relative_gibbs_free_energy_per_link(nondimensional_force, temperature)
     }
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy_per_link(&ZERO, temperature)
+        nondimensional_relative_gibbs_free_energy_per_link(&self.nondimensional_link_stiffness(temperature), nondimensional_force)
+    }
+}
+impl Isotensional for EFJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::gibbs_free_energy(self, force, temperature)
     }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the EFJC model in the isotensional ensemble approximated using an asymptotic approach, with the parameters, force, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &F, temperature: &F) -> F
+    {
+        let thermal_energy = F::from_f64(self.constants.boltzmann_constant)**temperature;
+        F::from_f64(self.number_of_links as f64)*self.link_length*nondimensional_end_to_end_length_per_link(&(self.link_stiffness*self.link_length.powi(2)/thermal_energy), &(*force*self.link_length/thermal_energy))
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        let thermal_energy = F::from_f64(self.constants.boltzmann_constant)**temperature;
+        F::from_f64(self.number_of_links as f64)*thermal_energy*(nondimensional_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/thermal_energy), &(*force*self.link_length/thermal_energy)) - F::from_f64(0.5)*(F::from_f64(2.0*PI)*thermal_energy/self.link_stiffness).ln() - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants))
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        let thermal_energy = F::from_f64(self.constants.boltzmann_constant)**temperature;
+        F::from_f64(self.number_of_links as f64)*thermal_energy*nondimensional_relative_gibbs_free_energy_per_link(&(self.link_stiffness*self.link_length.powi(2)/thermal_energy), &(*force*self.link_length/thermal_energy))
+    }
+}
+fn nondimensional_end_to_end_length_per_link<F: Float>(nondimensional_link_stiffness: &F, nondimensional_force: &F) -> F
+{
+    let one = F::from_f64(1.0);
+    langevin(nondimensional_force) + *nondimensional_force/ *nondimensional_link_stiffness + (langevin(nondimensional_force) + *nondimensional_force*langevin_derivative(nondimensional_force))/(*nondimensional_link_stiffness + one + *nondimensional_force*langevin(nondimensional_force))
+}
+fn nondimensional_gibbs_free_energy_per_link<F: Float>(nondimensional_link_stiffness: &F, nondimensional_force: &F) -> F
+{
+    nondimensional_relative_gibbs_free_energy_per_link(nondimensional_link_stiffness, nondimensional_force) - (F::from_f64(1.0)/ *nondimensional_link_stiffness).ln_1p()
+}
+fn nondimensional_relative_gibbs_free_energy_per_link<F: Float>(nondimensional_link_stiffness: &F, nondimensional_force: &F) -> F
+{
+    let one = F::from_f64(1.0);
+    -ln_sinhc(nondimensional_force) - F::from_f64(0.5)*nondimensional_force.powi(2)/ *nondimensional_link_stiffness - (*nondimensional_force*langevin(nondimensional_force)/(*nondimensional_link_stiffness + one)).ln_1p()
 }
@@ -2,16 +2,29 @@ mod test;
 pub mod legendre;
 use crate::physics::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    Constants,
+    single_chain::
+    {
+        Isotensional
+    }
 };
 use std::f64::consts::PI;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::math::special::
+{
+    langevin,
+    ln_sinhc
+};
 pub struct EFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub link_stiffness: f64,
     number_of_links_f64: f64,
     pub legendre: self::legendre::EFJC
@@ -19,65 +32,103 @@ pub struct EFJC
 impl EFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
     {
         EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             link_stiffness,
             number_of_links_f64: number_of_links as f64,
-            legendre: self::legendre::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness)
+            legendre: self::legendre::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants)
         }
     }
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.number_of_links_f64*(self.link_length/(force*self.link_length/BOLTZMANN_CONSTANT/temperature).tanh() - BOLTZMANN_CONSTANT*temperature/force + force/self.link_stiffness)
+        self.number_of_links_f64*(self.link_length*langevin(&(force*self.link_length/self.constants.boltzmann_constant/temperature)) + force/self.link_stiffness)
     }
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.link_length/(force*self.link_length/BOLTZMANN_CONSTANT/temperature).tanh() - BOLTZMANN_CONSTANT*temperature/force + force/self.link_stiffness
+        self.link_length*langevin(&(force*self.link_length/self.constants.boltzmann_constant/temperature)) + force/self.link_stiffness
     }
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.number_of_links_f64*(1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))
+        self.number_of_links_f64*(langevin(nondimensional_force) + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature))
     }
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature)
+        langevin(nondimensional_force) + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature)
+    }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.number_of_links_f64/self.link_length), temperature)*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let function = |nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature) - nondimensional_end_to_end_length_per_link;
+        let (lower, upper) = bracket(function, &(3.0*nondimensional_end_to_end_length_per_link), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
     }
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*BOLTZMANN_CONSTANT*temperature*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature) - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(-ln_sinhc(&nondimensional_force) - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())
     }
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        BOLTZMANN_CONSTANT*temperature*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature) - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.constants.boltzmann_constant*temperature*(-ln_sinhc(&nondimensional_force) - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())
     }
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy(force, temperature) - self.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.gibbs_free_energy(force, temperature) - self.gibbs_free_energy(&0.0, temperature)
     }
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy_per_link(force, temperature) - self.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.gibbs_free_energy_per_link(force, temperature) - self.gibbs_free_energy_per_link(&0.0, temperature)
     }
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.number_of_links_f64*(-(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature) - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        self.number_of_links_f64*(-ln_sinhc(nondimensional_force) - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())
     }
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature) - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -ln_sinhc(nondimensional_force) - 0.5*nondimensional_force.powi(2)/(self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy(&ZERO, temperature)
+        self.nondimensional_gibbs_free_energy(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy(&0.0, temperature)
     }
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy_per_link(&ZERO, temperature)
+        self.nondimensional_gibbs_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_gibbs_free_energy_per_link(&0.0, temperature)
+    }
+}
+impl Isotensional for EFJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
     }
 }
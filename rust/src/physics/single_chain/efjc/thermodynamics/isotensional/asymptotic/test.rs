@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::efjc::thermodynamics::isotensional::test::Parameters;
 mod base
 {
@@ -373,11 +374,11 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy = model.gibbs_free_energy(&force, &temperature);
-            let gibbs_free_energy_0 = model.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_0 = model.gibbs_free_energy(&0.0, &temperature);
             let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&force, &temperature);
             let residual_abs = &gibbs_free_energy - &gibbs_free_energy_0 - &relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&gibbs_free_energy_0;
-            assert!(residual_abs.abs() <= parameters.abs_tol);
+            assert!(residual_abs.abs() <= parameters.abs_tol*gibbs_free_energy_0.abs().max(1.0));
             assert!(residual_rel.abs() <= parameters.rel_tol);
         }
     }
@@ -397,7 +398,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy_per_link = model.gibbs_free_energy_per_link(&force, &temperature);
-            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&0.0, &temperature);
             let relative_gibbs_free_energy_per_link = model.relative_gibbs_free_energy_per_link(&force, &temperature);
             let residual_abs = &gibbs_free_energy_per_link - &gibbs_free_energy_per_link_0 - &relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&gibbs_free_energy_per_link_0;
@@ -420,7 +421,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy = model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy - &nondimensional_gibbs_free_energy_0 - &nondimensional_relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_0;
@@ -443,7 +444,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy_per_link = model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy_per_link = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy_per_link - &nondimensional_gibbs_free_energy_per_link_0 - &nondimensional_relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_per_link_0;
@@ -469,8 +470,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -486,8 +487,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -503,8 +504,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -520,8 +521,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
 }
@@ -559,8 +560,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.end_to_end_length(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -596,8 +597,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.end_to_end_length_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -631,8 +632,8 @@ mod asymptotic
                     {
                         model.nondimensional_end_to_end_length(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -666,8 +667,8 @@ mod asymptotic
                     {
                         model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -703,8 +704,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.gibbs_free_energy(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -740,8 +741,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.gibbs_free_energy_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -777,8 +778,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.relative_gibbs_free_energy(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -814,8 +815,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.relative_gibbs_free_energy_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -849,8 +850,8 @@ mod asymptotic
                     {
                         model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -884,8 +885,8 @@ mod asymptotic
                     {
                         model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -919,8 +920,8 @@ mod asymptotic
                     {
                         model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -954,8 +955,8 @@ mod asymptotic
                     {
                         model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -998,8 +999,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.end_to_end_length(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1035,8 +1036,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.end_to_end_length_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1070,8 +1071,8 @@ mod asymptotic
                     {
                         model.nondimensional_end_to_end_length(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1105,8 +1106,8 @@ mod asymptotic
                     {
                         model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1142,8 +1143,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.gibbs_free_energy(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1179,8 +1180,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.gibbs_free_energy_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1216,8 +1217,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.relative_gibbs_free_energy(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1253,8 +1254,8 @@ mod asymptotic
                         let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                         model.relative_gibbs_free_energy_per_link(&force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1288,8 +1289,8 @@ mod asymptotic
                     {
                         model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1323,8 +1324,8 @@ mod asymptotic
                     {
                         model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1358,8 +1359,8 @@ mod asymptotic
                     {
                         model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1393,8 +1394,8 @@ mod asymptotic
                     {
                         model.alternative.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_large);
@@ -1407,3 +1408,97 @@ mod asymptotic
         }
     }
 }
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link, &temperature)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod dual
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::efjc::Parameters as ModelParameters;
+    use crate::physics::single_chain::test::
+    {
+        Parameters as DefaultParameters,
+        check_gradient
+    };
+    #[test]
+    fn value()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let model_parameters = ModelParameters {number_of_links, constants: Constants::default(), link_length, hinge_mass, link_stiffness};
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model_parameters.end_to_end_length(&force, &temperature)/model.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.gibbs_free_energy(&force, &temperature)/model.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.relative_gibbs_free_energy(&force, &temperature)/model.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn gradient()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = DefaultParameters::default().nondimensional_force_small + parameters.nondimensional_force_reference*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let point = [link_length, hinge_mass, link_stiffness, force, temperature];
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.end_to_end_length(&x[3], &x[4]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.end_to_end_length(&x[3], &x[4]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.gibbs_free_energy(&x[3], &x[4]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.gibbs_free_energy(&x[3], &x[4]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.relative_gibbs_free_energy(&x[3], &x[4]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1], link_stiffness: x[2]}.relative_gibbs_free_energy(&x[3], &x[4]), &point, &parameters.rel_tol);
+        }
+    }
+}
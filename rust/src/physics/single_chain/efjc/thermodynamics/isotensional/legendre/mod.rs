@@ -1,49 +1,72 @@
 mod test;
-use crate::physics::
+use crate::physics::Constants;
+use crate::physics::single_chain::Isometric;
+use crate::math::roots::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT,
-    single_chain::ZERO
+    bracket,
+    brent
 };
-use super::erf;
 use std::f64::consts::PI;
+use super::super::
+{
+    ln_partition_function_per_link,
+    relative_ln_partition_function_per_link,
+    mean_link_projection
+};
 pub struct EFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub link_stiffness: f64,
     number_of_links_f64: f64
 }
 impl EFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
     {
         EFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             link_stiffness,
             number_of_links_f64: number_of_links as f64
 
         }
     }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.number_of_links_f64/self.link_length), temperature)*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature;
+        let function = |nondimensional_force: &f64| mean_link_projection(&nondimensional_link_stiffness, nondimensional_force) - nondimensional_end_to_end_length_per_link;
+        let (lower, upper) = bracket(function, &(3.0*nondimensional_end_to_end_length_per_link), &-f64::INFINITY, &f64::INFINITY);
+        brent(function, &lower, &upper)
+    }
     pub fn helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.helmholtz_free_energy(force, temperature) - self.helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.helmholtz_free_energy(force, temperature) - self.helmholtz_free_energy(&0.0, temperature)
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.helmholtz_free_energy_per_link(force, temperature) - self.helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.helmholtz_free_energy_per_link(force, temperature) - self.helmholtz_free_energy_per_link(&0.0, temperature)
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
@@ -51,17 +74,45 @@ impl EFJC
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
-        let denominator = 4.0*nondimensional_force.sinh()*(1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness);
-        let fraction = ((nondimensional_force/nondimensional_link_stiffness + 1.0)*nondimensional_force.exp()*erf(&((nondimensional_force + nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt())) - (nondimensional_force/nondimensional_link_stiffness - 1.0)/nondimensional_force.exp()*erf(&((nondimensional_force - nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt())))/denominator;
-        -(nondimensional_force.sinh()/nondimensional_force).ln() - 0.5*nondimensional_force.powi(2)/nondimensional_link_stiffness - (1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness).ln() - (0.5 + ((nondimensional_force/nondimensional_link_stiffness + 1.0)*nondimensional_force.exp()*erf(&((nondimensional_force + nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt())) - (nondimensional_force/nondimensional_link_stiffness - 1.0)/nondimensional_force.exp()*erf(&((nondimensional_force - nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt())))/(4.0*nondimensional_force.sinh()*(1.0 + nondimensional_force/nondimensional_force.tanh()/nondimensional_link_stiffness))).ln() - 0.5*(2.0*PI*BOLTZMANN_CONSTANT*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln() + nondimensional_force*(1.0/nondimensional_force.tanh() - 1.0/nondimensional_force + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature)*(1.0 + (nondimensional_force.tanh() - 1.0/nondimensional_force.tanh() + 1.0/nondimensional_force)/(nondimensional_force.tanh() + nondimensional_force/(self.link_stiffness*self.link_length.powi(2)/BOLTZMANN_CONSTANT/temperature))) + (nondimensional_force.exp()*((2.0/PI/nondimensional_link_stiffness).sqrt()*(nondimensional_force/nondimensional_link_stiffness + 1.0)*(-(nondimensional_force + nondimensional_link_stiffness).powi(2)/2.0/nondimensional_link_stiffness).exp() + (1.0 + (1.0 + nondimensional_force)/nondimensional_link_stiffness)) - 1.0/nondimensional_force.exp()*((2.0/PI/nondimensional_link_stiffness).sqrt()*(nondimensional_force/nondimensional_link_stiffness - 1.0)*(-(nondimensional_force - nondimensional_link_stiffness).powi(2)/2.0/nondimensional_link_stiffness).exp() + (1.0 + (1.0 - nondimensional_force)/nondimensional_link_stiffness)*erf(&((nondimensional_force - nondimensional_link_stiffness)/(2.0*nondimensional_link_stiffness).sqrt()))) - fraction*(4.0*(nondimensional_force.cosh()*(1.0 + (1.0 + nondimensional_force/nondimensional_force.tanh())/nondimensional_link_stiffness) - nondimensional_force/nondimensional_link_stiffness/nondimensional_force.sinh())))/denominator/(1.0 + fraction))
+        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature;
+        -ln_partition_function_per_link(&nondimensional_link_stiffness, nondimensional_force) - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln() + nondimensional_force*mean_link_projection(&nondimensional_link_stiffness, nondimensional_force)
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy(&ZERO, temperature)
+        self.number_of_links_f64*self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force, temperature)
     }
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(nondimensional_force, temperature) - self.nondimensional_helmholtz_free_energy_per_link(&ZERO, temperature)
+        let nondimensional_link_stiffness = self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature;
+        -relative_ln_partition_function_per_link(&nondimensional_link_stiffness, nondimensional_force) + nondimensional_force*mean_link_projection(&nondimensional_link_stiffness, nondimensional_force)
+    }
+}
+
+/// The helmholtz free energies of the trait are functions of the end-to-end length, which is related to the force by inverting the expected end-to-end length in the isotensional ensemble, unlike those of the model, which are functions of the force.
+impl Isometric for EFJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::helmholtz_free_energy(self, &EFJC::force(self, end_to_end_length, temperature), temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::relative_helmholtz_free_energy(self, &EFJC::force(self, end_to_end_length, temperature), temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        f64::INFINITY
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
     }
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::efjc::thermodynamics::isotensional::test::Parameters;
 mod base
 {
@@ -282,7 +283,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let helmholtz_free_energy = model.helmholtz_free_energy(&force, &temperature);
-            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&0.0, &temperature);
             let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&force, &temperature);
             let residual_abs = &helmholtz_free_energy - &helmholtz_free_energy_0 - &relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&helmholtz_free_energy_0;
@@ -306,7 +307,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let helmholtz_free_energy_per_link = model.helmholtz_free_energy_per_link(&force, &temperature);
-            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&0.0, &temperature);
             let relative_helmholtz_free_energy_per_link = model.relative_helmholtz_free_energy_per_link(&force, &temperature);
             let residual_abs = &helmholtz_free_energy_per_link - &helmholtz_free_energy_per_link_0 - &relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&helmholtz_free_energy_per_link_0;
@@ -329,7 +330,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy = model.nondimensional_helmholtz_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy = model.nondimensional_relative_helmholtz_free_energy(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_helmholtz_free_energy - &nondimensional_helmholtz_free_energy_0 - &nondimensional_relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_0;
@@ -352,7 +353,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy_per_link = model.nondimensional_helmholtz_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy_per_link = model.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_helmholtz_free_energy_per_link - &nondimensional_helmholtz_free_energy_per_link_0 - &nondimensional_relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_per_link_0;
@@ -378,8 +379,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_0.abs() <= ZERO);
+            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -395,8 +396,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -412,8 +413,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&ZERO, &temperature);
-            assert!(nondimensional_relative_helmholtz_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -429,8 +430,55 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&ZERO, &temperature);
-            assert!(nondimensional_relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_per_link_0, 0.0);
+        }
+    }
+}
+mod isometric
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let reference = crate::physics::single_chain::efjc::thermodynamics::isotensional::EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = reference.end_to_end_length(&force, &temperature);
+            assert!((Isometric::force(&model, &end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((Isometric::helmholtz_free_energy(&model, &end_to_end_length, &temperature)/model.helmholtz_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((Isometric::relative_helmholtz_free_energy(&model, &end_to_end_length, &temperature)/model.relative_helmholtz_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!(end_to_end_length < model.maximum_end_to_end_length());
+        }
+    }
+    #[test]
+    fn helmholtz_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, parameters.hinge_mass_reference, link_stiffness);
+            let reference = crate::physics::single_chain::efjc::thermodynamics::isotensional::EFJC::init(number_of_links, link_length, parameters.hinge_mass_reference, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = reference.end_to_end_length(&force, &temperature);
+            let h = 1e-6*(number_of_links as f64)*link_length;
+            let finite_difference = (Isometric::helmholtz_free_energy(&model, &(end_to_end_length + h), &temperature) - Isometric::helmholtz_free_energy(&model, &(end_to_end_length - h), &temperature))/(2.0*h);
+            assert!((finite_difference - force).abs() <= parameters.rel_tol*(force + BOLTZMANN_CONSTANT*temperature/link_length));
         }
     }
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::test::Parameters as DefaultParameters;
 pub struct Parameters
 {
@@ -423,7 +424,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy = model.gibbs_free_energy(&force, &temperature);
-            let gibbs_free_energy_0 = model.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_0 = model.gibbs_free_energy(&0.0, &temperature);
             let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&force, &temperature);
             let residual_abs = &gibbs_free_energy - &gibbs_free_energy_0 - &relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&gibbs_free_energy_0;
@@ -447,7 +448,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy_per_link = model.gibbs_free_energy_per_link(&force, &temperature);
-            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&0.0, &temperature);
             let relative_gibbs_free_energy_per_link = model.relative_gibbs_free_energy_per_link(&force, &temperature);
             let residual_abs = &gibbs_free_energy_per_link - &gibbs_free_energy_per_link_0 - &relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&gibbs_free_energy_per_link_0;
@@ -470,7 +471,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy = model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy - &nondimensional_gibbs_free_energy_0 - &nondimensional_relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_0;
@@ -493,7 +494,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy_per_link = model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy_per_link = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
             let residual_abs = &nondimensional_gibbs_free_energy_per_link - &nondimensional_gibbs_free_energy_per_link_0 - &nondimensional_relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_per_link_0;
@@ -519,8 +520,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -536,8 +537,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
-            assert!(relative_gibbs_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -553,8 +554,8 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -570,8 +571,50 @@ mod zero
             let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
             let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&ZERO, &temperature);
-            assert!(nondimensional_relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_per_link_0, 0.0);
+        }
+    }
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link_0 = model.nondimensional_end_to_end_length_per_link(&0.0, &temperature);
+            assert_eq!(nondimensional_end_to_end_length_per_link_0, 0.0);
+        }
+    }
+    #[test]
+    fn continuity()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_link_stiffness = 10_f64.powf(6.0*rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = nondimensional_link_stiffness*BOLTZMANN_CONSTANT*temperature/link_length.powi(2);
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            for (nondimensional_force_below, nondimensional_force_above) in [(0.0, 1e-12), (0.5*(1.0 - 1e-12), 0.5*(1.0 + 1e-12))]
+            {
+                let nondimensional_end_to_end_length_per_link_below = model.nondimensional_end_to_end_length_per_link(&nondimensional_force_below, &temperature);
+                let nondimensional_end_to_end_length_per_link_above = model.nondimensional_end_to_end_length_per_link(&nondimensional_force_above, &temperature);
+                assert!((nondimensional_end_to_end_length_per_link_above - nondimensional_end_to_end_length_per_link_below).abs() <= parameters.abs_tol);
+                let nondimensional_relative_gibbs_free_energy_per_link_below = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force_below, &temperature);
+                let nondimensional_relative_gibbs_free_energy_per_link_above = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force_above, &temperature);
+                assert!((nondimensional_relative_gibbs_free_energy_per_link_above - nondimensional_relative_gibbs_free_energy_per_link_below).abs() <= parameters.abs_tol);
+            }
         }
     }
 }
@@ -606,8 +649,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.end_to_end_length(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -643,8 +686,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.end_to_end_length_per_link(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -678,8 +721,8 @@ mod asymptotic
                 {
                     model.nondimensional_end_to_end_length(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -713,8 +756,8 @@ mod asymptotic
                 {
                     model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -750,8 +793,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.gibbs_free_energy(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -787,8 +830,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.gibbs_free_energy_per_link(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -824,8 +867,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.relative_gibbs_free_energy(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -861,8 +904,8 @@ mod asymptotic
                     let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
                     model.relative_gibbs_free_energy_per_link(&force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -896,8 +939,8 @@ mod asymptotic
                 {
                     model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -931,8 +974,8 @@ mod asymptotic
                 {
                     model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -966,8 +1009,8 @@ mod asymptotic
                 {
                     model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -1001,8 +1044,8 @@ mod asymptotic
                 {
                     model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, &temperature).powi(2)
                 };
-                let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
-                let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_force_scale, &POINTS);
+                let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
+                let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_force_scale, &POINTS);
                 (numerator/denominator).sqrt()
             };
             let residual_rel_1 = residual_rel(parameters.nondimensional_link_stiffness_medium);
@@ -1014,3 +1057,136 @@ mod asymptotic
         }
     }
 }
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link, &temperature)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_force_large()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = 1e1_f64.powf(1.0 + 3.0*rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link, &temperature)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force_large()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = 1e1_f64.powf(1.0 + 3.0*rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod extreme
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn large_nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            for nondimensional_force in [1e3, 1e4, 1e6]
+            {
+                let step = 1e-6*nondimensional_force;
+                let derivative = (model.nondimensional_relative_gibbs_free_energy_per_link(&(nondimensional_force + step), &temperature) - model.nondimensional_relative_gibbs_free_energy_per_link(&(nondimensional_force - step), &temperature))/(2.0*step);
+                let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature);
+                assert!(nondimensional_end_to_end_length_per_link.is_finite());
+                assert!((derivative/nondimensional_end_to_end_length_per_link + 1.0).abs() <= parameters.rel_tol);
+            }
+            let nondimensional_force = 1e8;
+            let nondimensional_link_stiffness = link_stiffness*link_length.powi(2)/BOLTZMANN_CONSTANT/temperature;
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature)/(1.0 + nondimensional_force/nondimensional_link_stiffness) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn large_negative_nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            for nondimensional_force in [1.0, 3.6e2, 1e3, 1e6]
+            {
+                let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&-nondimensional_force, &temperature);
+                let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&-nondimensional_force, &temperature);
+                assert!(nondimensional_end_to_end_length_per_link.is_finite() && nondimensional_relative_gibbs_free_energy.is_finite());
+                assert_eq!(nondimensional_end_to_end_length_per_link, -model.nondimensional_end_to_end_length_per_link(&nondimensional_force, &temperature));
+                assert_eq!(nondimensional_relative_gibbs_free_energy, model.nondimensional_relative_gibbs_free_energy(&nondimensional_force, &temperature));
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+mod test;
+use std::collections::HashMap;
+use crate::error::
+{
+    Error,
+    positive
+};
+use crate::physics::AVOGADRO_NUMBER;
+use crate::physics::single_chain::fitting::Data;
+
+/// The structure of a force-extension curve measured by single-molecule force spectroscopy, in units of nm and J/(mol⋅nm).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Curve
+{
+    /// The tip-sample separations, which increase away from the surface.
+    pub extension: Vec<f64>,
+
+    /// The forces, which are positive in tension and negative in compression against the surface.
+    pub force: Vec<f64>
+}
+
+/// The implemented functionality of force-extension curves.
+impl Curve
+{
+    /// Initializes and returns the curve sorted by extension, or an error if the lengths of the measurements differ or there are fewer than two.
+    pub fn init(extension: Vec<f64>, force: Vec<f64>) -> Result<Curve, Error>
+    {
+        if force.len() != extension.len()
+        {
+            return Err(Error::InvalidParameter{model: "Curve", argument: "force", value: force.len() as f64})
+        }
+        if extension.len() < 2
+        {
+            return Err(Error::InvalidParameter{model: "Curve", argument: "extension", value: extension.len() as f64})
+        }
+        let mut order: Vec<usize> = (0..extension.len()).collect();
+        order.sort_by(|a, b| extension[*a].total_cmp(&extension[*b]));
+        Ok(Curve
+        {
+            extension: order.iter().map(|index| extension[*index]).collect(),
+            force: order.iter().map(|index| force[*index]).collect()
+        })
+    }
+    /// Returns the curve with the line fit to the force over the fraction of measurements farthest from the surface subtracted, which removes the offset and drift of the deflection after the molecule has detached.
+    pub fn subtract_baseline(&self, fraction: &f64) -> Result<Curve, Error>
+    {
+        if fraction <= &0.0 || fraction > &1.0
+        {
+            return Err(Error::InvalidParameter{model: "Curve", argument: "fraction", value: *fraction})
+        }
+        let start = self.len() - ((fraction*(self.len() as f64)).round() as usize).clamp(2, self.len());
+        let count = (self.len() - start) as f64;
+        let mean_extension = self.extension[start..].iter().sum::<f64>()/count;
+        let mean_force = self.force[start..].iter().sum::<f64>()/count;
+        let variance: f64 = self.extension[start..].iter().map(|extension| (extension - mean_extension).powi(2)).sum();
+        let slope = if variance > 0.0 {self.extension[start..].iter().zip(self.force[start..].iter()).map(|(extension, force)| (extension - mean_extension)*(force - mean_force)).sum::<f64>()/variance} else {0.0};
+        Ok(Curve
+        {
+            extension: self.extension.clone(),
+            force: self.extension.iter().zip(self.force.iter()).map(|(extension, force)| force - mean_force - slope*(extension - mean_extension)).collect()
+        })
+    }
+    /// The extension at which the tip leaves the surface, where the force first crosses zero from compression, interpolated linearly between the neighboring measurements.
+    ///
+    /// The baseline should be subtracted first, and an error is returned if the curve does not start in compression or never leaves it.
+    pub fn contact_point(&self) -> Result<f64, Error>
+    {
+        if self.force[0] >= 0.0
+        {
+            return Err(Error::OutOfDomain{model: "Curve", argument: "force", value: self.force[0]})
+        }
+        let index = self.force.iter().position(|force| force >= &0.0).ok_or(Error::OutOfDomain{model: "Curve", argument: "force", value: self.force[self.len() - 1]})?;
+        let (force_0, force_1) = (self.force[index - 1], self.force[index]);
+        Ok(self.extension[index - 1] + (self.extension[index] - self.extension[index - 1])*force_0/(force_0 - force_1))
+    }
+    /// Returns the data of the stretched molecule at the temperature, with the extensions measured from the contact point and the measurements kept from there up to the largest force, beyond which the molecule has detached.
+    pub fn data(&self, temperature: &f64) -> Result<Data, Error>
+    {
+        positive("Curve", "temperature", temperature)?;
+        let contact_point = self.contact_point()?;
+        let rupture = (0..self.len()).max_by(|a, b| self.force[*a].total_cmp(&self.force[*b])).expect("there are at least two measurements");
+        let kept: Vec<usize> = (0..=rupture).filter(|index| self.extension[*index] > contact_point).collect();
+        Data::init(kept.iter().map(|index| self.extension[*index] - contact_point).collect(), kept.iter().map(|index| self.force[*index]).collect(), vec![*temperature])
+    }
+    /// The number of measurements.
+    pub fn len(&self) -> usize
+    {
+        self.extension.len()
+    }
+    /// Whether there are no measurements.
+    pub fn is_empty(&self) -> bool
+    {
+        self.extension.is_empty()
+    }
+}
+
+/// Returns the curve in a delimited text file, with columns separated by tabs, commas, semicolons, or whitespace.
+///
+/// Lines starting with a number sign are comments, and the header names each column with its units in parentheses or brackets, such as `Separation (nm)` or `Force [pN]`.
+/// The extension is the first column named extension, separation, distance, or length, the force is the first column named force, and an error is returned if either is missing, its units are unknown, or a row does not have a number in each.
+pub fn delimited(text: &str) -> Result<Curve, Error>
+{
+    let mut lines = text.lines().enumerate().map(|(number, line)| (number, line.trim())).filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+    let (_, header) = lines.next().ok_or(Error::InvalidParameter{model: "Import", argument: "header", value: 0.0})?;
+    let delimiter = ['\t', ',', ';'].into_iter().find(|delimiter| header.contains(*delimiter));
+    let columns: Vec<(String, String)> = split(header, delimiter).iter().map(|column| name_and_units(column)).collect();
+    let find = |names: &[&str]| columns.iter().position(|(name, _)| names.iter().any(|candidate| name.contains(candidate)));
+    let extension_column = find(&["extension", "separation", "distance", "length"]).ok_or(Error::InvalidParameter{model: "Import", argument: "extension", value: columns.len() as f64})?;
+    let force_column = find(&["force"]).ok_or(Error::InvalidParameter{model: "Import", argument: "force", value: columns.len() as f64})?;
+    let length_scale = length_scale(&columns[extension_column].1).ok_or(Error::InvalidParameter{model: "Import", argument: "units", value: extension_column as f64})?;
+    let force_scale = force_scale(&columns[force_column].1).ok_or(Error::InvalidParameter{model: "Import", argument: "units", value: force_column as f64})?;
+    let mut extension = Vec::new();
+    let mut force = Vec::new();
+    for (number, line) in lines
+    {
+        let row = numbers(&split(line, delimiter), number)?;
+        extension.push(length_scale*row.get(extension_column).ok_or(Error::InvalidParameter{model: "Import", argument: "row", value: (number + 1) as f64})?);
+        force.push(force_scale*row.get(force_column).ok_or(Error::InvalidParameter{model: "Import", argument: "row", value: (number + 1) as f64})?);
+    }
+    Curve::init(extension, force)
+}
+
+/// Returns the curve of the named segment, such as `extend` or `retract`, in a text export of a JPK force curve.
+///
+/// The properties of the file and each segment are given in comment lines, where the columns and their units are required, and the spring constant in N/m is required unless the tip-sample separation and the force are both exported.
+/// The extension is the tip-sample separation when it is exported, and otherwise the height increased by the vertical deflection, and the force is the vertical deflection times the spring constant with the sign changed, so that pulling on the molecule is positive.
+pub fn jpk(text: &str, segment: &str) -> Result<Curve, Error>
+{
+    let mut properties: HashMap<String, String> = HashMap::new();
+    let mut rows: Vec<Vec<f64>> = Vec::new();
+    let mut in_data = false;
+    for (number, line) in text.lines().enumerate().map(|(number, line)| (number, line.trim()))
+    {
+        if let Some(comment) = line.strip_prefix('#')
+        {
+            if in_data
+            {
+                if properties.get("segment").map(|name| name.as_str()) == Some(segment)
+                {
+                    break
+                }
+                rows.clear();
+                in_data = false;
+            }
+            if let Some((key, value)) = comment.split_once(':')
+            {
+                properties.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+        else if !line.is_empty()
+        {
+            rows.push(numbers(&split(line, None), number)?);
+            in_data = true;
+        }
+    }
+    if properties.get("segment").map(|name| name.as_str()) != Some(segment) || rows.is_empty()
+    {
+        return Err(Error::InvalidParameter{model: "Import", argument: "segment", value: rows.len() as f64})
+    }
+    let names = split(properties.get("columns").ok_or(Error::InvalidParameter{model: "Import", argument: "columns", value: 0.0})?, None);
+    let units = split(properties.get("units").ok_or(Error::InvalidParameter{model: "Import", argument: "units", value: 0.0})?, None);
+    if units.len() != names.len()
+    {
+        return Err(Error::InvalidParameter{model: "Import", argument: "units", value: units.len() as f64})
+    }
+    let spring_constant = properties.get("springConstant").and_then(|value| value.parse::<f64>().ok());
+    let column = |name: &str| -> Result<Vec<f64>, Error>
+    {
+        let index = names.iter().position(|entry| entry == name).ok_or(Error::InvalidParameter{model: "Import", argument: "columns", value: names.len() as f64})?;
+        rows.iter().map(|row| row.get(index).copied().ok_or(Error::InvalidParameter{model: "Import", argument: "row", value: row.len() as f64})).collect()
+    };
+    let units_of = |name: &str| units[names.iter().position(|entry| entry == name).expect("the column exists")].as_str();
+    let deflection = column("vDeflection")?;
+    if let Some(force_scale) = force_scale(units_of("vDeflection")).filter(|_| names.iter().any(|name| name == "tipSampleSeparation"))
+    {
+        let length_scale = length_scale(units_of("tipSampleSeparation")).ok_or(Error::InvalidParameter{model: "Import", argument: "units", value: 0.0})?;
+        return Curve::init(column("tipSampleSeparation")?.iter().map(|separation| length_scale*separation).collect(), deflection.iter().map(|deflection| -force_scale*deflection).collect())
+    }
+    let spring_constant = spring_constant.ok_or(Error::InvalidParameter{model: "Import", argument: "springConstant", value: 0.0})?;
+    positive("Import", "springConstant", &spring_constant)?;
+    let height_name = ["tipSampleSeparation", "measuredHeight", "smoothedMeasuredHeight", "capacitiveSensorHeight", "height"].into_iter().find(|name| names.iter().any(|entry| entry == name)).ok_or(Error::InvalidParameter{model: "Import", argument: "columns", value: names.len() as f64})?;
+    let height_scale = length_scale(units_of(height_name)).ok_or(Error::InvalidParameter{model: "Import", argument: "units", value: 0.0})?;
+    let deflection: Vec<f64> = match (length_scale(units_of("vDeflection")), force_scale(units_of("vDeflection")))
+    {
+        (Some(scale), _) => deflection.iter().map(|deflection| scale*deflection).collect(),
+        (None, Some(scale)) => deflection.iter().map(|deflection| scale*deflection/stiffness(&spring_constant)).collect(),
+        (None, None) => return Err(Error::InvalidParameter{model: "Import", argument: "units", value: 0.0})
+    };
+    let height: Vec<f64> = column(height_name)?.iter().map(|height| height_scale*height).collect();
+    cantilever(&height, &deflection, &spring_constant, height_name == "tipSampleSeparation")
+}
+
+/// Returns the curve of the segment of an Asylum Research force curve saved as an Igor Pro binary wave of version 5.
+///
+/// The wave has a column for each channel labeled by its dimension labels, where the deflection `Defl` and the sensor height `ZSnsr` are required in meters, and the note gives the spring constant in N/m and optionally the indexes separating the segments.
+/// The segment runs between its consecutive indexes, such as the retraction being segment one, and is the whole wave when there are no indexes.
+/// The extension is the deflection less the sensor height, which increases toward the surface, and the force is the deflection times the spring constant with the sign changed, so that pulling on the molecule is positive.
+/// An error is returned if the header is not that of a version 5 wave, its checksum fails, its data is not floating point, or a required channel or property is missing.
+pub fn ibw(bytes: &[u8], segment: usize) -> Result<Curve, Error>
+{
+    let invalid = |argument: &'static str, value: usize| Error::InvalidParameter{model: "Import", argument, value: value as f64};
+    if bytes.len() < 384
+    {
+        return Err(invalid("length", bytes.len()))
+    }
+    let little_endian = bytes[0] != 0;
+    let short = |offset: usize| if little_endian {i16::from_le_bytes([bytes[offset], bytes[offset + 1]])} else {i16::from_be_bytes([bytes[offset], bytes[offset + 1]])};
+    let long = |offset: usize| -> usize
+    {
+        let word = [bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]];
+        (if little_endian {i32::from_le_bytes(word)} else {i32::from_be_bytes(word)}).max(0) as usize
+    };
+    if short(0) != 5
+    {
+        return Err(invalid("version", short(0) as usize))
+    }
+    if (0..192).fold(0_i16, |sum, index| sum.wrapping_add(short(2*index))) != 0
+    {
+        return Err(invalid("checksum", 0))
+    }
+    let number_of_points = long(64 + 12);
+    let size = match short(64 + 16)
+    {
+        2 => 4,
+        4 => 8,
+        kind => return Err(invalid("type", kind as usize))
+    };
+    let number_of_rows = long(64 + 68);
+    let number_of_columns = long(64 + 72).max(1);
+    let data = 384;
+    let note = 64 + long(4) + long(8);
+    let labels = note + long(12) + long(16) + (0..4).map(|dimension| long(20 + 4*dimension)).sum::<usize>() + long(36);
+    if number_of_rows*number_of_columns != number_of_points || data + size*number_of_points > note || labels + long(40) > bytes.len()
+    {
+        return Err(invalid("length", bytes.len()))
+    }
+    let text = |start: usize, length: usize| String::from_utf8_lossy(&bytes[start..start + length]).trim_end_matches('\0').to_string();
+    let properties: HashMap<String, String> = text(note, long(12)).split(['\r', '\n']).filter_map(|line| line.split_once(':')).map(|(key, value)| (key.trim().to_string(), value.trim().to_string())).collect();
+    let channel = |name: &str| -> Result<Vec<f64>, Error>
+    {
+        let column = (0..number_of_columns).filter(|_| long(40) >= 32*(number_of_columns + 1)).find(|column| text(labels + 32*(column + 1), 32) == name).ok_or(invalid("columns", number_of_columns))?;
+        Ok((0..number_of_rows).map(|row|
+        {
+            let offset = data + size*(column*number_of_rows + row);
+            let bytes = &bytes[offset..offset + size];
+            match (size, little_endian)
+            {
+                (4, true) => f32::from_le_bytes(bytes.try_into().expect("four bytes")) as f64,
+                (4, false) => f32::from_be_bytes(bytes.try_into().expect("four bytes")) as f64,
+                (_, true) => f64::from_le_bytes(bytes.try_into().expect("eight bytes")),
+                (_, false) => f64::from_be_bytes(bytes.try_into().expect("eight bytes"))
+            }
+        }).collect())
+    };
+    let spring_constant = properties.get("SpringConstant").and_then(|value| value.parse::<f64>().ok()).ok_or(invalid("SpringConstant", 0))?;
+    positive("Import", "SpringConstant", &spring_constant)?;
+    let (start, end) = match properties.get("Indexes")
+    {
+        Some(indexes) =>
+        {
+            let indexes: Vec<usize> = indexes.split(',').filter_map(|index| index.trim().parse::<usize>().ok()).collect();
+            if segment + 1 >= indexes.len() || indexes[segment + 1] >= number_of_rows || indexes[segment] >= indexes[segment + 1]
+            {
+                return Err(invalid("segment", segment))
+            }
+            (indexes[segment], indexes[segment + 1] + 1)
+        },
+        None => (0, number_of_rows)
+    };
+    let deflection: Vec<f64> = channel("Defl")?[start..end].iter().map(|deflection| 1e9*deflection).collect();
+    let height: Vec<f64> = channel("ZSnsr")?[start..end].iter().map(|height| -1e9*height).collect();
+    cantilever(&height, &deflection, &spring_constant, false)
+}
+
+fn cantilever(height: &[f64], deflection: &[f64], spring_constant: &f64, separated: bool) -> Result<Curve, Error>
+{
+    let extension = if separated {height.to_vec()} else {height.iter().zip(deflection.iter()).map(|(height, deflection)| height + deflection).collect()};
+    Curve::init(extension, deflection.iter().map(|deflection| -stiffness(spring_constant)*deflection).collect())
+}
+
+fn stiffness(spring_constant: &f64) -> f64
+{
+    spring_constant*AVOGADRO_NUMBER/1e18
+}
+
+fn split(line: &str, delimiter: Option<char>) -> Vec<String>
+{
+    match delimiter
+    {
+        Some(delimiter) => line.split(delimiter).map(|entry| entry.trim().trim_matches('"').to_string()).collect(),
+        None => line.split_whitespace().map(|entry| entry.trim_matches('"').to_string()).collect()
+    }
+}
+
+fn numbers(entries: &[String], number: usize) -> Result<Vec<f64>, Error>
+{
+    entries.iter().map(|entry| entry.parse::<f64>().map_err(|_| Error::InvalidParameter{model: "Import", argument: "row", value: (number + 1) as f64})).collect()
+}
+
+fn name_and_units(column: &str) -> (String, String)
+{
+    match column.find(['(', '['])
+    {
+        Some(start) => (column[..start].trim().to_lowercase(), column[start + 1..].trim_end_matches([')', ']']).trim().to_string()),
+        None => (column.trim().to_lowercase(), String::new())
+    }
+}
+
+fn length_scale(units: &str) -> Option<f64>
+{
+    match units
+    {
+        "m" => Some(1e9),
+        "mm" => Some(1e6),
+        "um" | "µm" | "μm" => Some(1e3),
+        "nm" => Some(1.0),
+        "pm" => Some(1e-3),
+        "A" | "Å" | "angstrom" => Some(1e-1),
+        _ => None
+    }
+}
+
+fn force_scale(units: &str) -> Option<f64>
+{
+    match units
+    {
+        "N" => Some(AVOGADRO_NUMBER/1e9),
+        "mN" => Some(AVOGADRO_NUMBER/1e12),
+        "uN" | "µN" | "μN" => Some(AVOGADRO_NUMBER/1e15),
+        "nN" => Some(AVOGADRO_NUMBER/1e18),
+        "pN" => Some(AVOGADRO_NUMBER/1e21),
+        "fN" => Some(AVOGADRO_NUMBER/1e24),
+        "J/(mol*nm)" | "J/mol/nm" => Some(1.0),
+        _ => None
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+use super::*;
+static CSV: &str = include_str!("fixtures/curve.csv");
+static TSV: &str = include_str!("fixtures/curve.tsv");
+static JPK: &str = include_str!("fixtures/curve.jpk-force.txt");
+static IBW: &[u8] = include_bytes!("fixtures/curve.ibw");
+static CONTACT_POINT: f64 = 11.25;
+static SLOPE: f64 = 2.0;
+static RUPTURE: f64 = 45.0;
+fn check(curve: &Curve, tolerance: &f64)
+{
+    assert_eq!(curve.len(), 25);
+    let curve = curve.subtract_baseline(&0.25).unwrap();
+    assert!((curve.contact_point().unwrap() - CONTACT_POINT).abs() <= *tolerance);
+    let data = curve.data(&300.0).unwrap();
+    assert_eq!(data.len(), 14);
+    assert!((data.extension[data.len() - 1] - (RUPTURE - CONTACT_POINT)).abs() <= *tolerance);
+    for (extension, force) in data.extension.iter().zip(data.force.iter())
+    {
+        assert!((force/(SLOPE*extension*AVOGADRO_NUMBER/1e21) - 1.0).abs() <= *tolerance);
+    }
+}
+mod delimited
+{
+    use super::*;
+    #[test]
+    fn csv()
+    {
+        check(&delimited(CSV).unwrap(), &1e-6);
+    }
+    #[test]
+    fn tsv()
+    {
+        let curve = delimited(TSV).unwrap();
+        check(&curve, &1e-6);
+        delimited(CSV).unwrap().extension.iter().zip(curve.extension.iter()).for_each(|(csv, tsv)| assert!((csv - tsv).abs() <= 1e-9));
+    }
+    #[test]
+    fn invalid()
+    {
+        assert_eq!(delimited(&CSV.replace("(pN)", "(lbf)")), Err(Error::InvalidParameter{model: "Import", argument: "units", value: 1.0}));
+        assert_eq!(delimited(&CSV.replace("Force", "Time")), Err(Error::InvalidParameter{model: "Import", argument: "force", value: 2.0}));
+        assert_eq!(delimited(&(CSV.to_string() + "62.5,NaN?\n")), Err(Error::InvalidParameter{model: "Import", argument: "row", value: 28.0}));
+        assert_eq!(delimited("# nothing\n"), Err(Error::InvalidParameter{model: "Import", argument: "header", value: 0.0}));
+    }
+}
+mod jpk
+{
+    use super::*;
+    #[test]
+    fn retract()
+    {
+        let curve = jpk(JPK, "retract").unwrap();
+        check(&curve, &1e-6);
+        delimited(CSV).unwrap().extension.iter().zip(curve.extension.iter()).for_each(|(csv, jpk)| assert!((csv - jpk).abs() <= 1e-6));
+    }
+    #[test]
+    fn extend()
+    {
+        assert_eq!(jpk(JPK, "extend").unwrap(), jpk(JPK, "retract").unwrap());
+    }
+    #[test]
+    fn invalid()
+    {
+        assert_eq!(jpk(JPK, "pause"), Err(Error::InvalidParameter{model: "Import", argument: "segment", value: 25.0}));
+        assert_eq!(jpk(&JPK.replace("# springConstant: 0.05\n", ""), "retract"), Err(Error::InvalidParameter{model: "Import", argument: "springConstant", value: 0.0}));
+        assert_eq!(jpk(&JPK.replace("units: m N s", "units: m N"), "retract"), Err(Error::InvalidParameter{model: "Import", argument: "units", value: 2.0}));
+    }
+}
+mod ibw
+{
+    use super::*;
+    #[test]
+    fn retract()
+    {
+        let curve = ibw(IBW, 1).unwrap();
+        check(&curve, &1e-4);
+        delimited(CSV).unwrap().extension.iter().zip(curve.extension.iter()).for_each(|(csv, ibw)| assert!((csv - ibw).abs() <= 1e-4));
+    }
+    #[test]
+    fn approach()
+    {
+        check(&ibw(IBW, 0).unwrap(), &1e-4);
+    }
+    #[test]
+    fn invalid()
+    {
+        assert_eq!(ibw(IBW, 2), Err(Error::InvalidParameter{model: "Import", argument: "segment", value: 2.0}));
+        let mut corrupted = IBW.to_vec();
+        corrupted[100] ^= 1;
+        assert_eq!(ibw(&corrupted, 1), Err(Error::InvalidParameter{model: "Import", argument: "checksum", value: 0.0}));
+        assert_eq!(ibw(&IBW[..256], 1), Err(Error::InvalidParameter{model: "Import", argument: "length", value: 256.0}));
+    }
+}
+mod curve
+{
+    use super::*;
+    #[test]
+    fn invalid()
+    {
+        assert_eq!(Curve::init(vec![1.0, 2.0], vec![1.0]), Err(Error::InvalidParameter{model: "Curve", argument: "force", value: 1.0}));
+        assert_eq!(delimited(CSV).unwrap().subtract_baseline(&0.0), Err(Error::InvalidParameter{model: "Curve", argument: "fraction", value: 0.0}));
+        assert_eq!(Curve::init(vec![1.0, 2.0], vec![1.0, 2.0]).unwrap().contact_point(), Err(Error::OutOfDomain{model: "Curve", argument: "force", value: 1.0}));
+        assert_eq!(Curve::init(vec![1.0, 2.0], vec![-1.0, -2.0]).unwrap().contact_point(), Err(Error::OutOfDomain{model: "Curve", argument: "force", value: -2.0}));
+    }
+}
@@ -0,0 +1,467 @@
+mod test;
+
+/// Importers of force-extension curves from single-molecule force spectroscopy data files.
+pub mod import;
+
+use std::f64::consts::PI;
+use crate::error::
+{
+    Error,
+    positive
+};
+use crate::math::least_squares::levenberg_marquardt;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::single_chain::
+{
+    ideal,
+    fjc,
+    efjc,
+    swfjc
+};
+
+static TOLERANCE: f64 = 1e-10;
+
+/// The structure of force-extension data measured at one or more temperatures.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Data
+{
+    /// The measured extensions, which are the end-to-end lengths of the chain, or the potential distances of the instrument when it is fit with its compliance.
+    pub extension: Vec<f64>,
+
+    /// The measured forces.
+    pub force: Vec<f64>,
+
+    /// The temperatures of the measurements, or a single temperature for all of them.
+    pub temperature: Vec<f64>
+}
+
+/// The implemented functionality of force-extension data.
+impl Data
+{
+    /// Initializes and returns the data, or an error if the lengths of the measurements differ or a temperature is not positive.
+    pub fn init(extension: Vec<f64>, force: Vec<f64>, temperature: Vec<f64>) -> Result<Data, Error>
+    {
+        if force.len() != extension.len()
+        {
+            return Err(Error::InvalidParameter{model: "Data", argument: "force", value: force.len() as f64})
+        }
+        if temperature.len() != 1 && temperature.len() != extension.len()
+        {
+            return Err(Error::InvalidParameter{model: "Data", argument: "temperature", value: temperature.len() as f64})
+        }
+        temperature.iter().try_for_each(|temperature| positive("Data", "temperature", temperature))?;
+        Ok(Data
+        {
+            extension,
+            force,
+            temperature
+        })
+    }
+    /// The number of measurements.
+    pub fn len(&self) -> usize
+    {
+        self.extension.len()
+    }
+    /// Whether there are no measurements.
+    pub fn is_empty(&self) -> bool
+    {
+        self.extension.is_empty()
+    }
+    fn temperature(&self, index: usize) -> &f64
+    {
+        &self.temperature[if self.temperature.len() == 1 {0} else {index}]
+    }
+}
+
+/// The functionality common to single-chain models in an ensemble that can be fit to force-extension data.
+///
+/// The number of links is always fit, and the remaining parameters that are fit are given by each model.
+pub trait Fit: Sized
+{
+    /// The names of the parameters that are fit besides the number of links.
+    fn names(&self) -> Vec<&'static str>;
+    /// The name of the model, which identifies it in errors.
+    fn name(&self) -> &'static str;
+    /// The number of links in the chain.
+    fn number_of_links(&self) -> u8;
+    /// The values of the parameters that are fit besides the number of links.
+    fn parameters(&self) -> Vec<f64>;
+    /// Returns the model with the number of links and values of the parameters that are fit, keeping its other parameters.
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self;
+    /// The measured extension or force that the model predicts.
+    fn observation(&self, extension: &f64, force: &f64) -> f64;
+    /// The extension or force predicted by the model from the other measurement at a temperature.
+    fn prediction(&self, extension: &f64, force: &f64, temperature: &f64) -> f64;
+    /// Whether the number of links and the other parameters can be estimated separately, which they cannot when the predictions depend only on a combination of them.
+    fn identifiable(&self) -> bool
+    {
+        true
+    }
+    /// The difference between the prediction of the model and the measurement at a temperature.
+    fn residual(&self, extension: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.prediction(extension, force, temperature) - self.observation(extension, force)
+    }
+}
+
+/// The structure of the estimated parameters of a model fit to force-extension data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate
+{
+    /// The names of the parameters, starting with the number of links.
+    pub names: Vec<&'static str>,
+
+    /// The estimated values of the parameters, where the number of links is relaxed to be continuous.
+    pub parameters: Vec<f64>,
+
+    /// The asymptotic covariance of the estimated parameters.
+    pub covariance: Vec<Vec<f64>>,
+
+    /// The residuals of the fit model at each measurement.
+    pub residuals: Vec<f64>,
+
+    /// The number of iterations taken.
+    pub iterations: u32
+}
+
+/// The implemented functionality of estimated parameters.
+impl Estimate
+{
+    /// The estimated value of the named parameter.
+    pub fn parameter(&self, name: &str) -> Option<f64>
+    {
+        self.names.iter().position(|entry| entry == &name).map(|index| self.parameters[index])
+    }
+    /// The standard errors of the estimated parameters.
+    pub fn standard_errors(&self) -> Vec<f64>
+    {
+        (0..self.parameters.len()).map(|index| self.covariance[index][index].sqrt()).collect()
+    }
+    /// The two-sided confidence intervals of the estimated parameters at the confidence level, using the Student's t-distribution with the degrees of freedom of the fit.
+    pub fn confidence_intervals(&self, confidence: &f64) -> Vec<(f64, f64)>
+    {
+        let quantile = student_t_quantile(confidence, &self.degrees_of_freedom());
+        self.parameters.iter().zip(self.standard_errors().iter()).map(|(parameter, error)| (parameter - quantile*error, parameter + quantile*error)).collect()
+    }
+    /// The number of measurements in excess of the number of parameters.
+    pub fn degrees_of_freedom(&self) -> usize
+    {
+        self.residuals.len() - self.parameters.len()
+    }
+    /// The sum of the squared residuals.
+    pub fn sum_of_squared_residuals(&self) -> f64
+    {
+        self.residuals.iter().map(|residual| residual.powi(2)).sum()
+    }
+    /// The root-mean-square of the residuals.
+    pub fn root_mean_square_residual(&self) -> f64
+    {
+        (self.sum_of_squared_residuals()/(self.residuals.len() as f64)).sqrt()
+    }
+    /// The largest magnitude of the residuals.
+    pub fn maximum_absolute_residual(&self) -> f64
+    {
+        self.residuals.iter().fold(0.0, |maximum, residual| residual.abs().max(maximum))
+    }
+    /// The Durbin-Watson statistic of the residuals in the order of the measurements, which is near two for uncorrelated residuals and near zero when the model misses systematic trends along a trace.
+    pub fn durbin_watson(&self) -> f64
+    {
+        self.residuals.windows(2).map(|pair| (pair[1] - pair[0]).powi(2)).sum::<f64>()/self.sum_of_squared_residuals()
+    }
+    /// Returns the model with the estimated parameters, where the number of links is rounded to the nearest integer.
+    pub fn model<T: Fit>(&self, model: &T) -> T
+    {
+        model.refit(self.parameters[0].round() as u8, &self.parameters[1..])
+    }
+}
+
+/// Returns the residuals of the model with the parameters at each measurement, where the first parameter is the number of links relaxed to be continuous.
+///
+/// The residuals are interpolated linearly between the models with the neighboring integer numbers of links, and are NaN if the number of links is not between one and the largest number of links.
+pub fn residuals<T: Fit>(model: &T, data: &Data, parameters: &[f64]) -> Vec<f64>
+{
+    let lower = parameters[0].floor();
+    if !(1.0..u8::MAX as f64).contains(&lower)
+    {
+        return vec![f64::NAN; data.len()]
+    }
+    let fraction = parameters[0] - lower;
+    let lower_model = model.refit(lower as u8, &parameters[1..]);
+    let upper_model = model.refit(lower as u8 + 1, &parameters[1..]);
+    (0..data.len()).map(|index|
+    {
+        let lower_residual = lower_model.residual(&data.extension[index], &data.force[index], data.temperature(index));
+        if fraction == 0.0
+        {
+            lower_residual
+        }
+        else
+        {
+            (1.0 - fraction)*lower_residual + fraction*upper_model.residual(&data.extension[index], &data.force[index], data.temperature(index))
+        }
+    }).collect()
+}
+
+/// Returns the estimated parameters of the model fit to the data using the Levenberg-Marquardt algorithm, starting from the parameters of the model.
+///
+/// The number of links is relaxed to be continuous as in [`residuals`], which is exact in the isotensional ensemble where the end-to-end length is proportional to the number of links.
+/// An error is returned if the parameters are not identifiable, as for the ideal chain, whose end-to-end length depends only on the number of links times the square of the link length, if there are not more measurements than parameters, if the fit does not converge, or if the covariance of the parameters is singular.
+pub fn fit<T: Fit>(model: &T, data: &Data) -> Result<Estimate, Error>
+{
+    if !model.identifiable()
+    {
+        return Err(Error::InvalidParameter{model: model.name(), argument: "number_of_links", value: model.number_of_links() as f64})
+    }
+    let mut initial = vec![model.number_of_links() as f64];
+    initial.extend(model.parameters());
+    if data.len() <= initial.len()
+    {
+        return Err(Error::InvalidParameter{model: "Data", argument: "extension", value: data.len() as f64})
+    }
+    let minimum = levenberg_marquardt(|parameters| residuals(model, data, parameters), &initial, &TOLERANCE);
+    if !minimum.converged
+    {
+        return Err(Error::NonConvergence{model: model.name(), argument: "iterations", value: minimum.iterations as f64})
+    }
+    let covariance = minimum.covariance().ok_or(Error::NonConvergence{model: model.name(), argument: "covariance", value: minimum.sum_of_squared_residuals()})?;
+    let mut names = vec!["number_of_links"];
+    names.extend(model.names());
+    Ok(Estimate
+    {
+        names,
+        parameters: minimum.parameters,
+        covariance,
+        residuals: minimum.residuals,
+        iterations: minimum.iterations
+    })
+}
+
+/// The structure of an FJC model pulled through an instrument of finite stiffness, which is fit in the modified canonical ensemble.
+///
+/// The extensions are the potential distances of the instrument, the forces are those it measures, and the stiffness of the instrument is fit.
+pub struct Compliant
+{
+    /// The FJC model in the modified canonical ensemble.
+    pub model: fjc::thermodynamics::modified_canonical::FJC,
+
+    /// The stiffness of the instrument.
+    pub potential_stiffness: f64
+}
+
+/// The implemented functionality of FJC models pulled through an instrument of finite stiffness.
+impl Compliant
+{
+    /// Initializes and returns the model pulled through an instrument of the stiffness.
+    pub fn init(model: fjc::thermodynamics::modified_canonical::FJC, potential_stiffness: f64) -> Compliant
+    {
+        Compliant
+        {
+            model,
+            potential_stiffness
+        }
+    }
+}
+
+impl Fit for ideal::thermodynamics::isotensional::Ideal
+{
+    fn names(&self) -> Vec<&'static str>
+    {
+        vec!["link_length"]
+    }
+    fn name(&self) -> &'static str
+    {
+        "Ideal"
+    }
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn parameters(&self) -> Vec<f64>
+    {
+        vec![self.link_length]
+    }
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self
+    {
+        Self::init_with_constants(number_of_links, parameters[0], self.hinge_mass, self.constants)
+    }
+    fn observation(&self, extension: &f64, _force: &f64) -> f64
+    {
+        *extension
+    }
+    fn prediction(&self, _extension: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)
+    }
+    fn identifiable(&self) -> bool
+    {
+        false
+    }
+}
+
+impl Fit for fjc::thermodynamics::isotensional::FJC
+{
+    fn names(&self) -> Vec<&'static str>
+    {
+        vec!["link_length"]
+    }
+    fn name(&self) -> &'static str
+    {
+        "FJC"
+    }
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn parameters(&self) -> Vec<f64>
+    {
+        vec![self.link_length]
+    }
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self
+    {
+        Self::init_with_constants(number_of_links, parameters[0], self.hinge_mass, self.constants)
+    }
+    fn observation(&self, extension: &f64, _force: &f64) -> f64
+    {
+        *extension
+    }
+    fn prediction(&self, _extension: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)
+    }
+}
+
+impl Fit for efjc::thermodynamics::isotensional::EFJC
+{
+    fn names(&self) -> Vec<&'static str>
+    {
+        vec!["link_length", "link_stiffness"]
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn parameters(&self) -> Vec<f64>
+    {
+        vec![self.link_length, self.link_stiffness]
+    }
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self
+    {
+        Self::init_with_constants(number_of_links, parameters[0], self.hinge_mass, parameters[1], self.constants)
+    }
+    fn observation(&self, extension: &f64, _force: &f64) -> f64
+    {
+        *extension
+    }
+    fn prediction(&self, _extension: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)
+    }
+}
+
+impl Fit for swfjc::thermodynamics::isotensional::SWFJC
+{
+    fn names(&self) -> Vec<&'static str>
+    {
+        vec!["link_length", "well_width"]
+    }
+    fn name(&self) -> &'static str
+    {
+        "SWFJC"
+    }
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn parameters(&self) -> Vec<f64>
+    {
+        vec![self.link_length, self.well_width]
+    }
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self
+    {
+        Self::init_with_constants(number_of_links, parameters[0], self.hinge_mass, parameters[1], self.constants)
+    }
+    fn observation(&self, extension: &f64, _force: &f64) -> f64
+    {
+        *extension
+    }
+    fn prediction(&self, _extension: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)
+    }
+}
+
+impl Fit for Compliant
+{
+    fn names(&self) -> Vec<&'static str>
+    {
+        vec!["link_length", "potential_stiffness"]
+    }
+    fn name(&self) -> &'static str
+    {
+        "Compliant"
+    }
+    fn number_of_links(&self) -> u8
+    {
+        self.model.number_of_links
+    }
+    fn parameters(&self) -> Vec<f64>
+    {
+        vec![self.model.link_length, self.potential_stiffness]
+    }
+    fn refit(&self, number_of_links: u8, parameters: &[f64]) -> Self
+    {
+        Compliant::init(fjc::thermodynamics::modified_canonical::FJC::init_with_constants(number_of_links, parameters[0], self.model.hinge_mass, self.model.constants), parameters[1])
+    }
+    fn observation(&self, _extension: &f64, force: &f64) -> f64
+    {
+        *force
+    }
+    fn prediction(&self, extension: &f64, _force: &f64, temperature: &f64) -> f64
+    {
+        self.model.force(extension, &self.potential_stiffness, temperature)
+    }
+}
+
+/// The probability that the magnitude of a variable with the Student's t-distribution is below the value, using the closed forms for integer degrees of freedom.
+fn student_t_probability(value: &f64, degrees_of_freedom: &usize) -> f64
+{
+    let angle = (value/(*degrees_of_freedom as f64).sqrt()).atan();
+    let cosine_squared = angle.cos().powi(2);
+    let mut term = 1.0;
+    if degrees_of_freedom % 2 == 1
+    {
+        let mut sum = if degrees_of_freedom > &1 {1.0} else {0.0};
+        for k in (3..*degrees_of_freedom).step_by(2)
+        {
+            term *= ((k - 1) as f64)/(k as f64)*cosine_squared;
+            sum += term;
+        }
+        2.0/PI*(angle + angle.sin()*angle.cos()*sum)
+    }
+    else
+    {
+        let mut sum = 1.0;
+        for k in (2..*degrees_of_freedom - 1).step_by(2)
+        {
+            term *= ((k - 1) as f64)/(k as f64)*cosine_squared;
+            sum += term;
+        }
+        angle.sin()*sum
+    }
+}
+
+/// The value below which the magnitude of a variable with the Student's t-distribution falls with the probability.
+fn student_t_quantile(probability: &f64, degrees_of_freedom: &usize) -> f64
+{
+    let function = |value: &f64| student_t_probability(value, degrees_of_freedom) - probability;
+    let (lower, upper) = bracket(function, &2.0, &0.0, &f64::INFINITY);
+    brent(function, &lower, &upper)
+}
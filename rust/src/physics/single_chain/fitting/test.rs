@@ -0,0 +1,160 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::fjc::thermodynamics::
+{
+    isotensional::FJC as FJCIsotensional,
+    modified_canonical::FJC as FJCModifiedCanonical
+};
+use crate::physics::single_chain::ideal::thermodynamics::isotensional::Ideal as IdealIsotensional;
+use crate::physics::single_chain::efjc::thermodynamics::isotensional::EFJC as EFJCIsotensional;
+use crate::physics::single_chain::swfjc::thermodynamics::isotensional::SWFJC as SWFJCIsotensional;
+fn isotensional_data<T: Fit>(model: &T, link_length: &f64, temperature: &f64, maximum_nondimensional_force: &f64) -> Data
+{
+    let force: Vec<f64> = (1..=32).map(|index| maximum_nondimensional_force*(index as f64)/32.0*BOLTZMANN_CONSTANT*temperature/link_length).collect();
+    let extension = force.iter().map(|force| model.prediction(&0.0, force, temperature)).collect();
+    Data::init(extension, force, vec![*temperature]).unwrap()
+}
+fn assert_recovered(estimate: &Estimate, name: &str, value: &f64)
+{
+    assert!((estimate.parameter(name).unwrap()/value - 1.0).abs() <= 1e-6, "{} = {} != {}", name, estimate.parameter(name).unwrap(), value);
+}
+mod data
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        assert_eq!(Data::init(vec![1.0, 2.0], vec![1.0, 2.0], vec![300.0]).unwrap().len(), 2);
+        assert_eq!(Data::init(vec![1.0, 2.0], vec![1.0], vec![300.0]), Err(Error::InvalidParameter{model: "Data", argument: "force", value: 1.0}));
+        assert_eq!(Data::init(vec![1.0, 2.0], vec![1.0, 2.0], vec![300.0, 300.0, 300.0]), Err(Error::InvalidParameter{model: "Data", argument: "temperature", value: 3.0}));
+        assert_eq!(Data::init(vec![1.0], vec![1.0], vec![-300.0]), Err(Error::InvalidParameter{model: "Data", argument: "temperature", value: -300.0}));
+    }
+    #[test]
+    fn too_few()
+    {
+        let parameters = Parameters::default();
+        let model = FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let data = Data::init(vec![1.0, 2.0], vec![1.0, 2.0], vec![parameters.temperature_reference]).unwrap();
+        assert_eq!(fit(&model, &data), Err(Error::InvalidParameter{model: "Data", argument: "extension", value: 2.0}));
+    }
+    #[test]
+    fn unidentifiable()
+    {
+        let parameters = Parameters::default();
+        let model = IdealIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let data = isotensional_data(&model, &parameters.link_length_reference, &parameters.temperature_reference, &1e0);
+        assert!(matches!(fit(&model, &data), Err(Error::InvalidParameter{model: "Ideal", argument: "number_of_links", ..})));
+    }
+}
+mod recovered
+{
+    use super::*;
+    #[test]
+    fn fjc()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_maximum;
+        let link_length = 1.1*parameters.link_length_reference;
+        let model = FJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference);
+        let data = isotensional_data(&model, &link_length, &parameters.temperature_reference, &1e1);
+        let estimate = fit(&FJCIsotensional::init(number_of_links - 4, parameters.link_length_reference, parameters.hinge_mass_reference), &data).unwrap();
+        assert_eq!(estimate.names, vec!["number_of_links", "link_length"]);
+        assert_recovered(&estimate, "number_of_links", &(number_of_links as f64));
+        assert_recovered(&estimate, "link_length", &link_length);
+        assert_eq!(estimate.model(&model).number_of_links, number_of_links);
+    }
+    #[test]
+    fn efjc()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_maximum;
+        let link_length = parameters.link_length_reference;
+        let link_stiffness = parameters.link_stiffness_reference;
+        let model = EFJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference, link_stiffness);
+        let data = isotensional_data(&model, &link_length, &parameters.temperature_reference, &5e1);
+        let estimate = fit(&EFJCIsotensional::init(number_of_links - 3, 0.9*link_length, parameters.hinge_mass_reference, 0.6*link_stiffness), &data).unwrap();
+        assert_recovered(&estimate, "number_of_links", &(number_of_links as f64));
+        assert_recovered(&estimate, "link_length", &link_length);
+        assert_recovered(&estimate, "link_stiffness", &link_stiffness);
+    }
+    #[test]
+    fn swfjc()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_maximum;
+        let link_length = parameters.link_length_reference;
+        let well_width = parameters.well_width_reference;
+        let model = SWFJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference, well_width);
+        let data = isotensional_data(&model, &link_length, &parameters.temperature_reference, &1e1);
+        let estimate = fit(&SWFJCIsotensional::init(number_of_links - 3, 0.9*link_length, parameters.hinge_mass_reference, 0.7*well_width), &data).unwrap();
+        assert_recovered(&estimate, "number_of_links", &(number_of_links as f64));
+        assert_recovered(&estimate, "link_length", &link_length);
+        assert_recovered(&estimate, "well_width", &well_width);
+    }
+    #[test]
+    fn compliant()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_minimum + 3;
+        let link_length = parameters.link_length_reference;
+        let temperature = parameters.temperature_reference;
+        let contour_length = (number_of_links as f64)*link_length;
+        let potential_stiffness = 1e1*BOLTZMANN_CONSTANT*temperature/contour_length.powi(2);
+        let model = Compliant::init(FJCModifiedCanonical::init(number_of_links, link_length, parameters.hinge_mass_reference), potential_stiffness);
+        let extension: Vec<f64> = (1..=16).map(|index| 1.2*contour_length*(index as f64)/16.0).collect();
+        let force = extension.iter().map(|extension| model.prediction(extension, &0.0, &temperature)).collect();
+        let data = Data::init(extension, force, vec![temperature]).unwrap();
+        let estimate = fit(&Compliant::init(FJCModifiedCanonical::init(number_of_links - 2, 0.9*link_length, parameters.hinge_mass_reference), 0.7*potential_stiffness), &data).unwrap();
+        assert_recovered(&estimate, "number_of_links", &(number_of_links as f64));
+        assert_recovered(&estimate, "link_length", &link_length);
+        assert_recovered(&estimate, "potential_stiffness", &potential_stiffness);
+    }
+}
+mod diagnostics
+{
+    use super::*;
+    #[test]
+    fn uncorrelated()
+    {
+        let parameters = Parameters::default();
+        let link_length = parameters.link_length_reference;
+        let model = FJCIsotensional::init(parameters.number_of_links_maximum, link_length, parameters.hinge_mass_reference);
+        let mut data = isotensional_data(&model, &link_length, &parameters.temperature_reference, &1e1);
+        let noise = 1e-2*link_length;
+        data.extension.iter_mut().enumerate().for_each(|(index, extension)| *extension += if index % 4 < 2 {noise} else {-noise});
+        let estimate = fit(&model, &data).unwrap();
+        assert_eq!(estimate.degrees_of_freedom(), data.len() - 2);
+        assert!(estimate.root_mean_square_residual() <= noise);
+        assert!(estimate.maximum_absolute_residual() <= 2.0*noise);
+        assert!((estimate.durbin_watson() - 2.0).abs() <= 0.5);
+        let intervals = estimate.confidence_intervals(&0.95);
+        let standard_errors = estimate.standard_errors();
+        for ((interval, standard_error), value) in intervals.iter().zip(standard_errors.iter()).zip([parameters.number_of_links_maximum as f64, link_length].iter())
+        {
+            assert!(standard_error > &0.0);
+            assert!(interval.0 < *value && interval.1 > *value);
+        }
+    }
+    #[test]
+    fn systematic()
+    {
+        let parameters = Parameters::default();
+        let link_length = parameters.link_length_reference;
+        let temperature = parameters.temperature_reference;
+        let model = EFJCIsotensional::init(parameters.number_of_links_maximum, link_length, parameters.hinge_mass_reference, parameters.link_stiffness_reference);
+        let data = isotensional_data(&model, &link_length, &temperature, &5e1);
+        let estimate = fit(&FJCIsotensional::init(parameters.number_of_links_maximum, link_length, parameters.hinge_mass_reference), &data).unwrap();
+        assert!(estimate.durbin_watson() < 1.0);
+    }
+    #[test]
+    fn student_t()
+    {
+        assert!((student_t_quantile(&0.95, &1) - 12.706204736174705).abs() <= 1e-10);
+        assert!((student_t_quantile(&0.95, &2) - 4.302652729749464).abs() <= 1e-10);
+        assert!((student_t_quantile(&0.95, &10) - 2.228138851986274).abs() <= 1e-10);
+        assert!((student_t_quantile(&0.99, &25) - 2.78743581367585).abs() <= 1e-10);
+        assert!((student_t_quantile(&0.95, &10000) - 1.960201239890626).abs() <= 1e-10);
+    }
+}
@@ -3,6 +3,17 @@ mod test;
 /// The thermodynamics of the FJC model.
 pub mod thermodynamics;
 
+/// The scattering functions of the FJC model.
+pub mod scattering;
+
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
 /// The structure of the FJC model.
 pub struct FJC
 {
@@ -15,8 +26,35 @@ pub struct FJC
     /// The number of links in the chain.
     pub number_of_links: u8,
 
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
     /// The thermodynamic functions of the model.
-    pub thermodynamics: thermodynamics::FJC
+    pub thermodynamics: thermodynamics::FJC,
+
+    /// The scattering functions of the model.
+    pub scattering: scattering::FJC
+}
+
+/// The parameters of the FJC model, from which it can be reconstructed with [`FJC::init`].
+///
+/// The continuous parameters are generic so that the isotensional functions can be differentiated with respect to them using dual numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameters<F = f64>
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants of the units of the parameters.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Constants,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: F,
+
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: F
 }
 
 /// The implemented functionality of the FJC model.
@@ -24,13 +62,39 @@ impl FJC
 {
     /// Initializes and returns an instance of the FJC model.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            thermodynamics: thermodynamics::FJC::init(number_of_links, link_length, hinge_mass),
+            constants,
+            thermodynamics: thermodynamics::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            scattering: scattering::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
+        }
+    }
+    /// Initializes and returns an instance of the FJC model, or an error if a parameter is invalid, where at least two links are required for the isometric ensemble.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<FJC, Error>
+    {
+        minimum_number_of_links("FJC", &number_of_links, 2)?;
+        positive("FJC", "link_length", &link_length)?;
+        positive("FJC", "hinge_mass", &hinge_mass)?;
+        Ok(FJC::init(number_of_links, link_length, hinge_mass))
+    }
+    /// Returns the parameters of the model.
+    pub fn parameters(&self) -> Parameters
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: self.link_length,
+            hinge_mass: self.hinge_mass
         }
     }
 }
@@ -0,0 +1,91 @@
+mod test;
+
+use crate::math::complex::Complex;
+use crate::physics::Constants;
+
+/// The structure of the scattering functions of the FJC model.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64
+}
+
+/// The implemented functionality of the scattering functions of the FJC model.
+impl FJC
+{
+    /// Initializes and returns an instance of the scattering functions of the FJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the scattering functions of the FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
+    {
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64
+        }
+    }
+    /// The exact form factor of the chain of hinges as a function of the wavenumber in units of 1/nm.
+    pub fn form_factor(&self, wavenumber: &f64) -> f64
+    {
+        self.nondimensional_form_factor(&(wavenumber*self.link_length))
+    }
+    /// The exact form factor of the chain of hinges as a function of the nondimensional wavenumber.
+    pub fn nondimensional_form_factor(&self, nondimensional_wavenumber: &f64) -> f64
+    {
+        let characteristic_function = if nondimensional_wavenumber.abs() < 1e-4
+        {
+            1.0 - nondimensional_wavenumber.powi(2)/6.0
+        }
+        else
+        {
+            nondimensional_wavenumber.sin()/nondimensional_wavenumber
+        };
+        self.sum(&Complex::from(characteristic_function))
+    }
+    /// The exact form factor of the chain of hinges under an applied force as a function of the wavenumber, the angle between the scattering vector and the force, the force, and the temperature.
+    pub fn anisotropic_form_factor(&self, wavenumber: &f64, angle: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_anisotropic_form_factor(&(wavenumber*self.link_length), angle, &(force*self.link_length/self.constants.boltzmann_constant/temperature))
+    }
+    /// The exact form factor of the chain of hinges under an applied force as a function of the nondimensional wavenumber, the angle between the scattering vector and the force, and the nondimensional force.
+    pub fn nondimensional_anisotropic_form_factor(&self, nondimensional_wavenumber: &f64, angle: &f64, nondimensional_force: &f64) -> f64
+    {
+        let argument = Complex::new(nondimensional_force.powi(2) - nondimensional_wavenumber.powi(2), 2.0*nondimensional_force*nondimensional_wavenumber*angle.cos()).sqrt();
+        self.sum(&(ln_sinhc(&argument) - ln_sinhc(&Complex::from(nondimensional_force.abs()))).exp())
+    }
+    fn sum(&self, characteristic_function: &Complex) -> f64
+    {
+        let number_of_hinges = self.number_of_links_f64 + 1.0;
+        (number_of_hinges + 2.0*(1..=self.number_of_links).map(|k| (number_of_hinges - k as f64)*characteristic_function.powi(k as i32).re).sum::<f64>())/number_of_hinges.powi(2)
+    }
+}
+
+fn ln_sinhc(z: &Complex) -> Complex
+{
+    if z.norm() < 5e-1
+    {
+        let w = *z**z;
+        (Complex::from(1.0) + w*(Complex::from(1.0/6.0) + w*(Complex::from(1.0/120.0) + w*(Complex::from(1.0/5040.0) + w*(Complex::from(1.0/362880.0) + w*Complex::from(1.0/39916800.0)))))).ln()
+    }
+    else
+    {
+        *z + (Complex::from(1.0) - (Complex::from(-2.0)**z).exp()).ln() - Complex::from(2.0_f64.ln()) - z.ln()
+    }
+}
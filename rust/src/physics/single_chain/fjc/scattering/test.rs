@@ -0,0 +1,257 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters as DefaultParameters;
+use std::f64::consts::PI;
+pub struct Parameters
+{
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+    pub number_of_loops: u32,
+    pub hinge_mass_reference: f64,
+    pub hinge_mass_scale: f64,
+    pub link_length_reference: f64,
+    pub link_length_scale: f64,
+    pub number_of_links_minimum: u8,
+    pub number_of_links_maximum: u8,
+    pub nondimensional_force_reference: f64,
+    pub nondimensional_force_scale: f64,
+    pub nondimensional_wavenumber_reference: f64,
+    pub nondimensional_wavenumber_scale: f64,
+    pub nondimensional_wavenumber_small: f64,
+    pub temperature_reference: f64,
+    pub temperature_scale: f64,
+}
+impl Default for Parameters
+{
+    fn default() -> Self
+    {
+        Self
+        {
+            number_of_loops: 888,
+            abs_tol: DefaultParameters::default().abs_tol,
+            rel_tol: DefaultParameters::default().rel_tol,
+            hinge_mass_reference: DefaultParameters::default().hinge_mass_reference,
+            hinge_mass_scale: DefaultParameters::default().hinge_mass_scale,
+            link_length_reference: DefaultParameters::default().link_length_reference,
+            link_length_scale: DefaultParameters::default().link_length_scale,
+            number_of_links_minimum: DefaultParameters::default().number_of_links_minimum,
+            number_of_links_maximum: DefaultParameters::default().number_of_links_maximum,
+            nondimensional_force_reference: 5e0,
+            nondimensional_force_scale: 1e1,
+            nondimensional_wavenumber_reference: 5e0,
+            nondimensional_wavenumber_scale: 1e1,
+            nondimensional_wavenumber_small: 1e-1,
+            temperature_reference: DefaultParameters::default().temperature_reference,
+            temperature_scale: DefaultParameters::default().temperature_scale,
+        }
+    }
+}
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).number_of_links);
+        }
+    }
+    #[test]
+    fn link_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            assert_eq!(link_length, FJC::init(parameters.number_of_links_minimum, link_length, parameters.hinge_mass_reference).link_length);
+        }
+    }
+    #[test]
+    fn hinge_mass()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            assert_eq!(hinge_mass, FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, hinge_mass).hinge_mass);
+        }
+    }
+}
+mod nondimensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn form_factor()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_form_factor = model.nondimensional_form_factor(&nondimensional_wavenumber);
+            let form_factor = model.form_factor(&(nondimensional_wavenumber/link_length));
+            let residual_abs = form_factor - nondimensional_form_factor;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn anisotropic_form_factor()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let angle = PI*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_anisotropic_form_factor = model.nondimensional_anisotropic_form_factor(&nondimensional_wavenumber, &angle, &nondimensional_force);
+            let anisotropic_form_factor = model.anisotropic_form_factor(&(nondimensional_wavenumber/link_length), &angle, &(nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length), &temperature);
+            let residual_abs = anisotropic_form_factor - nondimensional_anisotropic_form_factor;
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+        }
+    }
+}
+mod zero
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn form_factor()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            assert_eq!(model.nondimensional_form_factor(&0.0), 1.0);
+        }
+    }
+    #[test]
+    fn anisotropic_form_factor()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let angle = PI*rng.gen::<f64>();
+            assert_eq!(model.nondimensional_anisotropic_form_factor(&0.0, &angle, &nondimensional_force), 1.0);
+        }
+    }
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let angle = PI*rng.gen::<f64>();
+            let residual_abs = model.nondimensional_anisotropic_form_factor(&nondimensional_wavenumber, &angle, &0.0) - model.nondimensional_form_factor(&nondimensional_wavenumber);
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+        }
+    }
+}
+mod limit
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::test::integrate;
+    #[test]
+    fn guinier()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_small*rng.gen::<f64>();
+            let nondimensional_radius_of_gyration_squared = (number_of_links as f64)*((number_of_links as f64) + 2.0)/((number_of_links as f64) + 1.0)/6.0;
+            let residual_abs = model.nondimensional_form_factor(&nondimensional_wavenumber) - (1.0 - nondimensional_wavenumber.powi(2)*nondimensional_radius_of_gyration_squared/3.0);
+            assert!(residual_abs.abs() <= nondimensional_wavenumber.powi(4)*nondimensional_radius_of_gyration_squared.powi(2) + parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn single_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let residual_abs = FJC::init(1, parameters.link_length_reference, parameters.hinge_mass_reference).nondimensional_form_factor(&nondimensional_wavenumber) - 0.5*(1.0 + nondimensional_wavenumber.sin()/nondimensional_wavenumber);
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn orientation_average()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..8
+        {
+            let model = FJC::init(1, parameters.link_length_reference, parameters.hinge_mass_reference);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let integrand = |cosine: f64| 0.5*model.nondimensional_anisotropic_form_factor(&nondimensional_wavenumber, &cosine.acos(), &nondimensional_force);
+            let residual_abs = integrate(integrand, &-1.0, &1.0, &10000) - model.nondimensional_form_factor(&nondimensional_wavenumber);
+            assert!(residual_abs.abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn symmetry()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_wavenumber = parameters.nondimensional_wavenumber_reference + parameters.nondimensional_wavenumber_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let angle = PI*rng.gen::<f64>();
+            let residual_abs = model.nondimensional_anisotropic_form_factor(&nondimensional_wavenumber, &angle, &nondimensional_force) - model.nondimensional_anisotropic_form_factor(&nondimensional_wavenumber, &(PI - angle), &nondimensional_force);
+            assert!(residual_abs.abs() <= parameters.abs_tol);
+        }
+    }
+}
@@ -58,3 +58,120 @@ mod base
         }
     }
 }
+mod error
+{
+    use super::*;
+    use crate::physics::BOLTZMANN_CONSTANT;
+    use crate::physics::single_chain::
+    {
+        Isometric,
+        Isotensional
+    };
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        for number_of_links in [0, 1]
+        {
+            assert_eq!(FJC::try_init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "FJC", argument: "number_of_links", value: number_of_links as f64}));
+        }
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, -parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "FJC", argument: "link_length", value: -parameters.link_length_reference}));
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, 0.0).err(), Some(Error::InvalidParameter{model: "FJC", argument: "hinge_mass", value: 0.0}));
+    }
+    #[test]
+    fn isometric()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).unwrap();
+        let contour_length = (parameters.number_of_links_minimum as f64)*parameters.link_length_reference;
+        let end_to_end_length = 0.5*contour_length;
+        assert_eq!(model.thermodynamics.isometric.try_force(&end_to_end_length, &parameters.temperature_reference), Ok(model.thermodynamics.isometric.force(&end_to_end_length, &parameters.temperature_reference)));
+        for end_to_end_length in [-end_to_end_length, contour_length, 2.0*contour_length]
+        {
+            assert!(matches!(model.thermodynamics.isometric.try_helmholtz_free_energy(&end_to_end_length, &parameters.temperature_reference), Err(Error::OutOfDomain{model: "FJC", argument: "end_to_end_length", ..})));
+        }
+        for temperature in [0.0, -parameters.temperature_reference, f64::NAN]
+        {
+            assert!(matches!(model.thermodynamics.isometric.try_relative_helmholtz_free_energy(&end_to_end_length, &temperature), Err(Error::OutOfDomain{model: "FJC", argument: "temperature", ..})));
+        }
+    }
+    #[test]
+    fn isotensional()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).unwrap();
+        let force = parameters.nondimensional_force_reference*BOLTZMANN_CONSTANT*parameters.temperature_reference/parameters.link_length_reference;
+        assert_eq!(model.thermodynamics.isotensional.try_end_to_end_length(&force, &parameters.temperature_reference), Ok(model.thermodynamics.isotensional.end_to_end_length(&force, &parameters.temperature_reference)));
+        assert!(matches!(model.thermodynamics.isotensional.try_gibbs_free_energy(&f64::NAN, &parameters.temperature_reference), Err(Error::OutOfDomain{model: "FJC", argument: "force", ..})));
+        assert!(matches!(model.thermodynamics.isotensional.try_relative_gibbs_free_energy(&force, &0.0), Err(Error::OutOfDomain{model: "FJC", argument: "temperature", ..})));
+    }
+    #[test]
+    fn large_number_of_links()
+    {
+        let parameters = Parameters::default();
+        for number_of_links in [35, u8::MAX]
+        {
+            let model = FJC::try_init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).unwrap();
+            let contour_length = (number_of_links as f64)*parameters.link_length_reference;
+            for end_to_end_length in [0.0, 0.01*contour_length, 0.5*contour_length, 0.9*contour_length]
+            {
+                for result in [model.thermodynamics.isometric.try_force(&end_to_end_length, &parameters.temperature_reference), model.thermodynamics.isometric.try_helmholtz_free_energy(&end_to_end_length, &parameters.temperature_reference), model.thermodynamics.isometric.try_relative_helmholtz_free_energy(&end_to_end_length, &parameters.temperature_reference)]
+                {
+                    assert!(result.is_ok() || matches!(result, Err(Error::NonConvergence{..}) | Err(Error::Overflow{..})));
+                }
+            }
+        }
+        let model = FJC::try_init(35, parameters.link_length_reference, parameters.hinge_mass_reference).unwrap();
+        let end_to_end_length = 0.5*35.0*parameters.link_length_reference;
+        let force = model.thermodynamics.isometric.try_force(&end_to_end_length, &parameters.temperature_reference).unwrap();
+        assert!((force/model.thermodynamics.isometric.legendre.force(&end_to_end_length, &parameters.temperature_reference) - 1.0).abs() <= 5e-2);
+    }
+}
+mod parameters
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).parameters();
+        assert_eq!(FJC::init(model_parameters.number_of_links, model_parameters.link_length, model_parameters.hinge_mass).parameters(), model_parameters);
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).parameters();
+        let json = serde_json::to_string(&model_parameters).unwrap();
+        assert_eq!(serde_json::from_str::<super::super::Parameters>(&json).unwrap(), model_parameters);
+    }
+}
+mod constants
+{
+    use super::*;
+    use crate::physics::Constants;
+    use crate::physics::single_chain::test::check_constants;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let constants = Constants::molecular();
+        let model = FJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, constants);
+        assert_eq!(model.constants, constants);
+        assert_eq!(model.thermodynamics.isotensional.constants, constants);
+        assert_eq!(model.parameters().constants, constants);
+        assert_eq!(FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).constants, Constants::molar());
+    }
+    #[test]
+    fn conventions()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let molar = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isotensional;
+        let molecular = FJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Constants::molecular()).thermodynamics.isotensional;
+        let reduced = FJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Constants::reduced(&temperature)).thermodynamics.isotensional;
+        check_constants(&molar, &molecular, &reduced, &temperature, &parameters.rel_tol);
+    }
+}
@@ -4,16 +4,22 @@
 mod test;
 
 use std::f64::consts::PI;
-use crate::physics::
+use crate::math::roots::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    bracket,
+    brent
 };
+use crate::physics::Constants;
 use crate::physics::single_chain::
 {
     ONE,
-    ZERO,
-    POINTS
+    POINTS,
+    Isometric
+};
+use crate::math::special::
+{
+    ln_sinhc,
+    InverseLangevin
 };
 
 /// The structure of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation.
@@ -28,6 +34,12 @@ pub struct FJC
     /// The number of links in the chain.
     pub number_of_links: u8,
 
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The exact inverse Langevin function or the approximant used to relate the force and the end-to-end length.
+    pub inverse_langevin: InverseLangevin,
+
     number_of_links_f64: f64,
 
     contour_length: f64,
@@ -40,23 +52,42 @@ impl FJC
 {
     /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
+    {
+        FJC::init_with_inverse_langevin_and_constants(number_of_links, link_length, hinge_mass, InverseLangevin::Rational, constants)
+    }
+    /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation, using the given exact inverse Langevin function or approximant.
+    pub fn init_with_inverse_langevin(number_of_links: u8, link_length: f64, hinge_mass: f64, inverse_langevin: InverseLangevin) -> FJC
+    {
+        FJC::init_with_inverse_langevin_and_constants(number_of_links, link_length, hinge_mass, inverse_langevin, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble approximated using a Legendre transformation, using the given exact inverse Langevin function or approximant and the physical constants.
+    pub fn init_with_inverse_langevin_and_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, inverse_langevin: InverseLangevin, constants: Constants) -> FJC
     {
         let temporary_model = FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
+            inverse_langevin,
             number_of_links_f64: number_of_links as f64,
             contour_length: (number_of_links as f64)*link_length,
             normalization_nondimensional_equilibrium_distribution: 1.0
         };
-        let dx = (ONE - ZERO)/(POINTS as f64);
-        let normalization = (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| temporary_model.nondimensional_equilibrium_radial_distribution(&(ZERO + (0.5 + *index as f64)*dx))).sum::<f64>()*dx;
+        let dx = ONE/(POINTS as f64);
+        let normalization = (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| temporary_model.nondimensional_equilibrium_radial_distribution(&((0.5 + *index as f64)*dx))).sum::<f64>()*dx;
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
+            inverse_langevin,
             number_of_links_f64: number_of_links as f64,
             contour_length: (number_of_links as f64)*link_length,
             normalization_nondimensional_equilibrium_distribution: normalization
@@ -65,13 +96,24 @@ impl FJC
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_end_to_end_length_per_link = end_to_end_length/self.contour_length;
-        BOLTZMANN_CONSTANT*temperature/self.link_length*(2.14234*nondimensional_end_to_end_length_per_link.powi(3) - 4.22785*nondimensional_end_to_end_length_per_link.powi(2) + 3.0*nondimensional_end_to_end_length_per_link)/(1.0 - nondimensional_end_to_end_length_per_link)/(0.71716*nondimensional_end_to_end_length_per_link.powi(3) - 0.41103*nondimensional_end_to_end_length_per_link.powi(2) - 0.39165*nondimensional_end_to_end_length_per_link + 1.0)
+        self.nondimensional_force(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
     }
     /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        (2.14234*nondimensional_end_to_end_length_per_link.powi(3) - 4.22785*nondimensional_end_to_end_length_per_link.powi(2) + 3.0*nondimensional_end_to_end_length_per_link)/(1.0 - nondimensional_end_to_end_length_per_link)/(0.71716*nondimensional_end_to_end_length_per_link.powi(3) - 0.41103*nondimensional_end_to_end_length_per_link.powi(2) - 0.39165*nondimensional_end_to_end_length_per_link + 1.0)
+        self.inverse_langevin.evaluate(nondimensional_end_to_end_length_per_link)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        let function = |nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link) - nondimensional_force;
+        let (lower, upper) = bracket(function, &(nondimensional_force/(3.0 + nondimensional_force)), &0.0, &1.0);
+        brent(function, &lower, &upper)
     }
     /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
@@ -81,7 +123,7 @@ impl FJC
     /// The helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
@@ -91,17 +133,17 @@ impl FJC
     /// The relative helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature
     }
     /// The nondimensional helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link) - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link) - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     /// The nondimensional helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link) - (self.number_of_links_f64 - 1.0)/self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_end_to_end_length_per_link) - (self.number_of_links_f64 - 1.0)/self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     /// The nondimensional relative helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
@@ -112,7 +154,7 @@ impl FJC
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         let nondimensional_force = self.nondimensional_force(nondimensional_end_to_end_length_per_link);
-        nondimensional_force**nondimensional_end_to_end_length_per_link - (nondimensional_force.sinh()/nondimensional_force).ln()
+        nondimensional_force**nondimensional_end_to_end_length_per_link - ln_sinhc(&nondimensional_force)
     }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
@@ -123,7 +165,7 @@ impl FJC
     pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         let nondimensional_force = self.nondimensional_force(nondimensional_end_to_end_length_per_link);
-        (nondimensional_force.sinh()/nondimensional_force*(-nondimensional_force**nondimensional_end_to_end_length_per_link).exp()).powi(self.number_of_links as i32)/self.normalization_nondimensional_equilibrium_distribution
+        (self.number_of_links_f64*(ln_sinhc(&nondimensional_force) - nondimensional_force*nondimensional_end_to_end_length_per_link)).exp()/self.normalization_nondimensional_equilibrium_distribution
     }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
@@ -138,12 +180,12 @@ impl FJC
     /// The gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy(&(end_to_end_length/self.contour_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_gibbs_free_energy(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     /// The gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_gibbs_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     /// The relative gibbs free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
@@ -153,17 +195,12 @@ impl FJC
     /// The relative gibbs free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_gibbs_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_gibbs_free_energy_per_link(&(end_to_end_length/self.contour_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature
     }
     /// The nondimensional gibbs free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        let n = self.number_of_links as u128;
-        let p: i32 = (self.number_of_links - 2).into();
-        let m = -*nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-        let k = (self.number_of_links_f64*m).ceil() as u128;
-        let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-        -(nondimensional_end_to_end_length_per_link*self.number_of_links_f64)*self.nondimensional_force(nondimensional_end_to_end_length_per_link) - (0.125/PI/nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum/self.contour_length.powi(3)).ln() - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -(nondimensional_end_to_end_length_per_link*self.number_of_links_f64)*self.nondimensional_force(nondimensional_end_to_end_length_per_link) - (super::super::nondimensional_equilibrium_distribution(&self.number_of_links, nondimensional_end_to_end_length_per_link)/self.contour_length.powi(3)).ln() - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     /// The nondimensional gibbs free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
@@ -173,7 +210,7 @@ impl FJC
     /// The nondimensional relative gibbs free energy as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, &300.0) - self.nondimensional_gibbs_free_energy(&ZERO, &300.0)
+        self.nondimensional_gibbs_free_energy(nondimensional_end_to_end_length_per_link, &300.0) - self.nondimensional_gibbs_free_energy(&0.0, &300.0)
     }
     /// The nondimensional relative gibbs free energy per link as a function of the applied nondimensional end-to-end length per link.
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
@@ -181,3 +218,31 @@ impl FJC
         self.nondimensional_relative_gibbs_free_energy(nondimensional_end_to_end_length_per_link)/self.number_of_links_f64
     }
 }
+
+impl Isometric for FJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.contour_length
+    }
+    fn name(&self) -> &'static str
+    {
+        "FJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::fjc::thermodynamics::isometric::test::Parameters;
 mod base
 {
@@ -75,7 +76,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |end_to_end_length: f64| 4.0*PI*end_to_end_length.powi(2)*model.equilibrium_distribution(&end_to_end_length);
-            let integral = integrate(integrand, &ZERO, &(ONE*model.contour_length), &POINTS);
+            let integral = integrate(integrand, &0.0, &(ONE*model.contour_length), &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -91,7 +92,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |nondimensional_end_to_end_length_per_link_per_link: f64| 4.0*PI*nondimensional_end_to_end_length_per_link_per_link.powi(2)*model.nondimensional_equilibrium_distribution(&nondimensional_end_to_end_length_per_link_per_link);
-            let integral = integrate(integrand, &ZERO, &ONE, &POINTS);
+            let integral = integrate(integrand, &0.0, &ONE, &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -107,7 +108,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |end_to_end_length: f64| model.equilibrium_radial_distribution(&end_to_end_length);
-            let integral = integrate(integrand, &ZERO, &(ONE*model.contour_length), &POINTS);
+            let integral = integrate(integrand, &0.0, &(ONE*model.contour_length), &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -123,7 +124,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |nondimensional_end_to_end_length_per_link_per_link: f64| model.nondimensional_equilibrium_radial_distribution(&nondimensional_end_to_end_length_per_link_per_link);
-            let integral = integrate(integrand, &ZERO, &ONE, &POINTS);
+            let integral = integrate(integrand, &0.0, &ONE, &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -510,7 +511,6 @@ mod relative
 {
     use super::*;
     use rand::Rng;
-    use crate::physics::single_chain::ZERO;
     #[test]
     fn helmholtz_free_energy()
     {
@@ -526,7 +526,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let helmholtz_free_energy = model.helmholtz_free_energy(&end_to_end_length, &temperature);
-            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&ZERO, &temperature);
+            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&0.0, &temperature);
             let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&end_to_end_length, &temperature);
             let residual_abs = &helmholtz_free_energy - &helmholtz_free_energy_0 - &relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&helmholtz_free_energy_0;
@@ -548,7 +548,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let helmholtz_free_energy_per_link = model.helmholtz_free_energy_per_link(&end_to_end_length, &temperature);
-            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&0.0, &temperature);
             let relative_helmholtz_free_energy_per_link = model.relative_helmholtz_free_energy_per_link(&end_to_end_length, &temperature);
             let residual_abs = &helmholtz_free_energy_per_link - &helmholtz_free_energy_per_link_0 - &relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&helmholtz_free_energy_per_link_0;
@@ -569,7 +569,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy = model.nondimensional_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy = model.nondimensional_relative_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_helmholtz_free_energy - &nondimensional_helmholtz_free_energy_0 - &nondimensional_relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_0;
@@ -590,7 +590,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy_per_link = model.nondimensional_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy_per_link = model.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_helmholtz_free_energy_per_link - &nondimensional_helmholtz_free_energy_per_link_0 - &nondimensional_relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_per_link_0;
@@ -612,7 +612,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let gibbs_free_energy = model.gibbs_free_energy(&end_to_end_length, &temperature);
-            let gibbs_free_energy_0 = model.gibbs_free_energy(&ZERO, &temperature);
+            let gibbs_free_energy_0 = model.gibbs_free_energy(&0.0, &temperature);
             let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&end_to_end_length, &temperature);
             let residual_abs = &gibbs_free_energy - &gibbs_free_energy_0 - &relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&gibbs_free_energy_0;
@@ -634,7 +634,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let gibbs_free_energy_per_link = model.gibbs_free_energy_per_link(&end_to_end_length, &temperature);
-            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&ZERO, &temperature);
+            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&0.0, &temperature);
             let relative_gibbs_free_energy_per_link = model.relative_gibbs_free_energy_per_link(&end_to_end_length, &temperature);
             let residual_abs = &gibbs_free_energy_per_link - &gibbs_free_energy_per_link_0 - &relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&gibbs_free_energy_per_link_0;
@@ -655,7 +655,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy = model.nondimensional_gibbs_free_energy(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_gibbs_free_energy - &nondimensional_gibbs_free_energy_0 - &nondimensional_relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_0;
@@ -676,7 +676,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy_per_link = model.nondimensional_gibbs_free_energy_per_link(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy_per_link = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_gibbs_free_energy_per_link - &nondimensional_gibbs_free_energy_per_link_0 - &nondimensional_relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_per_link_0;
@@ -688,7 +688,6 @@ mod zero
 {
     use super::*;
     use rand::Rng;
-    use crate::physics::single_chain::ZERO;
     #[test]
     fn force()
     {
@@ -701,8 +700,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let force_0 = model.force(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(force_0.abs() <= 3.0*BOLTZMANN_CONSTANT*temperature/link_length*ZERO);
+            let force_0 = model.force(&0.0, &temperature);
+            assert_eq!(force_0, 0.0);
         }
     }
     #[test]
@@ -716,8 +715,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_force_0 = model.nondimensional_force(&ZERO);
-            assert!(nondimensional_force_0.abs() <= 3.0*ZERO);
+            let nondimensional_force_0 = model.nondimensional_force(&0.0);
+            assert_eq!(nondimensional_force_0, 0.0);
         }
     }
     #[test]
@@ -732,8 +731,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -748,8 +747,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -763,8 +762,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&ZERO);
-            assert!(nondimensional_relative_helmholtz_free_energy_0.abs() <= (number_of_links as f64)*ZERO);
+            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&0.0);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -778,8 +777,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&ZERO);
-            assert!(nondimensional_relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&0.0);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -793,8 +792,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let equilibrium_radial_distribution_0 = model.equilibrium_radial_distribution(&(ZERO*(number_of_links as f64)*link_length));
-            assert!(equilibrium_radial_distribution_0.abs() <= ZERO);
+            let equilibrium_radial_distribution_0 = model.equilibrium_radial_distribution(&0.0);
+            assert_eq!(equilibrium_radial_distribution_0, 0.0);
         }
     }
     #[test]
@@ -808,8 +807,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_equilibrium_radial_distribution_0 = model.nondimensional_equilibrium_radial_distribution(&ZERO);
-            assert!(nondimensional_equilibrium_radial_distribution_0.abs() <= ZERO);
+            let nondimensional_equilibrium_radial_distribution_0 = model.nondimensional_equilibrium_radial_distribution(&0.0);
+            assert_eq!(nondimensional_equilibrium_radial_distribution_0, 0.0);
         }
     }
     #[test]
@@ -824,8 +823,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_gibbs_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -840,8 +839,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_gibbs_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -855,8 +854,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&ZERO);
-            assert!(nondimensional_relative_gibbs_free_energy_0.abs() <= (number_of_links as f64)*ZERO);
+            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&0.0);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -870,8 +869,133 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&ZERO);
-            assert!(nondimensional_relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&0.0);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_per_link_0, 0.0);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force)/nondimensional_end_to_end_length_per_link - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((model.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+
+mod exact
+{
+    use super::*;
+    use rand::Rng;
+    use crate::math::special::langevin;
+    use crate::physics::single_chain::test::integrate;
+    #[test]
+    fn inverse_langevin()
+    {
+        let parameters = Parameters::default();
+        assert_eq!(InverseLangevin::Rational, FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).inverse_langevin);
+        assert_eq!(InverseLangevin::Exact, FJC::init_with_inverse_langevin(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, InverseLangevin::Exact).inverse_langevin);
+        assert_eq!(Constants::molar(), FJC::init_with_inverse_langevin(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, InverseLangevin::Exact).constants);
+        assert_eq!(InverseLangevin::Rational, FJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Constants::molecular()).inverse_langevin);
+    }
+    #[test]
+    fn inverse_langevin_and_constants()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let constants = Constants::molecular();
+            let model = FJC::init_with_inverse_langevin_and_constants(number_of_links, link_length, hinge_mass, InverseLangevin::Exact, constants);
+            assert_eq!(InverseLangevin::Exact, model.inverse_langevin);
+            assert_eq!(constants, model.constants);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((force/(InverseLangevin::Exact.evaluate(&nondimensional_end_to_end_length_per_link)*constants.boltzmann_constant*temperature/link_length) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init_with_inverse_langevin(number_of_links, link_length, hinge_mass, InverseLangevin::Exact);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            assert!((langevin(&nondimensional_force)/nondimensional_end_to_end_length_per_link - 1.0).abs() <= 1e-12);
+        }
+    }
+    #[test]
+    fn nondimensional_relative_helmholtz_free_energy_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init_with_inverse_langevin(number_of_links, link_length, hinge_mass, InverseLangevin::Exact);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let step = 1e-6;
+            let derivative = (model.nondimensional_relative_helmholtz_free_energy_per_link(&(nondimensional_end_to_end_length_per_link + step)) - model.nondimensional_relative_helmholtz_free_energy_per_link(&(nondimensional_end_to_end_length_per_link - step)))/(2.0*step);
+            assert!((derivative/model.nondimensional_force(&nondimensional_end_to_end_length_per_link) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn normalization()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init_with_inverse_langevin(number_of_links, link_length, hinge_mass, InverseLangevin::Exact);
+            let integral = integrate(|nondimensional_end_to_end_length_per_link: f64| model.nondimensional_equilibrium_radial_distribution(&nondimensional_end_to_end_length_per_link), &0.0, &ONE, &POINTS);
+            assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
 }
@@ -4,12 +4,24 @@ mod test;
 pub mod legendre;
 
 use std::f64::consts::PI;
-use crate::physics::
+use crate::math::float::Float;
+use crate::math::roots::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    bracket,
+    brent
+};
+use crate::physics::Constants;
+use crate::physics::single_chain::
+{
+    Isometric,
+    ln_hinge_partition_function
+};
+use crate::physics::single_chain::fjc::Parameters;
+use super::
+{
+    nondimensional_equilibrium_distribution,
+    nondimensional_equilibrium_distribution_log_derivative
 };
-use crate::physics::single_chain::ZERO;
 
 /// The structure of the thermodynamics of the FJC model in the isometric ensemble.
 pub struct FJC
@@ -23,6 +35,9 @@ pub struct FJC
     /// The number of links in the chain.
     pub number_of_links: u8,
 
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
     /// The thermodynamic functions of the model in the isometric ensemble approximated using a Legendre transformation.
     pub legendre: legendre::FJC,
 
@@ -36,13 +51,19 @@ impl FJC
 {
     /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the FJC model in the isometric ensemble, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            legendre: legendre::FJC::init(number_of_links, link_length, hinge_mass),
+            constants,
+            legendre: legendre::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
             number_of_links_f64: number_of_links as f64,
             contour_length: (number_of_links as f64)*link_length
         }
@@ -50,18 +71,24 @@ impl FJC
     /// The expected force as a function of the applied end-to-end length and temperature.
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_force(&(end_to_end_length/self.contour_length))*BOLTZMANN_CONSTANT*temperature/self.link_length
+        self.nondimensional_force(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
     }
-    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
-    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link, in any floating-point precision.
+    pub fn nondimensional_force<F: Float>(&self, nondimensional_end_to_end_length_per_link: &F) -> F
     {
-        let n = self.number_of_links as u128;
-        let p: i32 = (self.number_of_links - 2).into();
-        let m = -*nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-        let k = (self.number_of_links_f64*m).ceil() as u128;
-        let sum_0: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-        let sum_1: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p - 1)).sum();
-        (1.0/nondimensional_end_to_end_length_per_link + (0.5*self.number_of_links_f64 - 1.0)*sum_1/sum_0)/self.number_of_links_f64
+        -nondimensional_equilibrium_distribution_log_derivative(&self.number_of_links, nondimensional_end_to_end_length_per_link)/F::from_f64(self.number_of_links_f64)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        let function = |nondimensional_end_to_end_length_per_link: &f64| self.nondimensional_force(nondimensional_end_to_end_length_per_link) - nondimensional_force;
+        let (lower, upper) = bracket(function, &(nondimensional_force/(3.0 + nondimensional_force)), &0.0, &1.0);
+        brent(function, &lower, &upper)
     }
     /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
@@ -71,7 +98,7 @@ impl FJC
     /// The helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
@@ -81,56 +108,97 @@ impl FJC
     /// The relative helmholtz free energy per link as a function of the applied end-to-end length and temperature.
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature
     }
     /// The nondimensional helmholtz free energy as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        -(self.equilibrium_distribution(&(nondimensional_end_to_end_length_per_link*self.contour_length))).ln() - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -(self.equilibrium_distribution(&(nondimensional_end_to_end_length_per_link*self.contour_length))).ln() - (self.number_of_links_f64 - 1.0)*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     /// The nondimensional helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
         self.nondimensional_helmholtz_free_energy(nondimensional_end_to_end_length_per_link, temperature)/self.number_of_links_f64
     }
-    /// The nondimensional relative helmholtz free energy as a function of the applied nondimensional end-to-end length per link.
-    pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    /// The nondimensional relative helmholtz free energy as a function of the applied nondimensional end-to-end length per link, in any floating-point precision.
+    pub fn nondimensional_relative_helmholtz_free_energy<F: Float>(&self, nondimensional_end_to_end_length_per_link: &F) -> F
     {
-        (self.nondimensional_equilibrium_distribution(&ZERO)/self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)).ln()
+        (self.nondimensional_equilibrium_distribution(&F::from_f64(0.0))/self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)).ln()
     }
-    /// The nondimensional relative helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
-    pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    /// The nondimensional relative helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link, in any floating-point precision.
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link<F: Float>(&self, nondimensional_end_to_end_length_per_link: &F) -> F
     {
-        self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link)/self.number_of_links_f64
+        self.nondimensional_relative_helmholtz_free_energy(nondimensional_end_to_end_length_per_link)/F::from_f64(self.number_of_links_f64)
     }
     /// The equilibrium probability density of end-to-end vectors as a function of the end-to-end length.
     pub fn equilibrium_distribution(&self, end_to_end_length: &f64) -> f64
     {
         self.nondimensional_equilibrium_distribution(&(end_to_end_length/self.contour_length))/self.contour_length.powi(3)
     }
-    /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link.
-    pub fn nondimensional_equilibrium_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    /// The equilibrium probability density of nondimensional end-to-end vectors per link as a function of the nondimensional end-to-end length per link, in any floating-point precision.
+    pub fn nondimensional_equilibrium_distribution<F: Float>(&self, nondimensional_end_to_end_length_per_link: &F) -> F
     {
-        let n = self.number_of_links as u128;
-        let p: i32 = (self.number_of_links - 2).into();
-        let m = -*nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-        let k = (self.number_of_links_f64*m).ceil() as u128;
-        let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-        0.125/PI/nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum
+        nondimensional_equilibrium_distribution(&self.number_of_links, nondimensional_end_to_end_length_per_link)
     }
     /// The equilibrium probability density of end-to-end lengths as a function of the end-to-end length.
     pub fn equilibrium_radial_distribution(&self, end_to_end_length: &f64) -> f64
     {
         self.nondimensional_equilibrium_radial_distribution(&(end_to_end_length/self.contour_length))/self.contour_length
     }
-    /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link.
-    pub fn nondimensional_equilibrium_radial_distribution(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    /// The equilibrium probability density of nondimensional end-to-end lengths per link as a function of the nondimensional end-to-end length per link, in any floating-point precision.
+    pub fn nondimensional_equilibrium_radial_distribution<F: Float>(&self, nondimensional_end_to_end_length_per_link: &F) -> F
+    {
+        F::from_f64(4.0*PI)*nondimensional_end_to_end_length_per_link.powi(2)*self.nondimensional_equilibrium_distribution(nondimensional_end_to_end_length_per_link)
+    }
+}
+
+impl Isometric for FJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.contour_length
+    }
+    fn name(&self) -> &'static str
+    {
+        "FJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+
+/// The generic functionality of the thermodynamics of the FJC model in the isometric ensemble, with the parameters, end-to-end length, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        let number_of_links = F::from_f64(self.number_of_links as f64);
+        -nondimensional_equilibrium_distribution_log_derivative(&self.number_of_links, &(*end_to_end_length/number_of_links/self.link_length))/number_of_links*F::from_f64(self.constants.boltzmann_constant)**temperature/self.link_length
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        let contour_length = F::from_f64(self.number_of_links as f64)*self.link_length;
+        -F::from_f64(self.constants.boltzmann_constant)**temperature*((nondimensional_equilibrium_distribution(&self.number_of_links, &(*end_to_end_length/contour_length))/contour_length.powi(3)).ln() + F::from_f64(self.number_of_links as f64 - 1.0)*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants))
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
     {
-        let n = self.number_of_links as u128;
-        let p: i32 = (self.number_of_links - 2).into();
-        let m = -*nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-        let k = (self.number_of_links_f64*m).ceil() as u128;
-        let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-        0.5*nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum
+        let contour_length = F::from_f64(self.number_of_links as f64)*self.link_length;
+        F::from_f64(self.constants.boltzmann_constant)**temperature*(nondimensional_equilibrium_distribution(&self.number_of_links, &F::from_f64(0.0))/nondimensional_equilibrium_distribution(&self.number_of_links, &(*end_to_end_length/contour_length))).ln()
     }
 }
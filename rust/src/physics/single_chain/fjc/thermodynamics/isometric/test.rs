@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::test::Parameters as DefaultParameters;
 pub struct Parameters
 {
@@ -103,7 +104,6 @@ mod normalization
     use crate::physics::single_chain::
     {
         ONE,
-        ZERO,
         POINTS,
         test::integrate
     };
@@ -119,7 +119,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |end_to_end_length: f64| 4.0*PI*end_to_end_length.powi(2)*model.equilibrium_distribution(&end_to_end_length);
-            let integral = integrate(integrand, &ZERO, &(ONE*model.contour_length), &POINTS);
+            let integral = integrate(integrand, &0.0, &(ONE*model.contour_length), &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -135,7 +135,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |nondimensional_end_to_end_length_per_link_per_link: f64| 4.0*PI*nondimensional_end_to_end_length_per_link_per_link.powi(2)*model.nondimensional_equilibrium_distribution(&nondimensional_end_to_end_length_per_link_per_link);
-            let integral = integrate(integrand, &ZERO, &ONE, &POINTS);
+            let integral = integrate(integrand, &0.0, &ONE, &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -151,7 +151,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |end_to_end_length: f64| model.equilibrium_radial_distribution(&end_to_end_length);
-            let integral = integrate(integrand, &ZERO, &(ONE*model.contour_length), &POINTS);
+            let integral = integrate(integrand, &0.0, &(ONE*model.contour_length), &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -167,7 +167,7 @@ mod normalization
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let integrand = |nondimensional_end_to_end_length_per_link_per_link: f64| model.nondimensional_equilibrium_radial_distribution(&nondimensional_end_to_end_length_per_link_per_link);
-            let integral = integrate(integrand, &ZERO, &ONE, &POINTS);
+            let integral = integrate(integrand, &0.0, &ONE, &POINTS);
             assert!((integral - 1.0).abs() <= parameters.rel_tol);
         }
     }
@@ -381,7 +381,6 @@ mod relative
 {
     use super::*;
     use rand::Rng;
-    use crate::physics::single_chain::ZERO;
     #[test]
     fn helmholtz_free_energy()
     {
@@ -397,7 +396,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let helmholtz_free_energy = model.helmholtz_free_energy(&end_to_end_length, &temperature);
-            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&ZERO, &temperature);
+            let helmholtz_free_energy_0 = model.helmholtz_free_energy(&0.0, &temperature);
             let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&end_to_end_length, &temperature);
             let residual_abs = &helmholtz_free_energy - &helmholtz_free_energy_0 - &relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&helmholtz_free_energy_0;
@@ -419,7 +418,7 @@ mod relative
             let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let helmholtz_free_energy_per_link = model.helmholtz_free_energy_per_link(&end_to_end_length, &temperature);
-            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let helmholtz_free_energy_per_link_0 = model.helmholtz_free_energy_per_link(&0.0, &temperature);
             let relative_helmholtz_free_energy_per_link = model.relative_helmholtz_free_energy_per_link(&end_to_end_length, &temperature);
             let residual_abs = &helmholtz_free_energy_per_link - &helmholtz_free_energy_per_link_0 - &relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&helmholtz_free_energy_per_link_0;
@@ -440,7 +439,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy = model.nondimensional_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_0 = model.nondimensional_helmholtz_free_energy(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy = model.nondimensional_relative_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_helmholtz_free_energy - &nondimensional_helmholtz_free_energy_0 - &nondimensional_relative_helmholtz_free_energy;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_0;
@@ -461,7 +460,7 @@ mod relative
             let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_helmholtz_free_energy_per_link = model.nondimensional_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link, &temperature);
-            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_helmholtz_free_energy_per_link_0 = model.nondimensional_helmholtz_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_helmholtz_free_energy_per_link = model.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link);
             let residual_abs = &nondimensional_helmholtz_free_energy_per_link - &nondimensional_helmholtz_free_energy_per_link_0 - &nondimensional_relative_helmholtz_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_helmholtz_free_energy_per_link_0;
@@ -486,8 +485,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_0.abs() <= BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let relative_helmholtz_free_energy_0 = model.relative_helmholtz_free_energy(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -502,8 +501,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&(ZERO*(number_of_links as f64)*link_length), &temperature);
-            assert!(relative_helmholtz_free_energy_per_link_0.abs() <= BOLTZMANN_CONSTANT*temperature*ZERO);
+            let relative_helmholtz_free_energy_per_link_0 = model.relative_helmholtz_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -517,8 +516,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&ZERO);
-            assert!(nondimensional_relative_helmholtz_free_energy_0.abs() <= (number_of_links as f64)*ZERO);
+            let nondimensional_relative_helmholtz_free_energy_0 = model.nondimensional_relative_helmholtz_free_energy(&0.0);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -532,8 +531,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&ZERO);
-            assert!(nondimensional_relative_helmholtz_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_helmholtz_free_energy_per_link_0 = model.nondimensional_relative_helmholtz_free_energy_per_link(&0.0);
+            assert_eq!(nondimensional_relative_helmholtz_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -547,8 +546,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let equilibrium_radial_distribution_0 = model.equilibrium_radial_distribution(&(ZERO*(number_of_links as f64)*link_length));
-            assert!(equilibrium_radial_distribution_0.abs() <= ZERO);
+            let equilibrium_radial_distribution_0 = model.equilibrium_radial_distribution(&0.0);
+            assert_eq!(equilibrium_radial_distribution_0, 0.0);
         }
     }
     #[test]
@@ -562,8 +561,45 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_equilibrium_radial_distribution_0 = model.nondimensional_equilibrium_radial_distribution(&ZERO);
-            assert!(nondimensional_equilibrium_radial_distribution_0.abs() <= ZERO);
+            let nondimensional_equilibrium_radial_distribution_0 = model.nondimensional_equilibrium_radial_distribution(&0.0);
+            assert_eq!(nondimensional_equilibrium_radial_distribution_0, 0.0);
+        }
+    }
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force_0 = model.nondimensional_force(&0.0);
+            assert_eq!(nondimensional_force_0, 0.0);
+            let nondimensional_force_small = model.nondimensional_force(&ZERO);
+            assert!(nondimensional_force_small > 0.0 && nondimensional_force_small <= 3.0*ZERO);
+        }
+    }
+    #[test]
+    fn continuity()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = 1.0/(number_of_links as f64);
+            for (below, above) in [(0.0, ZERO), (nondimensional_end_to_end_length_per_link*(1.0 - 1e-12), nondimensional_end_to_end_length_per_link*(1.0 + 1e-12))]
+            {
+                assert!((model.nondimensional_equilibrium_distribution(&below)/model.nondimensional_equilibrium_distribution(&above) - 1.0).abs() <= parameters.rel_tol);
+                assert!((model.nondimensional_force(&below) - model.nondimensional_force(&above)).abs() <= 3.0*ZERO);
+                assert!((model.nondimensional_relative_helmholtz_free_energy(&below) - model.nondimensional_relative_helmholtz_free_energy(&above)).abs() <= (number_of_links as f64)*ZERO);
+            }
         }
     }
 }
@@ -852,3 +888,138 @@ mod thermodynamic_limit
         }
     }
 }
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force)/nondimensional_end_to_end_length_per_link - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((model.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod precision
+{
+    use super::*;
+    use crate::math::float::DoubleDouble;
+    fn cancellation_error<F>(number_of_links: u8, function: F) -> f64
+    where F: Fn(&FJC, &f64) -> (f64, DoubleDouble)
+    {
+        let model = FJC::init(number_of_links, 1.0, 1.0);
+        (1..100).map(|index| function(&model, &(1e-2*(index as f64)))).map(|(value, reference)| (value/reference.to_f64() - 1.0).abs()).fold(0.0, f64::max)
+    }
+    #[test]
+    fn nondimensional_force()
+    {
+        let parameters = Parameters::default();
+        let force = |model: &FJC, gamma: &f64| (model.nondimensional_force(gamma), model.nondimensional_force(&DoubleDouble::from(*gamma)));
+        let minimum_error = cancellation_error(parameters.number_of_links_minimum, force);
+        let maximum_error = cancellation_error(parameters.number_of_links_maximum, force);
+        assert!(minimum_error <= 1e-14);
+        assert!(maximum_error <= 1e-8);
+        assert!(maximum_error >= 1e3*minimum_error);
+    }
+    #[test]
+    fn nondimensional_relative_helmholtz_free_energy()
+    {
+        let parameters = Parameters::default();
+        let relative_helmholtz_free_energy = |model: &FJC, gamma: &f64| (model.nondimensional_relative_helmholtz_free_energy(gamma), model.nondimensional_relative_helmholtz_free_energy(&DoubleDouble::from(*gamma)));
+        let minimum_error = cancellation_error(parameters.number_of_links_minimum, relative_helmholtz_free_energy);
+        let maximum_error = cancellation_error(parameters.number_of_links_maximum, relative_helmholtz_free_energy);
+        assert!(minimum_error <= 1e-12);
+        assert!(maximum_error <= 1e-7);
+        assert!(maximum_error >= 1e3*minimum_error);
+    }
+    #[test]
+    fn large_number_of_links()
+    {
+        let force = |model: &FJC, gamma: &f64| (model.nondimensional_force(gamma), model.nondimensional_force(&DoubleDouble::from(*gamma)));
+        assert!(cancellation_error(50, force) <= 1e-3);
+        assert!(cancellation_error(100, force) >= 1e-1);
+        let model = FJC::init(100, 1.0, 1.0);
+        let legendre_error = (1..100).map(|index| 1e-2*(index as f64)).map(|gamma| (model.nondimensional_force(&DoubleDouble::from(gamma)).to_f64()/model.legendre.nondimensional_force(&gamma) - 1.0).abs()).fold(0.0, f64::max);
+        assert!(legendre_error <= 3e-2);
+    }
+    #[test]
+    fn single_precision()
+    {
+        let parameters = Parameters::default();
+        let force = |model: &FJC, gamma: &f64| (model.nondimensional_force(&(*gamma as f32)) as f64, model.nondimensional_force(&DoubleDouble::from(*gamma)));
+        assert!(cancellation_error(parameters.number_of_links_minimum, force) <= 1e-5);
+    }
+}
+mod dual
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::fjc::Parameters as ModelParameters;
+    use crate::physics::single_chain::test::check_gradient;
+    #[test]
+    fn value()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let model_parameters = ModelParameters {number_of_links, constants: Constants::default(), link_length, hinge_mass};
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            assert!((model_parameters.force(&end_to_end_length, &temperature)/model.force(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.helmholtz_free_energy(&end_to_end_length, &temperature)/model.helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.relative_helmholtz_free_energy(&end_to_end_length, &temperature)/model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn gradient()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = DefaultParameters::default().nondimensional_end_to_end_length_per_link_small + parameters.nondimensional_end_to_end_length_per_link_reference*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let point = [link_length, hinge_mass, end_to_end_length, temperature];
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.force(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.force(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.helmholtz_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.helmholtz_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_helmholtz_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_helmholtz_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
+        }
+    }
+}
@@ -1,59 +1,66 @@
 mod test;
 use std::f64::consts::PI;
-use crate::physics::
+use crate::physics::Constants;
+use crate::math::special::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    langevin,
+    ln_sinhc
 };
 pub struct FJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     number_of_links_f64: f64
 }
 impl FJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             number_of_links_f64: number_of_links as f64
         }
     }
     pub fn helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        (nondimensional_force/nondimensional_force.tanh() - 1.0 - (nondimensional_force.sinh()/nondimensional_force).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())*self.number_of_links_f64
+        (nondimensional_force*langevin(nondimensional_force) - ln_sinhc(nondimensional_force) - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())*self.number_of_links_f64
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        nondimensional_force/nondimensional_force.tanh() - 1.0 - (nondimensional_force.sinh()/nondimensional_force).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        nondimensional_force*langevin(nondimensional_force) - ln_sinhc(nondimensional_force) - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64) -> f64
     {
-        (nondimensional_force/nondimensional_force.tanh() - 1.0 - (nondimensional_force.sinh()/nondimensional_force).ln())*self.number_of_links_f64
+        (nondimensional_force*langevin(nondimensional_force) - ln_sinhc(nondimensional_force))*self.number_of_links_f64
     }
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        nondimensional_force/nondimensional_force.tanh() - 1.0 - (nondimensional_force.sinh()/nondimensional_force).ln()
+        nondimensional_force*langevin(nondimensional_force) - ln_sinhc(nondimensional_force)
     }
 }
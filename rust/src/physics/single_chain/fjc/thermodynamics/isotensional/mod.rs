@@ -1,78 +1,146 @@
 mod test;
 pub mod legendre;
-use std::f64::consts::PI;
-use crate::physics::
+use crate::physics::Constants;
+use crate::physics::single_chain::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    Isotensional,
+    ln_hinge_partition_function
+};
+use crate::physics::single_chain::fjc::Parameters;
+use crate::math::float::Float;
+use crate::math::special::
+{
+    inverse_langevin,
+    langevin,
+    ln_sinhc
 };
 pub struct FJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     number_of_links_f64: f64,
     pub legendre: legendre::FJC
 }
 impl FJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             number_of_links_f64: number_of_links as f64,
-            legendre: legendre::FJC::init(number_of_links, link_length, hinge_mass)
+            legendre: legendre::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
         }
     }
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_end_to_end_length(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*self.link_length
+        self.nondimensional_end_to_end_length(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.link_length
     }
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_end_to_end_length_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*self.link_length
+        self.nondimensional_end_to_end_length_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.link_length
     }
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64) -> f64
     {
-        (1.0/nondimensional_force.tanh() - 1.0/nondimensional_force)*self.number_of_links_f64
+        langevin(nondimensional_force)*self.number_of_links_f64
     }
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        1.0/nondimensional_force.tanh() - 1.0/nondimensional_force
+        langevin(nondimensional_force)
+    }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.number_of_links_f64/self.link_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        if nondimensional_end_to_end_length_per_link.abs() >= 1.0
+        {
+            return f64::NAN
+        }
+        inverse_langevin(nondimensional_end_to_end_length_per_link)
     }
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.relative_gibbs_free_energy_per_link(force, temperature) - BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        self.relative_gibbs_free_energy_per_link(force, temperature) - self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_gibbs_free_energy(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_gibbs_free_energy(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_gibbs_free_energy_per_link(&(force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -self.number_of_links_f64*(nondimensional_force.sinh()/nondimensional_force).ln() - self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -self.number_of_links_f64*ln_sinhc(nondimensional_force) - self.number_of_links_f64*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -(nondimensional_force.sinh()/nondimensional_force).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -ln_sinhc(nondimensional_force) - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
     {
-        -self.number_of_links_f64*(nondimensional_force.sinh()/nondimensional_force).ln()
+        -self.number_of_links_f64*ln_sinhc(nondimensional_force)
     }
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        -(nondimensional_force.sinh()/nondimensional_force).ln()
+        -ln_sinhc(nondimensional_force)
+    }
+}
+impl Isotensional for FJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "FJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the FJC model in the isotensional ensemble, with the parameters, force, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &F, temperature: &F) -> F
+    {
+        F::from_f64(self.number_of_links as f64)*self.link_length*langevin(&(*force*self.link_length/F::from_f64(self.constants.boltzmann_constant)/ *temperature))
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        self.relative_gibbs_free_energy(force, temperature) - F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        -F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*ln_sinhc(&(*force*self.link_length/F::from_f64(self.constants.boltzmann_constant)/ *temperature))
     }
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::test::Parameters as DefaultParameters;
 pub struct Parameters
 {
@@ -368,7 +369,6 @@ mod per_link
 mod relative
 {
     use super::*;
-    use crate::physics::single_chain::ZERO;
     use rand::Rng;
     #[test]
     fn gibbs_free_energy()
@@ -385,7 +385,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy = model.gibbs_free_energy(&force, &temperature);
-            let gibbs_free_energy_0 = model.gibbs_free_energy(&ZERO, &temperature);
+            let gibbs_free_energy_0 = model.gibbs_free_energy(&0.0, &temperature);
             let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&force, &temperature);
             let residual_abs = &gibbs_free_energy - &gibbs_free_energy_0 - &relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&gibbs_free_energy_0;
@@ -408,7 +408,7 @@ mod relative
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
             let gibbs_free_energy_per_link = model.gibbs_free_energy_per_link(&force, &temperature);
-            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&ZERO, &temperature);
+            let gibbs_free_energy_per_link_0 = model.gibbs_free_energy_per_link(&0.0, &temperature);
             let relative_gibbs_free_energy_per_link = model.relative_gibbs_free_energy_per_link(&force, &temperature);
             let residual_abs = &gibbs_free_energy_per_link - &gibbs_free_energy_per_link_0 - &relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&gibbs_free_energy_per_link_0;
@@ -430,7 +430,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy = model.nondimensional_gibbs_free_energy(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_0 = model.nondimensional_gibbs_free_energy(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy = model.nondimensional_relative_gibbs_free_energy(&nondimensional_force);
             let residual_abs = &nondimensional_gibbs_free_energy - &nondimensional_gibbs_free_energy_0 - &nondimensional_relative_gibbs_free_energy;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_0;
@@ -452,7 +452,7 @@ mod relative
             let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
             let nondimensional_gibbs_free_energy_per_link = model.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, &temperature);
-            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&ZERO, &temperature);
+            let nondimensional_gibbs_free_energy_per_link_0 = model.nondimensional_gibbs_free_energy_per_link(&0.0, &temperature);
             let nondimensional_relative_gibbs_free_energy_per_link = model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force);
             let residual_abs = &nondimensional_gibbs_free_energy_per_link - &nondimensional_gibbs_free_energy_per_link_0 - &nondimensional_relative_gibbs_free_energy_per_link;
             let residual_rel = &residual_abs/&nondimensional_gibbs_free_energy_per_link_0;
@@ -464,7 +464,6 @@ mod relative
 mod zero
 {
     use super::*;
-    use crate::physics::single_chain::ZERO;
     use rand::Rng;
     #[test]
     fn end_to_end_length()
@@ -478,8 +477,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let end_to_end_length_0 = model.end_to_end_length(&ZERO, &temperature);
-            assert!(end_to_end_length_0.abs() <= 3.0*BOLTZMANN_CONSTANT*temperature*(number_of_links as f64)*ZERO);
+            let end_to_end_length_0 = model.end_to_end_length(&0.0, &temperature);
+            assert_eq!(end_to_end_length_0, 0.0);
         }
     }
     #[test]
@@ -494,8 +493,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let end_to_end_length_per_link_0 = model.end_to_end_length_per_link(&ZERO, &temperature);
-            assert!(end_to_end_length_per_link_0.abs() <= 3.0*BOLTZMANN_CONSTANT*temperature*ZERO);
+            let end_to_end_length_per_link_0 = model.end_to_end_length_per_link(&0.0, &temperature);
+            assert_eq!(end_to_end_length_per_link_0, 0.0);
         }
     }
     #[test]
@@ -509,8 +508,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_end_to_end_length_0 = model.nondimensional_end_to_end_length(&ZERO);
-            assert!(nondimensional_end_to_end_length_0.abs() <= 3.0*(number_of_links as f64)*ZERO);
+            let nondimensional_end_to_end_length_0 = model.nondimensional_end_to_end_length(&0.0);
+            assert_eq!(nondimensional_end_to_end_length_0, 0.0);
         }
     }
     #[test]
@@ -524,8 +523,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_end_to_end_length_per_link_0 = model.nondimensional_end_to_end_length_per_link(&ZERO);
-            assert!(nondimensional_end_to_end_length_per_link_0.abs() <= 3.0*ZERO);
+            let nondimensional_end_to_end_length_per_link_0 = model.nondimensional_end_to_end_length_per_link(&0.0);
+            assert_eq!(nondimensional_end_to_end_length_per_link_0, 0.0);
         }
     }
     #[test]
@@ -540,8 +539,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&ZERO, &temperature);
-            assert!(relative_gibbs_free_energy_0.abs() <= ZERO);
+            let relative_gibbs_free_energy_0 = model.relative_gibbs_free_energy(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -556,8 +555,8 @@ mod zero
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
             let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
-            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&ZERO, &temperature);
-            assert!(relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let relative_gibbs_free_energy_per_link_0 = model.relative_gibbs_free_energy_per_link(&0.0, &temperature);
+            assert_eq!(relative_gibbs_free_energy_per_link_0, 0.0);
         }
     }
     #[test]
@@ -571,8 +570,8 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&ZERO);
-            assert!(nondimensional_relative_gibbs_free_energy_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_0 = model.nondimensional_relative_gibbs_free_energy(&0.0);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_0, 0.0);
         }
     }
     #[test]
@@ -586,8 +585,195 @@ mod zero
             let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
             let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
             let model = FJC::init(number_of_links, link_length, hinge_mass);
-            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&ZERO);
-            assert!(nondimensional_relative_gibbs_free_energy_per_link_0.abs() <= ZERO);
+            let nondimensional_relative_gibbs_free_energy_per_link_0 = model.nondimensional_relative_gibbs_free_energy_per_link(&0.0);
+            assert_eq!(nondimensional_relative_gibbs_free_energy_per_link_0, 0.0);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn nondimensional_force_large()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = 1e1_f64.powf(1.0 + 3.0*rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force);
+            assert!((model.nondimensional_force(&nondimensional_end_to_end_length_per_link)/nondimensional_force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force_large()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = 1e1_f64.powf(1.0 + 3.0*rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn beyond_full_extension()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = 1.0 + rng.gen::<f64>();
+            assert!(model.nondimensional_force(&1.0).is_nan());
+            assert!(model.nondimensional_force(&nondimensional_end_to_end_length_per_link).is_nan());
+            assert!(model.nondimensional_force(&(-nondimensional_end_to_end_length_per_link)).is_nan());
+            assert!(model.force(&(nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length), &temperature).is_nan());
+        }
+    }
+}
+mod extreme
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn large_nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            for nondimensional_force in [1e3, 1e4, 1e6]
+            {
+                let step = 1e-6*nondimensional_force;
+                let derivative = (model.nondimensional_relative_gibbs_free_energy_per_link(&(nondimensional_force + step)) - model.nondimensional_relative_gibbs_free_energy_per_link(&(nondimensional_force - step)))/(2.0*step);
+                let nondimensional_end_to_end_length_per_link = model.nondimensional_end_to_end_length_per_link(&nondimensional_force);
+                assert!(nondimensional_end_to_end_length_per_link.is_finite());
+                assert!((derivative/nondimensional_end_to_end_length_per_link + 1.0).abs() <= parameters.rel_tol);
+            }
+            let nondimensional_force = 1e8;
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force) - 1.0 + 1.0/nondimensional_force).abs() <= parameters.abs_tol);
+            assert!((model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force)/(2.0_f64.ln() + nondimensional_force.ln() - nondimensional_force) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn small_nondimensional_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_force = 1e-8;
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force)/nondimensional_force*3.0 - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force)/nondimensional_force.powi(2)*6.0 + 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod dual
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::fjc::Parameters as ModelParameters;
+    use crate::physics::single_chain::test::
+    {
+        Parameters as DefaultParameters,
+        check_gradient
+    };
+    #[test]
+    fn value()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, hinge_mass);
+            let model_parameters = ModelParameters {number_of_links, constants: Constants::default(), link_length, hinge_mass};
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model_parameters.end_to_end_length(&force, &temperature)/model.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.gibbs_free_energy(&force, &temperature)/model.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.relative_gibbs_free_energy(&force, &temperature)/model.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn gradient()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = DefaultParameters::default().nondimensional_force_small + parameters.nondimensional_force_reference*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            let point = [link_length, hinge_mass, force, temperature];
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.end_to_end_length(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.end_to_end_length(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.gibbs_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.gibbs_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_gibbs_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_gibbs_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
         }
     }
 }
@@ -9,6 +9,10 @@ pub mod isotensional;
 /// The thermodynamics of the FJC model in the modified canonical ensemble.
 pub mod modified_canonical;
 
+use crate::physics::Constants;
+use std::f64::consts::PI;
+use crate::math::float::Float;
+
 /// The structure of the thermodynamics of the FJC model.
 pub struct FJC
 {
@@ -21,6 +25,9 @@ pub struct FJC
     /// The number of links in the chain.
     pub number_of_links: u8,
 
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
     /// The thermodynamic functions of the model in the isometric ensemble.
     pub isometric: isometric::FJC,
 
@@ -36,15 +43,64 @@ impl FJC
 {
     /// Initializes and returns an instance of the thermodynamics of the FJC model.
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            isometric: isometric::FJC::init(number_of_links, link_length, hinge_mass),
-            isotensional: isotensional::FJC::init(number_of_links, link_length, hinge_mass),
-            modified_canonical: modified_canonical::FJC::init(number_of_links, link_length, hinge_mass)
+            constants,
+            isometric: isometric::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            isotensional: isotensional::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            modified_canonical: modified_canonical::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
         }
     }
 }
+
+/// The sum in the exact equilibrium distribution of the FJC model divided by the nondimensional end-to-end length per link, or its derivatives with respect to the nondimensional end-to-end length per link.
+///
+/// The sum vanishes linearly at zero length, so within the first polynomial piece of the distribution it is expanded in powers of the length and divided exactly, which retains precision near and at zero.
+/// The even powers in this expansion vanish identically apart from the highest power, and are omitted so that they do not contribute roundoff.
+///
+/// The alternating sum cancels catastrophically as the number of links grows, so it is generic over the floating-point type to allow evaluating it in extended precision, with the binomial coefficients also computed in that type; double-double precision retains accuracy up to about 150 links.
+fn nondimensional_equilibrium_distribution_sum<F: Float>(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &F, derivative: i32) -> F
+{
+    let n = *number_of_links as u128;
+    let number_of_links_f64 = *number_of_links as f64;
+    let p: i32 = (number_of_links - 2).into();
+    let binomial = |a: u128, b: u128| (1..=b).fold(F::from_f64(1.0), |product, i| product*F::from_f64((a - b + i) as f64)/F::from_f64(i as f64));
+    let falling_factorial = |a: i32, b: i32| F::from_f64((a - b + 1..=a).product::<i32>() as f64);
+    let ratio = |s: u128| F::from_f64(s as f64)/F::from_f64(number_of_links_f64);
+    let half = F::from_f64(0.5);
+    if nondimensional_end_to_end_length_per_link.abs() < F::from_f64(1.0/number_of_links_f64)
+    {
+        let terms: Vec<(F, F)> = (0..n.div_ceil(2)).map(|s| (F::from_f64((-1.0_f64).powi(s as i32))*binomial(n, s), half - ratio(s))).collect();
+        (1..=p).filter(|i| i % 2 == 1 || i == &p).map(|i| binomial(p as u128, i as u128)*(-half).powi(i)*terms.iter().map(|(coefficient, base)| *coefficient*base.powi(p - i)).sum::<F>()*falling_factorial(i - 1, derivative)*nondimensional_end_to_end_length_per_link.powi((i - 1 - derivative).max(0))).sum()
+    }
+    else
+    {
+        let m = -*nondimensional_end_to_end_length_per_link*half + half;
+        let k = (number_of_links_f64*m.to_f64()).ceil() as u128;
+        let terms: Vec<(F, F)> = (0..k).map(|s| (F::from_f64((-1.0_f64).powi(s as i32))*binomial(n, s), m - ratio(s))).collect();
+        let sum = |j: i32| terms.iter().map(|(coefficient, base)| *coefficient*base.powi(p - j)).sum::<F>();
+        (0..=derivative).map(|j| binomial(derivative as u128, j as u128)*falling_factorial(p, j)*(-half).powi(j)*sum(j)*falling_factorial(-1, derivative - j)*nondimensional_end_to_end_length_per_link.powi(j - derivative - 1)).sum()
+    }
+}
+
+/// The exact equilibrium probability density of nondimensional end-to-end vectors per link of the FJC model.
+fn nondimensional_equilibrium_distribution<F: Float>(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &F) -> F
+{
+    let number_of_links_f = F::from_f64(*number_of_links as f64);
+    (number_of_links_f*number_of_links_f.ln() - (2..*number_of_links - 1).map(|k| F::from_f64(k as f64).ln()).sum::<F>()).exp()/F::from_f64(8.0*PI)*nondimensional_equilibrium_distribution_sum(number_of_links, nondimensional_end_to_end_length_per_link, 0)
+}
+
+/// The logarithmic derivative of the exact equilibrium probability density of the FJC model with respect to the nondimensional end-to-end length per link.
+fn nondimensional_equilibrium_distribution_log_derivative<F: Float>(number_of_links: &u8, nondimensional_end_to_end_length_per_link: &F) -> F
+{
+    nondimensional_equilibrium_distribution_sum(number_of_links, nondimensional_end_to_end_length_per_link, 1)/nondimensional_equilibrium_distribution_sum(number_of_links, nondimensional_end_to_end_length_per_link, 0)
+}
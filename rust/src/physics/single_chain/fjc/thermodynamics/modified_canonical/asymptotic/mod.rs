@@ -1,25 +1,32 @@
 mod test;
 pub mod weak_potential;
 pub mod strong_potential;
+use crate::physics::Constants;
 pub struct FJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub weak_potential: weak_potential::FJC,
     pub strong_potential: strong_potential::FJC
 }
 impl FJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
-            weak_potential: weak_potential::FJC::init(number_of_links, link_length, hinge_mass),
-            strong_potential: strong_potential::FJC::init(number_of_links, link_length, hinge_mass)
+            constants,
+            weak_potential: weak_potential::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            strong_potential: strong_potential::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
         }
     }
 }
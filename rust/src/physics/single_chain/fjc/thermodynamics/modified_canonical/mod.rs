@@ -1,22 +1,30 @@
 mod test;
 pub mod asymptotic;
 use std::f64::consts::PI;
-use crate::physics::
-{
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
-};
+use crate::physics::Constants;
 use crate::physics::single_chain::
 {
     ONE,
-    ZERO,
-    POINTS
+    POINTS,
+    ModifiedCanonical
+};
+use crate::math::special::
+{
+    langevin,
+    ln_sinhc
 };
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use super::nondimensional_equilibrium_distribution;
 pub struct FJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     number_of_links_f64: f64,
     contour_length: f64,
     pub asymptotic: asymptotic::FJC,
@@ -24,15 +32,20 @@ pub struct FJC
 impl FJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
     {
         FJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             number_of_links_f64: number_of_links as f64,
             contour_length: (number_of_links as f64)*link_length,
-            asymptotic: asymptotic::FJC::init(number_of_links, link_length, hinge_mass)
+            asymptotic: asymptotic::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
         }
     }
     pub fn end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
@@ -53,59 +66,51 @@ impl FJC
     }
     pub fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_force(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*BOLTZMANN_CONSTANT*temperature/self.link_length
+        self.nondimensional_force(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature/self.link_length
     }
     pub fn nondimensional_force(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        let n = self.number_of_links as u128;
-        let p: i32 = (self.number_of_links - 2).into();
-        let integrand_numerator = |nondimensional_end_to_end_length_per_link: f64|
-        {
-            let m = -nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-            let k = (self.number_of_links_f64*m).ceil() as u128;
-            let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-            0.5*nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum*((nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link)*(-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link)*(-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link) + ((-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - (-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)*nondimensional_end_to_end_length_per_link))
-        };
-        let integrand_denominator = |nondimensional_end_to_end_length_per_link: f64|
+        let integrand_denominator = |nondimensional_end_to_end_length_per_link: f64| 4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link)*(ln_sinhc(&(nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)) - 0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance.powi(2) + nondimensional_end_to_end_length_per_link.powi(2))).exp();
+        let integrand_numerator = |nondimensional_end_to_end_length_per_link: f64| nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link*langevin(&(nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)))*integrand_denominator(nondimensional_end_to_end_length_per_link);
+        let dx = ONE/(POINTS as f64);
+        (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand_numerator((0.5 + *index as f64)*dx)).sum::<f64>()/(0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand_denominator((0.5 + *index as f64)*dx)).sum::<f64>()/self.number_of_links_f64
+    }
+    pub fn potential_distance(&self, force: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_potential_distance(&(force*self.link_length/self.constants.boltzmann_constant/temperature), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    pub fn nondimensional_potential_distance(&self, nondimensional_force: &f64, nondimensional_potential_stiffness: &f64) -> f64
+    {
+        if nondimensional_force < &0.0
         {
-            let m = -nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-            let k = (self.number_of_links_f64*m).ceil() as u128;
-            let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-            0.5*nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum*((-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - (-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)
-        };
-        let dx = (ONE - ZERO)/(POINTS as f64);
-        (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand_numerator(ZERO + (0.5 + *index as f64)*dx)).sum::<f64>()/(0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand_denominator(ZERO + (0.5 + *index as f64)*dx)).sum::<f64>()/self.number_of_links_f64    
+            return -self.nondimensional_potential_distance(&(-nondimensional_force), nondimensional_potential_stiffness)
+        }
+        let function = |nondimensional_potential_distance: &f64| self.nondimensional_force(nondimensional_potential_distance, nondimensional_potential_stiffness) - nondimensional_force;
+        let (lower, upper) = bracket(function, &(nondimensional_force/(3.0 + nondimensional_force) + self.number_of_links_f64*nondimensional_force/nondimensional_potential_stiffness), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
     }
     pub fn helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(&(potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy(&(potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/BOLTZMANN_CONSTANT/temperature), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/BOLTZMANN_CONSTANT/temperature))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(*potential_distance/self.contour_length), &(potential_stiffness*self.contour_length.powi(2)/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
-        let integrand = |nondimensional_end_to_end_length_per_link: f64|
-        {
-            let n = self.number_of_links as u128;
-            let p: i32 = (self.number_of_links - 2).into();
-            let m = -nondimensional_end_to_end_length_per_link*0.5 + 0.5;
-            let k = (self.number_of_links_f64*m).ceil() as u128;
-            let sum: f64 = (0..=k-1).collect::<Vec::<u128>>().iter().map(|s| (-1.0_f64).powf(*s as f64)*(((1..=n).product::<u128>()/(1..=*s).product::<u128>()/(1..=n-s).product::<u128>()) as f64)*(m - (*s as f64)/self.number_of_links_f64).powi(p)).sum();
-            0.5*nondimensional_end_to_end_length_per_link*(n.pow(n as u32) as f64)/((1..=n-2).product::<u128>() as f64)*sum*((-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance - nondimensional_end_to_end_length_per_link).powi(2)).exp() - (-0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance + nondimensional_end_to_end_length_per_link).powi(2)).exp())/(2.0*nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)
-        };
-        let dx = (ONE - ZERO)/(POINTS as f64);
-        let nondimensional_configurational_partition_function = (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand(ZERO + (0.5 + *index as f64)*dx)).sum::<f64>()*dx;
-        -nondimensional_configurational_partition_function.ln() - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        let integrand = |nondimensional_end_to_end_length_per_link: f64| 4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*nondimensional_equilibrium_distribution(&self.number_of_links, &nondimensional_end_to_end_length_per_link)*(ln_sinhc(&(nondimensional_potential_stiffness*nondimensional_potential_distance*nondimensional_end_to_end_length_per_link)) - 0.5*nondimensional_potential_stiffness*(nondimensional_potential_distance.powi(2) + nondimensional_end_to_end_length_per_link.powi(2))).exp();
+        let dx = ONE/(POINTS as f64);
+        let nondimensional_configurational_partition_function = (0..=POINTS-1).collect::<Vec::<u128>>().iter().map(|index| integrand((0.5 + *index as f64)*dx)).sum::<f64>()*dx;
+        -nondimensional_configurational_partition_function.ln() - (self.number_of_links_f64 - 1.0)*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64, temperature: &f64) -> f64
     {
@@ -113,7 +118,7 @@ impl FJC
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, &300.0) - self.nondimensional_helmholtz_free_energy(&ZERO, nondimensional_potential_stiffness, &300.0)
+        self.nondimensional_helmholtz_free_energy(nondimensional_potential_distance, nondimensional_potential_stiffness, &300.0) - self.nondimensional_helmholtz_free_energy(&0.0, nondimensional_potential_stiffness, &300.0)
     }
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_potential_distance: &f64, nondimensional_potential_stiffness: &f64) -> f64
     {
@@ -152,3 +157,35 @@ impl FJC
         self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_potential_distance, nondimensional_potential_stiffness) - 0.5*nondimensional_potential_stiffness*nondimensional_potential_distance.powi(2)/self.number_of_links_f64
     }
 }
+
+impl ModifiedCanonical for FJC
+{
+    fn end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::end_to_end_length(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::force(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::helmholtz_free_energy(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_helmholtz_free_energy(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::gibbs_free_energy(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_gibbs_free_energy(self, potential_distance, potential_stiffness, temperature)
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
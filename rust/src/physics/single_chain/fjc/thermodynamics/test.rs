@@ -543,7 +543,6 @@ mod modified_canonical
         use rand::Rng;
         use crate::physics::single_chain::
         {
-            ZERO,
             POINTS,
             test::integrate
         };
@@ -570,8 +569,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.force(&end_to_end_length, &potential_stiffness, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                    let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -601,8 +600,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.nondimensional_force(&nondimensional_end_to_end_length_per_link, &nondimensional_potential_stiffness).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -634,8 +633,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.relative_helmholtz_free_energy(&end_to_end_length, &potential_stiffness, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                    let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -667,8 +666,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.relative_helmholtz_free_energy_per_link(&end_to_end_length, &potential_stiffness, &temperature).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                    let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -698,8 +697,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.nondimensional_relative_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link, &nondimensional_potential_stiffness).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -729,8 +728,8 @@ mod modified_canonical
                     {
                         model.modified_canonical.nondimensional_relative_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link, &nondimensional_potential_stiffness).powi(2)
                     };
-                    let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                    let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                    let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                     (numerator/denominator).sqrt()
                 };
                 let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1206,7 +1205,6 @@ mod modified_canonical
             use rand::Rng;
             use crate::physics::single_chain::
             {
-                ZERO,
                 POINTS,
                 test::integrate
             };
@@ -1233,8 +1231,8 @@ mod modified_canonical
                         {
                             model.isometric.force(&end_to_end_length, &temperature).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                        let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1264,8 +1262,8 @@ mod modified_canonical
                         {
                             model.isometric.nondimensional_force(&nondimensional_end_to_end_length_per_link).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                        let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1297,8 +1295,8 @@ mod modified_canonical
                         {
                             (model.isometric.helmholtz_free_energy(&end_to_end_length, &temperature) - model.isometric.helmholtz_free_energy(&(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                        let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1330,8 +1328,8 @@ mod modified_canonical
                         {
                             (model.isometric.helmholtz_free_energy_per_link(&end_to_end_length, &temperature) - model.isometric.helmholtz_free_energy_per_link(&(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                        let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1363,8 +1361,8 @@ mod modified_canonical
                         {
                             (model.isometric.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - model.isometric.relative_helmholtz_free_energy(&(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                        let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1396,8 +1394,8 @@ mod modified_canonical
                         {
                             (model.isometric.relative_helmholtz_free_energy_per_link(&end_to_end_length, &temperature) - model.isometric.relative_helmholtz_free_energy_per_link(&(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
-                        let denominator = integrate(integrand_denominator, &(ZERO*(number_of_links as f64)*link_length), &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &(parameters.nondimensional_potential_distance_small*(number_of_links as f64)*link_length), &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1428,8 +1426,8 @@ mod modified_canonical
                         {
                             (model.isometric.nondimensional_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link, &temperature) - model.isometric.nondimensional_helmholtz_free_energy(&parameters.nondimensional_potential_distance_small, &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                        let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1460,8 +1458,8 @@ mod modified_canonical
                         {
                             (model.isometric.nondimensional_helmholtz_free_energy_per_link(&nondimensional_end_to_end_length_per_link, &temperature) - model.isometric.nondimensional_helmholtz_free_energy_per_link(&parameters.nondimensional_potential_distance_small, &temperature)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                        let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
@@ -1491,8 +1489,8 @@ mod modified_canonical
                         {
                             (model.isometric.nondimensional_relative_helmholtz_free_energy(&nondimensional_end_to_end_length_per_link) - model.isometric.nondimensional_relative_helmholtz_free_energy(&parameters.nondimensional_potential_distance_small)).powi(2)
                         };
-                        let numerator = integrate(integrand_numerator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
-                        let denominator = integrate(integrand_denominator, &ZERO, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let numerator = integrate(integrand_numerator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
+                        let denominator = integrate(integrand_denominator, &0.0, &parameters.nondimensional_potential_distance_small, &POINTS);
                         (numerator/denominator).sqrt()
                     };
                     let residual_rel_1 = residual_rel(parameters.nondimensional_potential_stiffness_large);
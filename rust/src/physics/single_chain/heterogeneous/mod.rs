@@ -0,0 +1,159 @@
+mod test;
+
+/// The thermodynamics of the heterogeneous chain model.
+pub mod thermodynamics;
+
+use crate::physics::single_chain::
+{
+    Isotensional,
+    fjc,
+    efjc,
+    swfjc
+};
+use crate::error::
+{
+    Error,
+    positive
+};
+
+static MAXIMUM_NUMBER_OF_LINKS: &str = "a heterogeneous chain has at most 255 links";
+
+/// The types of links that a heterogeneous chain can be built from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Link
+{
+    /// A rigid link of the FJC model.
+    FJC
+    {
+        /// The mass of the hinge in units of kg/mol.
+        hinge_mass: f64,
+        /// The length of the link in units of nm.
+        link_length: f64
+    },
+    /// A harmonically extensible link of the EFJC model.
+    EFJC
+    {
+        /// The mass of the hinge in units of kg/mol.
+        hinge_mass: f64,
+        /// The rest length of the link in units of nm.
+        link_length: f64,
+        /// The stiffness of the link in units of J/(mol⋅nm^2).
+        link_stiffness: f64
+    },
+    /// A square-well link of the SWFJC model.
+    SWFJC
+    {
+        /// The mass of the hinge in units of kg/mol.
+        hinge_mass: f64,
+        /// The minimum length of the link in units of nm.
+        link_length: f64,
+        /// The width of the well in units of nm.
+        well_width: f64
+    }
+}
+
+/// The implemented functionality of the types of links.
+impl Link
+{
+    /// The (rest or minimum) length of the link.
+    pub fn link_length(&self) -> f64
+    {
+        match *self
+        {
+            Link::FJC{link_length, ..} => link_length,
+            Link::EFJC{link_length, ..} => link_length,
+            Link::SWFJC{link_length, ..} => link_length
+        }
+    }
+    /// The supremum of the lengths the link can be stretched to.
+    pub fn maximum_link_length(&self) -> f64
+    {
+        match *self
+        {
+            Link::FJC{link_length, ..} => link_length,
+            Link::EFJC{..} => f64::INFINITY,
+            Link::SWFJC{link_length, well_width, ..} => link_length + well_width
+        }
+    }
+    /// Returns an error naming the model of the link if any of its parameters are invalid.
+    pub fn validate(&self) -> Result<(), Error>
+    {
+        match *self
+        {
+            Link::FJC{hinge_mass, link_length} =>
+            {
+                positive("FJC", "link_length", &link_length)?;
+                positive("FJC", "hinge_mass", &hinge_mass)
+            },
+            Link::EFJC{hinge_mass, link_length, link_stiffness} =>
+            {
+                positive("EFJC", "link_length", &link_length)?;
+                positive("EFJC", "hinge_mass", &hinge_mass)?;
+                positive("EFJC", "link_stiffness", &link_stiffness)
+            },
+            Link::SWFJC{hinge_mass, link_length, well_width} =>
+            {
+                positive("SWFJC", "link_length", &link_length)?;
+                positive("SWFJC", "hinge_mass", &hinge_mass)?;
+                positive("SWFJC", "well_width", &well_width)
+            }
+        }
+    }
+    /// The thermodynamics of the link in the isotensional ensemble, as a single-link chain of the corresponding model.
+    pub fn isotensional(&self) -> Box<dyn Isotensional>
+    {
+        match *self
+        {
+            Link::FJC{hinge_mass, link_length} => Box::new(fjc::thermodynamics::isotensional::FJC::init(1, link_length, hinge_mass)),
+            Link::EFJC{hinge_mass, link_length, link_stiffness} => Box::new(efjc::thermodynamics::isotensional::EFJC::init(1, link_length, hinge_mass, link_stiffness)),
+            Link::SWFJC{hinge_mass, link_length, well_width} => Box::new(swfjc::thermodynamics::isotensional::SWFJC::init(1, link_length, hinge_mass, well_width))
+        }
+    }
+}
+
+/// The structure of the heterogeneous chain model, a chain of consecutive blocks of identical links.
+pub struct Heterogeneous
+{
+    /// The blocks of the chain, each a number of links and their type.
+    pub blocks: Vec<(u8, Link)>,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The thermodynamic functions of the model.
+    pub thermodynamics: thermodynamics::Heterogeneous
+}
+
+/// Returns the total number of links in the blocks, or an error if the chain has more than 255 links.
+pub fn number_of_links(blocks: &[(u8, Link)]) -> Result<u8, Error>
+{
+    blocks.iter().try_fold(0_u8, |total, (number_of_links, _)| total.checked_add(*number_of_links)).ok_or(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: blocks.iter().map(|(number_of_links, _)| *number_of_links as f64).sum()})
+}
+
+/// The implemented functionality of the heterogeneous chain model.
+impl Heterogeneous
+{
+    /// Initializes and returns an instance of the heterogeneous chain model, given blocks such as `vec![(50, a), (30, b)]` for 50 links of type `a` followed by 30 links of type `b`, which panics if the chain has more than 255 links.
+    pub fn init(blocks: Vec<(u8, Link)>) -> Heterogeneous
+    {
+        Heterogeneous
+        {
+            number_of_links: number_of_links(&blocks).expect(MAXIMUM_NUMBER_OF_LINKS),
+            thermodynamics: thermodynamics::Heterogeneous::init(blocks.clone()),
+            blocks
+        }
+    }
+    /// Initializes and returns an instance of the heterogeneous chain model, or an error if a link is invalid or the chain has no links or more than 255 links.
+    pub fn try_init(blocks: Vec<(u8, Link)>) -> Result<Heterogeneous, Error>
+    {
+        if number_of_links(&blocks)? == 0
+        {
+            return Err(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: 0.0})
+        }
+        for (_, link) in blocks.iter()
+        {
+            link.validate()?;
+        }
+        Ok(Heterogeneous::init(blocks))
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let link_b = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+            let model = Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]);
+            assert_eq!(number_of_links_a + number_of_links_b, model.number_of_links);
+            assert_eq!(number_of_links_a + number_of_links_b, model.thermodynamics.number_of_links);
+            assert_eq!(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)], model.blocks);
+        }
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn number_of_links()
+    {
+        let parameters = Parameters::default();
+        let link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+        assert_eq!(super::number_of_links(&[(200, link), (55, link)]), Ok(u8::MAX));
+        assert_eq!(super::number_of_links(&[(200, link), (200, link)]), Err(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: 400.0}));
+    }
+    #[test]
+    #[should_panic(expected = "a heterogeneous chain has at most 255 links")]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+        let _ = Heterogeneous::init(vec![(200, link), (200, link)]);
+    }
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        let link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+        assert!(Heterogeneous::try_init(vec![(parameters.number_of_links_minimum, link)]).is_ok());
+        assert_eq!(Heterogeneous::try_init(vec![]).err(), Some(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: 0.0}));
+        assert_eq!(Heterogeneous::try_init(vec![(200, link), (100, link)]).err(), Some(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: 300.0}));
+        assert_eq!(Heterogeneous::try_init(vec![(200, link), (200, link)]).err(), Some(Error::InvalidParameter{model: "Heterogeneous", argument: "number_of_links", value: 400.0}));
+        let invalid_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: 0.0};
+        assert_eq!(Heterogeneous::try_init(vec![(parameters.number_of_links_minimum, link), (parameters.number_of_links_minimum, invalid_link)]).err(), Some(Error::InvalidParameter{model: "EFJC", argument: "link_stiffness", value: 0.0}));
+    }
+}
@@ -0,0 +1,107 @@
+mod test;
+use crate::physics::Constants;
+use crate::physics::single_chain::
+{
+    Isometric,
+    heterogeneous::
+    {
+        Link,
+        MAXIMUM_NUMBER_OF_LINKS,
+        number_of_links,
+        thermodynamics::isotensional
+    }
+};
+
+/// The structure of the thermodynamics of the heterogeneous chain model in the isometric ensemble approximated using a Legendre transformation.
+pub struct Heterogeneous
+{
+    /// The blocks of the chain, each a number of links and their type.
+    pub blocks: Vec<(u8, Link)>,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    number_of_links_f64: f64,
+
+    maximum_end_to_end_length: f64,
+
+    isotensional: isotensional::Heterogeneous
+}
+
+/// The implemented functionality of the thermodynamics of the heterogeneous chain model in the isometric ensemble approximated using a Legendre transformation.
+impl Heterogeneous
+{
+    /// Initializes and returns an instance of the thermodynamics of the heterogeneous chain model in the isometric ensemble approximated using a Legendre transformation.
+    pub fn init(blocks: Vec<(u8, Link)>) -> Heterogeneous
+    {
+        let number_of_links = number_of_links(&blocks).expect(MAXIMUM_NUMBER_OF_LINKS);
+        Heterogeneous
+        {
+            number_of_links,
+            number_of_links_f64: number_of_links as f64,
+            maximum_end_to_end_length: blocks.iter().map(|(number_of_links, link)| (*number_of_links as f64)*link.maximum_link_length()).sum(),
+            isotensional: isotensional::Heterogeneous::init(blocks.clone()),
+            blocks
+        }
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.isotensional.force(end_to_end_length, temperature)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.isotensional.end_to_end_length(force, temperature)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.isotensional.gibbs_free_energy(&force, temperature) + force*end_to_end_length
+    }
+    /// The helmholtz free energy per link as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.helmholtz_free_energy(end_to_end_length, temperature)/self.number_of_links_f64
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.isotensional.relative_gibbs_free_energy(&force, temperature) + force*end_to_end_length
+    }
+    /// The relative helmholtz free energy per link as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.relative_helmholtz_free_energy(end_to_end_length, temperature)/self.number_of_links_f64
+    }
+}
+
+impl Isometric for Heterogeneous
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.maximum_end_to_end_length
+    }
+    fn name(&self) -> &'static str
+    {
+        "Heterogeneous"
+    }
+    fn constants(&self) -> Constants
+    {
+        Constants::default()
+    }
+}
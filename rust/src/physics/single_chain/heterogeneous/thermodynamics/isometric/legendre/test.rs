@@ -0,0 +1,88 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, Heterogeneous::init(vec![(number_of_links, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]).number_of_links);
+        }
+    }
+    #[test]
+    fn maximum_end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let link_b = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+            let link_c = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+            let maximum = (number_of_links_a as f64)*parameters.link_length_reference + (number_of_links_b as f64)*(parameters.link_length_reference + parameters.well_width_reference);
+            assert!((Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]).maximum_end_to_end_length()/maximum - 1.0).abs() <= parameters.rel_tol);
+            assert!(Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_c)]).maximum_end_to_end_length().is_infinite());
+        }
+    }
+}
+mod legendre
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn inverse()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let link_b = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+            let model = Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = model.maximum_end_to_end_length()*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((model.isotensional.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.helmholtz_free_energy(&end_to_end_length, &temperature) - model.isotensional.gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.abs_tol);
+            assert!((model.relative_helmholtz_free_energy_per_link(&end_to_end_length, &temperature)*(model.number_of_links as f64) - model.relative_helmholtz_free_energy(&end_to_end_length, &temperature)).abs() <= parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let link_b = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+            let model = Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let contour_length = ((number_of_links_a + number_of_links_b) as f64)*parameters.link_length_reference;
+            let end_to_end_length = contour_length*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let h = parameters.rel_tol*contour_length;
+            let derivative = (model.relative_helmholtz_free_energy(&(end_to_end_length + 0.5*h), &temperature) - model.relative_helmholtz_free_energy(&(end_to_end_length - 0.5*h), &temperature))/h;
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((derivative/force - 1.0).abs() <= parameters.rel_tol.sqrt());
+        }
+    }
+}
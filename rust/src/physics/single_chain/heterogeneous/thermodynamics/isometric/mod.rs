@@ -0,0 +1,39 @@
+mod test;
+
+/// The thermodynamics of the heterogeneous chain model in the isometric ensemble approximated using a Legendre transformation.
+pub mod legendre;
+
+use crate::physics::single_chain::heterogeneous::
+{
+    Link,
+    MAXIMUM_NUMBER_OF_LINKS,
+    number_of_links
+};
+
+/// The structure of the thermodynamics of the heterogeneous chain model in the isometric ensemble.
+pub struct Heterogeneous
+{
+    /// The blocks of the chain, each a number of links and their type.
+    pub blocks: Vec<(u8, Link)>,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The thermodynamic functions of the model in the isometric ensemble approximated using a Legendre transformation.
+    pub legendre: legendre::Heterogeneous
+}
+
+/// The implemented functionality of the thermodynamics of the heterogeneous chain model in the isometric ensemble.
+impl Heterogeneous
+{
+    /// Initializes and returns an instance of the thermodynamics of the heterogeneous chain model in the isometric ensemble.
+    pub fn init(blocks: Vec<(u8, Link)>) -> Heterogeneous
+    {
+        Heterogeneous
+        {
+            number_of_links: number_of_links(&blocks).expect(MAXIMUM_NUMBER_OF_LINKS),
+            legendre: legendre::Heterogeneous::init(blocks.clone()),
+            blocks
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Heterogeneous::init(vec![(number_of_links, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+            assert_eq!(number_of_links, model.number_of_links);
+            assert_eq!(number_of_links, model.legendre.number_of_links);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+mod test;
+use crate::physics::Constants;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::
+{
+    Isotensional,
+    heterogeneous::
+    {
+        Link,
+        MAXIMUM_NUMBER_OF_LINKS,
+        number_of_links
+    }
+};
+
+/// The structure of the thermodynamics of the heterogeneous chain model in the isotensional ensemble.
+///
+/// The isotensional partition function factorizes over the links, so each function is the exact sum of the single-link functions over the blocks.
+pub struct Heterogeneous
+{
+    /// The blocks of the chain, each a number of links and their type.
+    pub blocks: Vec<(u8, Link)>,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    number_of_links_f64: f64,
+
+    average_link_length: f64,
+
+    maximum_end_to_end_length: f64,
+
+    links: Vec<(f64, Box<dyn Isotensional>)>
+}
+
+/// The implemented functionality of the thermodynamics of the heterogeneous chain model in the isotensional ensemble.
+impl Heterogeneous
+{
+    /// Initializes and returns an instance of the thermodynamics of the heterogeneous chain model in the isotensional ensemble.
+    pub fn init(blocks: Vec<(u8, Link)>) -> Heterogeneous
+    {
+        let number_of_links = number_of_links(&blocks).expect(MAXIMUM_NUMBER_OF_LINKS);
+        Heterogeneous
+        {
+            number_of_links,
+            number_of_links_f64: number_of_links as f64,
+            average_link_length: blocks.iter().map(|(number_of_links, link)| (*number_of_links as f64)*link.link_length()).sum::<f64>()/(number_of_links as f64),
+            maximum_end_to_end_length: blocks.iter().map(|(number_of_links, link)| (*number_of_links as f64)*link.maximum_link_length()).sum(),
+            links: blocks.iter().map(|(number_of_links, link)| (*number_of_links as f64, link.isotensional())).collect(),
+            blocks
+        }
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.links.iter().map(|(number_of_links, link)| number_of_links*link.end_to_end_length(force, temperature)).sum()
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature.
+    pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)/self.number_of_links_f64
+    }
+    /// The force as a function of the expected end-to-end length and temperature, which is not a number at or beyond the maximum end-to-end length of the chain.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if end_to_end_length.abs() >= self.maximum_end_to_end_length
+        {
+            return f64::NAN
+        }
+        if end_to_end_length < &0.0
+        {
+            return -self.force(&(-end_to_end_length), temperature)
+        }
+        let function = |force: &f64| self.end_to_end_length(force, temperature) - end_to_end_length;
+        let (lower, upper) = bracket(function, &(3.0*end_to_end_length/self.number_of_links_f64/self.average_link_length.powi(2)*BOLTZMANN_CONSTANT*temperature), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.links.iter().map(|(number_of_links, link)| number_of_links*link.gibbs_free_energy(force, temperature)).sum()
+    }
+    /// The gibbs free energy per link as a function of the applied force and temperature.
+    pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.gibbs_free_energy(force, temperature)/self.number_of_links_f64
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.links.iter().map(|(number_of_links, link)| number_of_links*link.relative_gibbs_free_energy(force, temperature)).sum()
+    }
+    /// The relative gibbs free energy per link as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature)/self.number_of_links_f64
+    }
+}
+
+impl Isotensional for Heterogeneous
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Heterogeneous::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "Heterogeneous"
+    }
+    fn constants(&self) -> Constants
+    {
+        Constants::default()
+    }
+}
@@ -0,0 +1,186 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::
+{
+    fjc::thermodynamics::isotensional::FJC,
+    efjc::thermodynamics::isotensional::EFJC,
+    swfjc::thermodynamics::isotensional::SWFJC
+};
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, Heterogeneous::init(vec![(number_of_links, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]).number_of_links);
+        }
+    }
+}
+mod homogeneous
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn fjc()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Heterogeneous::init(vec![(number_of_links, Link::FJC{hinge_mass, link_length})]);
+            let reference = FJC::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.end_to_end_length_per_link(&force, &temperature)/reference.end_to_end_length_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/reference.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy_per_link(&force, &temperature)/reference.relative_gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn efjc()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = Heterogeneous::init(vec![(number_of_links, Link::EFJC{hinge_mass, link_length, link_stiffness})]);
+            let reference = EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy_per_link(&force, &temperature)/reference.gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/reference.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn swfjc()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let well_width = parameters.well_width_reference + parameters.well_width_scale*(0.5 - rng.gen::<f64>());
+            let model = Heterogeneous::init(vec![(number_of_links, Link::SWFJC{hinge_mass, link_length, well_width})]);
+            let reference = SWFJC::init(number_of_links, link_length, hinge_mass, well_width);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/reference.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/reference.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod blocks
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn additive()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+            let link_b = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+            let model = Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]);
+            let model_a = Heterogeneous::init(vec![(number_of_links_a, link_a)]);
+            let model_b = Heterogeneous::init(vec![(number_of_links_b, link_b)]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            assert!((model.end_to_end_length(&force, &temperature)/(model_a.end_to_end_length(&force, &temperature) + model_b.end_to_end_length(&force, &temperature)) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/(model_a.gibbs_free_energy(&force, &temperature) + model_b.gibbs_free_energy(&force, &temperature)) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/(model_a.relative_gibbs_free_energy(&force, &temperature) + model_b.relative_gibbs_free_energy(&force, &temperature)) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn sequence()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_a = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let link_b = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+            let sequence = (0..parameters.number_of_links_maximum).map(|_| (1, if rng.gen::<bool>() {link_a} else {link_b})).collect::<Vec<(u8, Link)>>();
+            let number_of_links_a = sequence.iter().filter(|(_, link)| link == &link_a).count() as u8;
+            let model = Heterogeneous::init(sequence);
+            let model_blocks = Heterogeneous::init(vec![(number_of_links_a, link_a), (parameters.number_of_links_maximum - number_of_links_a, link_b)]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            assert!((model.end_to_end_length(&force, &temperature)/model_blocks.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/model_blocks.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let well_width = parameters.well_width_reference + parameters.well_width_scale*(0.5 - rng.gen::<f64>());
+            let model = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass, link_length}), (parameters.number_of_links_minimum, Link::SWFJC{hinge_mass, link_length, well_width})]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn beyond_maximum_end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let well_width = parameters.well_width_reference + parameters.well_width_scale*(0.5 - rng.gen::<f64>());
+            let model = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass, link_length}), (parameters.number_of_links_minimum, Link::SWFJC{hinge_mass, link_length, well_width})]);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = (parameters.number_of_links_minimum as f64)*(2.0*link_length + well_width)*(1.0 + rng.gen::<f64>());
+            assert!(model.force(&end_to_end_length, &temperature).is_nan());
+            assert!(model.force(&(-end_to_end_length), &temperature).is_nan());
+        }
+    }
+}
@@ -0,0 +1,46 @@
+mod test;
+
+/// The thermodynamics of the heterogeneous chain model in the isometric ensemble.
+pub mod isometric;
+
+/// The thermodynamics of the heterogeneous chain model in the isotensional ensemble.
+pub mod isotensional;
+
+use super::
+{
+    Link,
+    MAXIMUM_NUMBER_OF_LINKS,
+    number_of_links
+};
+
+/// The structure of the thermodynamics of the heterogeneous chain model.
+pub struct Heterogeneous
+{
+    /// The blocks of the chain, each a number of links and their type.
+    pub blocks: Vec<(u8, Link)>,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The thermodynamic functions of the model in the isometric ensemble.
+    pub isometric: isometric::Heterogeneous,
+
+    /// The thermodynamic functions of the model in the isotensional ensemble.
+    pub isotensional: isotensional::Heterogeneous
+}
+
+/// The implemented functionality of the thermodynamics of the heterogeneous chain model.
+impl Heterogeneous
+{
+    /// Initializes and returns an instance of the thermodynamics of the heterogeneous chain model.
+    pub fn init(blocks: Vec<(u8, Link)>) -> Heterogeneous
+    {
+        Heterogeneous
+        {
+            number_of_links: number_of_links(&blocks).expect(MAXIMUM_NUMBER_OF_LINKS),
+            isometric: isometric::Heterogeneous::init(blocks.clone()),
+            isotensional: isotensional::Heterogeneous::init(blocks.clone()),
+            blocks
+        }
+    }
+}
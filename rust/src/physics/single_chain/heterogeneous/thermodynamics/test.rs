@@ -0,0 +1,31 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Heterogeneous::init(vec![(parameters.number_of_links_minimum, Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference})]);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links_a: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let number_of_links_b: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_a = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+            let link_b = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let model = Heterogeneous::init(vec![(number_of_links_a, link_a), (number_of_links_b, link_b)]);
+            assert_eq!(number_of_links_a + number_of_links_b, model.number_of_links);
+            assert_eq!(number_of_links_a + number_of_links_b, model.isometric.number_of_links);
+            assert_eq!(number_of_links_a + number_of_links_b, model.isotensional.number_of_links);
+        }
+    }
+}
@@ -1,22 +1,65 @@
 mod test;
 pub mod thermodynamics;
+pub mod scattering;
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
 pub struct Ideal
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
-    pub thermodynamics: thermodynamics::Ideal
+    pub constants: Constants,
+    pub thermodynamics: thermodynamics::Ideal,
+    pub scattering: scattering::Ideal
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameters<F = f64>
+{
+    pub number_of_links: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Constants,
+    pub link_length: F,
+    pub hinge_mass: F
 }
 impl Ideal
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
     {
         Ideal
         {
             hinge_mass,
             link_length,
             number_of_links,
-            thermodynamics: thermodynamics::Ideal::init(number_of_links, link_length, hinge_mass),
+            constants,
+            thermodynamics: thermodynamics::Ideal::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            scattering: scattering::Ideal::init_with_constants(number_of_links, link_length, hinge_mass, constants)
+        }
+    }
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Ideal, Error>
+    {
+        minimum_number_of_links("Ideal", &number_of_links, 1)?;
+        positive("Ideal", "link_length", &link_length)?;
+        positive("Ideal", "hinge_mass", &hinge_mass)?;
+        Ok(Ideal::init(number_of_links, link_length, hinge_mass))
+    }
+    pub fn parameters(&self) -> Parameters
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: self.link_length,
+            hinge_mass: self.hinge_mass
         }
     }
 }
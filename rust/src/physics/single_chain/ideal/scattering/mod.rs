@@ -0,0 +1,76 @@
+mod test;
+
+use crate::math::complex::Complex;
+use crate::physics::Constants;
+
+/// The structure of the scattering functions of the ideal chain model.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64
+}
+
+/// The implemented functionality of the scattering functions of the ideal chain model.
+impl Ideal
+{
+    /// Initializes and returns an instance of the scattering functions of the ideal chain model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the scattering functions of the ideal chain model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64
+        }
+    }
+    /// The form factor (the Debye function) as a function of the wavenumber in units of 1/nm.
+    pub fn form_factor(&self, wavenumber: &f64) -> f64
+    {
+        self.nondimensional_form_factor(&(wavenumber*self.link_length))
+    }
+    /// The form factor (the Debye function) as a function of the nondimensional wavenumber.
+    pub fn nondimensional_form_factor(&self, nondimensional_wavenumber: &f64) -> f64
+    {
+        debye(&Complex::from(self.number_of_links_f64*nondimensional_wavenumber.powi(2)/6.0)).re
+    }
+    /// The form factor of the chain under an applied force as a function of the wavenumber, the angle between the scattering vector and the force, the force, and the temperature.
+    pub fn anisotropic_form_factor(&self, wavenumber: &f64, angle: &f64, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_anisotropic_form_factor(&(wavenumber*self.link_length), angle, &(force*self.link_length/self.constants.boltzmann_constant/temperature))
+    }
+    /// The form factor of the chain under an applied force as a function of the nondimensional wavenumber, the angle between the scattering vector and the force, and the nondimensional force.
+    pub fn nondimensional_anisotropic_form_factor(&self, nondimensional_wavenumber: &f64, angle: &f64, nondimensional_force: &f64) -> f64
+    {
+        debye(&Complex::new(self.number_of_links_f64*nondimensional_wavenumber.powi(2)/6.0, -self.number_of_links_f64*nondimensional_wavenumber*angle.cos()*nondimensional_force/3.0)).re
+    }
+}
+
+fn debye(x: &Complex) -> Complex
+{
+    if x.norm() < 1e-1
+    {
+        Complex::from(1.0) - *x*(Complex::from(1.0/3.0) - *x*(Complex::from(1.0/12.0) - *x*(Complex::from(1.0/60.0) - *x*(Complex::from(1.0/360.0) - *x*Complex::from(1.0/2520.0)))))
+    }
+    else
+    {
+        Complex::from(2.0)*((-*x).exp() + *x - Complex::from(1.0))/x.powi(2)
+    }
+}
@@ -58,3 +58,62 @@ mod base
         }
     }
 }
+mod error
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(Ideal::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(Ideal::try_init(0, parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "Ideal", argument: "number_of_links", value: 0.0}));
+        assert_eq!(Ideal::try_init(parameters.number_of_links_minimum, f64::INFINITY, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "Ideal", argument: "link_length", value: f64::INFINITY}));
+    }
+}
+mod parameters
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).parameters();
+        assert_eq!(Ideal::init(model_parameters.number_of_links, model_parameters.link_length, model_parameters.hinge_mass).parameters(), model_parameters);
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).parameters();
+        let json = serde_json::to_string(&model_parameters).unwrap();
+        assert_eq!(serde_json::from_str::<super::super::Parameters>(&json).unwrap(), model_parameters);
+    }
+}
+mod constants
+{
+    use super::*;
+    use crate::physics::Constants;
+    use crate::physics::single_chain::test::check_constants;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let constants = Constants::molecular();
+        let model = Ideal::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, constants);
+        assert_eq!(model.constants, constants);
+        assert_eq!(model.thermodynamics.isotensional.constants, constants);
+        assert_eq!(model.parameters().constants, constants);
+        assert_eq!(Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).constants, Constants::molar());
+    }
+    #[test]
+    fn conventions()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let molar = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isotensional;
+        let molecular = Ideal::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Constants::molecular()).thermodynamics.isotensional;
+        let reduced = Ideal::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, Constants::reduced(&temperature)).thermodynamics.isotensional;
+        check_constants(&molar, &molecular, &reduced, &temperature, &parameters.rel_tol);
+    }
+}
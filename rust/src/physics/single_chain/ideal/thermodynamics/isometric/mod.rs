@@ -1,46 +1,63 @@
 mod test;
 use std::f64::consts::PI;
-use crate::physics::
+use crate::physics::Constants;
+use crate::physics::single_chain::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    Isometric,
+    ln_hinge_partition_function
 };
+use crate::physics::single_chain::ideal::Parameters;
+use crate::math::float::Float;
 pub struct Ideal
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     number_of_links_f64: f64,
     contour_length: f64
 }
 impl Ideal
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
     {
         Ideal
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             number_of_links_f64: number_of_links as f64,
             contour_length: (number_of_links as f64)*link_length
         }
     }
     pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        3.0*end_to_end_length*BOLTZMANN_CONSTANT*temperature/self.number_of_links_f64/self.link_length.powi(2)
+        3.0*end_to_end_length*self.constants.boltzmann_constant*temperature/self.number_of_links_f64/self.link_length.powi(2)
     }
     pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
         3.0*nondimensional_end_to_end_length_per_link
     }
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.number_of_links_f64*force/self.constants.boltzmann_constant/temperature*self.link_length.powi(2)/3.0
+    }
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_force/3.0
+    }
     pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
         self.helmholtz_free_energy_per_link(end_to_end_length, temperature)*self.number_of_links_f64
     }
     pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
@@ -48,15 +65,15 @@ impl Ideal
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        1.5*self.number_of_links_f64*nondimensional_end_to_end_length_per_link.powi(2) - self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        1.5*self.number_of_links_f64*nondimensional_end_to_end_length_per_link.powi(2) - self.number_of_links_f64*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
     {
-        1.5*nondimensional_end_to_end_length_per_link.powi(2) - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        1.5*nondimensional_end_to_end_length_per_link.powi(2) - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
     {
@@ -83,3 +100,49 @@ impl Ideal
         4.0*PI*nondimensional_end_to_end_length_per_link.powi(2)*(1.5/PI*self.number_of_links_f64).powf(1.5)*(-1.5*nondimensional_end_to_end_length_per_link.powi(2)*self.number_of_links_f64).exp()
     }
 }
+impl Isometric for Ideal
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        f64::INFINITY
+    }
+    fn name(&self) -> &'static str
+    {
+        "Ideal"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the ideal model in the isometric ensemble, with the parameters, end-to-end length, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        F::from_f64(3.0*self.constants.boltzmann_constant/self.number_of_links as f64)**end_to_end_length**temperature/self.link_length.powi(2)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        self.relative_helmholtz_free_energy(end_to_end_length, temperature) - F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        F::from_f64(1.5*self.constants.boltzmann_constant/self.number_of_links as f64)**temperature*(*end_to_end_length/self.link_length).powi(2)
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
 use crate::physics::single_chain::test::Parameters as DefaultParameters;
 pub struct Parameters
 {
@@ -559,4 +560,89 @@ mod zero
             assert!(nondimensional_equilibrium_radial_distribution_0.abs() <= 0.0);
         }
     }
-}
\ No newline at end of file
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn nondimensional_end_to_end_length_per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, hinge_mass);
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = model.nondimensional_force(&nondimensional_end_to_end_length_per_link);
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force)/nondimensional_end_to_end_length_per_link - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((model.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod dual
+{
+    use super::*;
+    use rand::Rng;
+    use crate::physics::single_chain::ideal::Parameters as ModelParameters;
+    use crate::physics::single_chain::test::check_gradient;
+    #[test]
+    fn value()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, hinge_mass);
+            let model_parameters = ModelParameters {number_of_links, constants: Constants::default(), link_length, hinge_mass};
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            assert!((model_parameters.force(&end_to_end_length, &temperature)/model.force(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.helmholtz_free_energy(&end_to_end_length, &temperature)/model.helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model_parameters.relative_helmholtz_free_energy(&end_to_end_length, &temperature)/model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn gradient()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = DefaultParameters::default().nondimensional_end_to_end_length_per_link_small + parameters.nondimensional_end_to_end_length_per_link_reference*rng.gen::<f64>();
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = nondimensional_end_to_end_length_per_link*(number_of_links as f64)*link_length;
+            let point = [link_length, hinge_mass, end_to_end_length, temperature];
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.force(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.force(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.helmholtz_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.helmholtz_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
+            check_gradient(|x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_helmholtz_free_energy(&x[2], &x[3]), |x| ModelParameters {number_of_links, constants: Constants::default(), link_length: x[0], hinge_mass: x[1]}.relative_helmholtz_free_energy(&x[2], &x[3]), &point, &parameters.rel_tol);
+        }
+    }
+}
@@ -1,36 +1,44 @@
 mod test;
-use std::f64::consts::PI;
-use crate::physics::
+use crate::physics::Constants;
+use crate::physics::single_chain::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    Isotensional,
+    ln_hinge_partition_function
 };
+use crate::physics::single_chain::ideal::Parameters;
+use crate::math::float::Float;
 pub struct Ideal
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     number_of_links_f64: f64
 }
 impl Ideal
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
     {
         Ideal
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             number_of_links_f64: number_of_links as f64
         }
     }
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.number_of_links_f64*force/BOLTZMANN_CONSTANT/temperature*self.link_length.powi(2)/3.0
+        self.number_of_links_f64*force/self.constants.boltzmann_constant/temperature*self.link_length.powi(2)/3.0
     }
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        force/BOLTZMANN_CONSTANT/temperature*self.link_length.powi(2)/3.0
+        force/self.constants.boltzmann_constant/temperature*self.link_length.powi(2)/3.0
     }
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64) -> f64
     {
@@ -40,29 +48,37 @@ impl Ideal
     {
         nondimensional_force/3.0
     }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        3.0*end_to_end_length*self.constants.boltzmann_constant*temperature/self.number_of_links_f64/self.link_length.powi(2)
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        3.0*nondimensional_end_to_end_length_per_link
+    }
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        -self.number_of_links_f64*((force*self.link_length).powi(2)/6.0/BOLTZMANN_CONSTANT/temperature + BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln())
+        -self.number_of_links_f64*((force*self.link_length).powi(2)/6.0/self.constants.boltzmann_constant/temperature + self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants))
     }
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        -(force*self.link_length).powi(2)/6.0/BOLTZMANN_CONSTANT/temperature - BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -(force*self.link_length).powi(2)/6.0/self.constants.boltzmann_constant/temperature - self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        -self.number_of_links_f64*(force*self.link_length).powi(2)/6.0/BOLTZMANN_CONSTANT/temperature
+        -self.number_of_links_f64*(force*self.link_length).powi(2)/6.0/self.constants.boltzmann_constant/temperature
     }
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        -(force*self.link_length).powi(2)/6.0/BOLTZMANN_CONSTANT/temperature
+        -(force*self.link_length).powi(2)/6.0/self.constants.boltzmann_constant/temperature
     }
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -self.number_of_links_f64*nondimensional_force.powi(2)/6.0 - self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -self.number_of_links_f64*nondimensional_force.powi(2)/6.0 - self.number_of_links_f64*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        -nondimensional_force.powi(2)/6.0 - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -nondimensional_force.powi(2)/6.0 - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
     {
@@ -73,3 +89,45 @@ impl Ideal
         -nondimensional_force.powi(2)/6.0
     }
 }
+impl Isotensional for Ideal
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "Ideal"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the ideal model in the isotensional ensemble, with the parameters, force, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &F, temperature: &F) -> F
+    {
+        F::from_f64(self.number_of_links as f64)**force/F::from_f64(self.constants.boltzmann_constant)/ *temperature*self.link_length.powi(2)/F::from_f64(3.0)
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        self.relative_gibbs_free_energy(force, temperature) - F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        -F::from_f64(self.number_of_links as f64)*(*force*self.link_length).powi(2)/F::from_f64(6.0*self.constants.boltzmann_constant)/ *temperature
+    }
+}
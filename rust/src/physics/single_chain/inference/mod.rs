@@ -0,0 +1,264 @@
+mod test;
+use std::f64::consts::PI;
+use crate::error::Error;
+use crate::math::random::Random;
+use crate::math::sampling::
+{
+    ensemble,
+    stepping_stone
+};
+use crate::physics::single_chain::fitting::
+{
+    Data,
+    Fit,
+    fit,
+    residuals
+};
+
+/// The prior distributions of the parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Prior
+{
+    /// The uniform distribution from the minimum to the maximum.
+    Uniform
+    {
+        /// The smallest value.
+        minimum: f64,
+        /// The largest value.
+        maximum: f64
+    },
+    /// The normal distribution with the mean and standard deviation.
+    Normal
+    {
+        /// The mean of the distribution.
+        mean: f64,
+        /// The standard deviation of the distribution.
+        standard_deviation: f64
+    },
+    /// The log-normal distribution, the logarithm of which is normally distributed about the logarithm of the median with the standard deviation.
+    LogNormal
+    {
+        /// The median of the distribution.
+        median: f64,
+        /// The standard deviation of the logarithm.
+        standard_deviation: f64
+    }
+}
+
+/// The implemented functionality of prior distributions.
+impl Prior
+{
+    /// The logarithm of the normalized density at the value, which is negative infinity outside of the support.
+    pub fn ln_density(&self, value: &f64) -> f64
+    {
+        match *self
+        {
+            Prior::Uniform{minimum, maximum} => if value >= &minimum && value <= &maximum {-(maximum - minimum).ln()} else {f64::NEG_INFINITY},
+            Prior::Normal{mean, standard_deviation} => -0.5*((value - mean)/standard_deviation).powi(2) - (standard_deviation*(2.0*PI).sqrt()).ln(),
+            Prior::LogNormal{median, standard_deviation} => if value > &0.0 {-0.5*((value.ln() - median.ln())/standard_deviation).powi(2) - (value*standard_deviation*(2.0*PI).sqrt()).ln()} else {f64::NEG_INFINITY}
+        }
+    }
+    /// Returns a value drawn from the distribution.
+    pub fn sample(&self, random: &mut Random) -> f64
+    {
+        match *self
+        {
+            Prior::Uniform{minimum, maximum} => minimum + (maximum - minimum)*random.uniform(),
+            Prior::Normal{mean, standard_deviation} => mean + standard_deviation*random.normal(),
+            Prior::LogNormal{median, standard_deviation} => median*(standard_deviation*random.normal()).exp()
+        }
+    }
+}
+
+/// The structure of the settings of the affine-invariant ensemble sampler.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sampler
+{
+    /// The number of walkers in the ensemble.
+    pub walkers: usize,
+
+    /// The number of steps taken by each walker.
+    pub steps: usize,
+
+    /// The number of initial steps discarded from the samples.
+    pub burn_in: usize,
+
+    /// The seed of the pseudorandom number generator.
+    pub seed: u64
+}
+
+/// The implemented functionality of the settings of the sampler.
+impl Sampler
+{
+    /// Initializes and returns the settings of the sampler.
+    pub fn init(walkers: usize, steps: usize, burn_in: usize, seed: u64) -> Sampler
+    {
+        Sampler
+        {
+            walkers,
+            steps,
+            burn_in,
+            seed
+        }
+    }
+}
+
+/// The structure of samples of the posterior distribution of the parameters of a model given force-extension data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Posterior
+{
+    /// The names of the parameters, starting with the number of links and ending with the standard deviation of the noise.
+    pub names: Vec<&'static str>,
+
+    /// The samples of the parameters after the burn-in, where the number of links is relaxed to be continuous.
+    pub samples: Vec<Vec<f64>>,
+
+    /// The logarithm of the unnormalized posterior density at each sample.
+    pub ln_posterior: Vec<f64>,
+
+    /// The fraction of proposed moves that were accepted.
+    pub acceptance_fraction: f64
+}
+
+/// The implemented functionality of samples of posterior distributions.
+impl Posterior
+{
+    /// The posterior means of the parameters.
+    pub fn means(&self) -> Vec<f64>
+    {
+        (0..self.names.len()).map(|index| self.samples.iter().map(|sample| sample[index]).sum::<f64>()/(self.samples.len() as f64)).collect()
+    }
+    /// The posterior standard deviations of the parameters.
+    pub fn standard_deviations(&self) -> Vec<f64>
+    {
+        self.means().iter().enumerate().map(|(index, mean)| (self.samples.iter().map(|sample| (sample[index] - mean).powi(2)).sum::<f64>()/(self.samples.len() as f64)).sqrt()).collect()
+    }
+    /// The equal-tailed credible intervals of the parameters containing the posterior probability.
+    pub fn credible_intervals(&self, probability: &f64) -> Vec<(f64, f64)>
+    {
+        (0..self.names.len()).map(|index|
+        {
+            let mut marginal: Vec<f64> = self.samples.iter().map(|sample| sample[index]).collect();
+            marginal.sort_by(f64::total_cmp);
+            (quantile(&marginal, &(0.5*(1.0 - probability))), quantile(&marginal, &(0.5*(1.0 + probability))))
+        }).collect()
+    }
+    /// The sample with the largest posterior density.
+    pub fn maximum(&self) -> &[f64]
+    {
+        let index = (0..self.samples.len()).max_by(|a, b| self.ln_posterior[*a].total_cmp(&self.ln_posterior[*b])).expect("there is at least one sample");
+        &self.samples[index]
+    }
+    /// The posterior predictive curves of the extensions or forces that the model predicts at the measurements, for every given number of samples.
+    ///
+    /// The curves are those of the model without the noise, so their spread is the uncertainty in the prediction due to the uncertainty in the parameters.
+    pub fn predictive<T: Fit>(&self, model: &T, data: &Data, every: usize) -> Vec<Vec<f64>>
+    {
+        let observations: Vec<f64> = data.extension.iter().zip(data.force.iter()).map(|(extension, force)| model.observation(extension, force)).collect();
+        self.samples.iter().step_by(every.max(1)).map(|sample| residuals(model, data, &sample[..sample.len() - 1]).iter().zip(observations.iter()).map(|(residual, observation)| residual + observation).collect()).collect()
+    }
+}
+
+/// Returns samples of the posterior distribution of the parameters of the model given the data, using the affine-invariant ensemble sampler.
+///
+/// The parameters are the number of links, relaxed to be continuous, the parameters that are fit for the model, and the standard deviation of the independent normal noise in the residuals, with a prior for each in that order.
+/// The walkers start near the least-squares estimate of the parameters when it converges, and are drawn from the priors otherwise.
+/// An error is returned if the number of priors differs from the number of parameters, if there are not more walkers than twice the number of parameters, or if the burn-in is not shorter than the chain.
+pub fn sample<T: Fit>(model: &T, data: &Data, priors: &[Prior], sampler: &Sampler) -> Result<Posterior, Error>
+{
+    let names = names(model, priors, sampler)?;
+    let mut random = Random::init(sampler.seed);
+    let walkers = initial_walkers(model, data, priors, sampler, &mut random);
+    let chain = ensemble(|parameters| ln_posterior(model, data, priors, parameters), &walkers, &sampler.steps, &mut random).discard(sampler.walkers, sampler.burn_in);
+    Ok(Posterior
+    {
+        names,
+        samples: chain.samples,
+        ln_posterior: chain.ln_densities,
+        acceptance_fraction: chain.acceptance_fraction
+    })
+}
+
+/// Returns the logarithm of the evidence for the model given the data, the likelihood integrated over the priors, using stepping-stone sampling over the number of inverse temperatures.
+///
+/// Differences in the logarithm of the evidence between models fit to the same data are the logarithms of their Bayes factors, which balance the quality of the fit against the number of parameters and the widths of their priors.
+/// The priors must be proper, and the walkers start from draws of them.
+pub fn ln_evidence<T: Fit>(model: &T, data: &Data, priors: &[Prior], sampler: &Sampler, temperatures: &usize) -> Result<f64, Error>
+{
+    names(model, priors, sampler)?;
+    let mut random = Random::init(sampler.seed);
+    let walkers: Vec<Vec<f64>> = (0..sampler.walkers).map(|_| priors.iter().map(|prior| prior.sample(&mut random)).collect()).collect();
+    Ok(stepping_stone(|parameters| ln_prior(priors, parameters), |parameters| ln_likelihood(model, data, parameters), &walkers, temperatures, &sampler.steps, &sampler.burn_in, &mut random))
+}
+
+fn names<T: Fit>(model: &T, priors: &[Prior], sampler: &Sampler) -> Result<Vec<&'static str>, Error>
+{
+    let mut names = vec!["number_of_links"];
+    names.extend(model.names());
+    names.push("noise");
+    if priors.len() != names.len()
+    {
+        return Err(Error::InvalidParameter{model: "Prior", argument: "priors", value: priors.len() as f64})
+    }
+    if sampler.walkers <= 2*names.len()
+    {
+        return Err(Error::InvalidParameter{model: "Sampler", argument: "walkers", value: sampler.walkers as f64})
+    }
+    if sampler.burn_in >= sampler.steps
+    {
+        return Err(Error::InvalidParameter{model: "Sampler", argument: "burn_in", value: sampler.burn_in as f64})
+    }
+    Ok(names)
+}
+
+fn ln_prior(priors: &[Prior], parameters: &[f64]) -> f64
+{
+    priors.iter().zip(parameters.iter()).map(|(prior, parameter)| prior.ln_density(parameter)).sum()
+}
+
+fn ln_posterior<T: Fit>(model: &T, data: &Data, priors: &[Prior], parameters: &[f64]) -> f64
+{
+    let ln_prior = ln_prior(priors, parameters);
+    if ln_prior.is_finite()
+    {
+        ln_prior + ln_likelihood(model, data, parameters)
+    }
+    else
+    {
+        f64::NEG_INFINITY
+    }
+}
+
+fn ln_likelihood<T: Fit>(model: &T, data: &Data, parameters: &[f64]) -> f64
+{
+    let noise = parameters[parameters.len() - 1];
+    if noise <= 0.0
+    {
+        return f64::NEG_INFINITY
+    }
+    let sum_of_squares: f64 = residuals(model, data, &parameters[..parameters.len() - 1]).iter().map(|residual| residual.powi(2)).sum();
+    let ln_likelihood = -0.5*sum_of_squares/noise.powi(2) - (data.len() as f64)*(noise*(2.0*PI).sqrt()).ln();
+    if ln_likelihood.is_nan() {f64::NEG_INFINITY} else {ln_likelihood}
+}
+
+fn initial_walkers<T: Fit>(model: &T, data: &Data, priors: &[Prior], sampler: &Sampler, random: &mut Random) -> Vec<Vec<f64>>
+{
+    if let Ok(estimate) = fit(model, data)
+    {
+        let mut center = estimate.parameters.clone();
+        center.push(estimate.root_mean_square_residual().max(f64::EPSILON*data.extension.iter().fold(0.0, |maximum, extension| extension.abs().max(maximum))));
+        if ln_prior(priors, &center).is_finite()
+        {
+            return (0..sampler.walkers).map(|_| center.iter().map(|parameter| parameter*(1.0 + 1e-3*random.normal())).collect()).collect()
+        }
+    }
+    (0..sampler.walkers).map(|_| priors.iter().map(|prior| prior.sample(random)).collect()).collect()
+}
+
+fn quantile(sorted: &[f64], probability: &f64) -> f64
+{
+    let position = probability*((sorted.len() - 1) as f64);
+    let lower = position.floor() as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    sorted[lower] + (position - lower as f64)*(sorted[upper] - sorted[lower])
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::
+{
+    Parameters,
+    integrate
+};
+use crate::physics::single_chain::ideal::thermodynamics::isotensional::Ideal as IdealIsotensional;
+use crate::physics::single_chain::fjc::thermodynamics::isotensional::FJC as FJCIsotensional;
+use crate::physics::single_chain::efjc::thermodynamics::isotensional::EFJC as EFJCIsotensional;
+static NOISE: f64 = 5e-2;
+fn noisy_data<T: Fit>(model: &T, link_length: &f64, maximum_nondimensional_force: &f64, seed: u64) -> Data
+{
+    let parameters = Parameters::default();
+    let temperature = parameters.temperature_reference;
+    let mut random = Random::init(seed);
+    let force: Vec<f64> = (1..=32).map(|index| maximum_nondimensional_force*(index as f64)/32.0*BOLTZMANN_CONSTANT*temperature/link_length).collect();
+    let extension = force.iter().map(|force| model.prediction(&0.0, force, &temperature) + NOISE*random.normal()).collect();
+    Data::init(extension, force, vec![temperature]).unwrap()
+}
+fn fjc_priors() -> Vec<Prior>
+{
+    vec![Prior::Uniform{minimum: 1.0, maximum: 5e1}, Prior::Uniform{minimum: 1e-1, maximum: 1e1}, Prior::LogNormal{median: NOISE, standard_deviation: 1.0}]
+}
+mod prior
+{
+    use super::*;
+    #[test]
+    fn normalized()
+    {
+        for prior in [Prior::Uniform{minimum: 1.0, maximum: 3.0}, Prior::Normal{mean: 2.0, standard_deviation: 0.25}, Prior::LogNormal{median: 2.0, standard_deviation: 0.25}]
+        {
+            let integral = integrate(|value| prior.ln_density(&value).exp(), &0.0, &8.0, &100000);
+            assert!((integral - 1.0).abs() <= 1e-6);
+        }
+    }
+    #[test]
+    fn sample()
+    {
+        let mut random = Random::init(8);
+        for (prior, mean) in [(Prior::Uniform{minimum: 1.0, maximum: 3.0}, 2.0), (Prior::Normal{mean: 2.0, standard_deviation: 0.25}, 2.0), (Prior::LogNormal{median: 2.0, standard_deviation: 0.25}, 2.0*(0.5*0.25_f64.powi(2)).exp())]
+        {
+            let samples: Vec<f64> = (0..88888).map(|_| prior.sample(&mut random)).collect();
+            assert!(samples.iter().all(|sample| prior.ln_density(sample).is_finite()));
+            assert!((samples.iter().sum::<f64>()/(samples.len() as f64) - mean).abs() <= 1e-2);
+        }
+    }
+}
+mod invalid
+{
+    use super::*;
+    #[test]
+    fn priors()
+    {
+        let parameters = Parameters::default();
+        let model = FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let data = noisy_data(&model, &parameters.link_length_reference, &1e1, 8);
+        assert_eq!(sample(&model, &data, &fjc_priors()[..2], &Sampler::init(16, 8, 4, 8)), Err(Error::InvalidParameter{model: "Prior", argument: "priors", value: 2.0}));
+    }
+    #[test]
+    fn sampler()
+    {
+        let parameters = Parameters::default();
+        let model = FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let data = noisy_data(&model, &parameters.link_length_reference, &1e1, 8);
+        assert_eq!(sample(&model, &data, &fjc_priors(), &Sampler::init(6, 8, 4, 8)), Err(Error::InvalidParameter{model: "Sampler", argument: "walkers", value: 6.0}));
+        assert_eq!(sample(&model, &data, &fjc_priors(), &Sampler::init(16, 8, 8, 8)), Err(Error::InvalidParameter{model: "Sampler", argument: "burn_in", value: 8.0}));
+    }
+}
+mod posterior
+{
+    use super::*;
+    #[test]
+    fn fjc()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_maximum;
+        let link_length = parameters.link_length_reference;
+        let model = FJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference);
+        let data = noisy_data(&model, &link_length, &1e1, 88);
+        let start = FJCIsotensional::init(number_of_links - 3, 1.1*link_length, parameters.hinge_mass_reference);
+        let posterior = sample(&start, &data, &fjc_priors(), &Sampler::init(16, 1000, 250, 8)).unwrap();
+        assert_eq!(posterior.names, vec!["number_of_links", "link_length", "noise"]);
+        assert_eq!(posterior.samples.len(), 16*750);
+        assert!(posterior.acceptance_fraction > 0.1);
+        let means = posterior.means();
+        let standard_deviations = posterior.standard_deviations();
+        let intervals = posterior.credible_intervals(&0.999);
+        for (index, value) in [number_of_links as f64, link_length, NOISE].iter().enumerate()
+        {
+            assert!((means[index] - value).abs() <= 4.0*standard_deviations[index]);
+            assert!(intervals[index].0 < *value && intervals[index].1 > *value);
+        }
+        assert!(posterior.maximum().len() == 3);
+        let curves = posterior.predictive(&start, &data, 100);
+        assert_eq!(curves.len(), (16*750_usize).div_ceil(100));
+        for curve in curves.iter()
+        {
+            curve.iter().zip(data.extension.iter()).for_each(|(prediction, extension)| assert!((prediction - extension).abs() <= 5.0*NOISE));
+        }
+    }
+    #[test]
+    fn ideal()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_maximum;
+        let link_length = parameters.link_length_reference;
+        let model = IdealIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference);
+        let data = noisy_data(&model, &link_length, &1e0, 888);
+        let posterior = sample(&model, &data, &fjc_priors(), &Sampler::init(16, 1000, 250, 8)).unwrap();
+        let product: Vec<f64> = posterior.samples.iter().map(|sample| sample[0]*sample[1].powi(2)).collect();
+        let mean = product.iter().sum::<f64>()/(product.len() as f64);
+        assert!((mean/((number_of_links as f64)*link_length.powi(2)) - 1.0).abs() <= 5e-2);
+    }
+}
+mod evidence
+{
+    use super::*;
+    #[test]
+    fn efjc_against_fjc()
+    {
+        let parameters = Parameters::default();
+        let number_of_links = parameters.number_of_links_minimum;
+        let link_length = parameters.link_length_reference;
+        let link_stiffness = 1e-1*parameters.link_stiffness_reference;
+        let model = EFJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference, link_stiffness);
+        let data = noisy_data(&model, &link_length, &2e1, 8888);
+        let sampler = Sampler::init(16, 200, 50, 8);
+        let mut priors = fjc_priors();
+        let ln_evidence_fjc = ln_evidence(&FJCIsotensional::init(number_of_links, link_length, parameters.hinge_mass_reference), &data, &priors, &sampler, &8).unwrap();
+        priors.insert(2, Prior::LogNormal{median: link_stiffness, standard_deviation: 1.0});
+        let ln_evidence_efjc = ln_evidence(&model, &data, &priors, &sampler, &8).unwrap();
+        assert!(ln_evidence_efjc.is_finite());
+        assert!(ln_evidence_efjc - ln_evidence_fjc > 1e1);
+    }
+}
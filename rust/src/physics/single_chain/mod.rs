@@ -12,6 +12,162 @@ pub mod efjc;
 /// The square-well freely-jointed chain (EFJC) single-chain model.
 pub mod swfjc;
 
+/// The heterogeneous chain single-chain model, built from blocks of different types of links.
+pub mod heterogeneous;
+
+/// The two-state freely-jointed chain (TSFJC) single-chain model, with links that unfold between two states.
+pub mod tsfjc;
+
+/// Polydisperse ensembles of single-chain models.
+pub mod polydisperse;
+
+/// Two-dimensional single-chain models of chains adsorbed onto a surface.
+pub mod planar;
+
+/// Single-chain models with one end grafted to an impenetrable planar surface, as in single-molecule pulling experiments.
+pub mod tethered;
+
+/// Single-chain models confined within slits, cylinders, and spherical cavities, as in nanochannel and nanopore experiments.
+pub mod confined;
+
+/// Single-chain models with excluded volume, swelling or collapsing the phantom models according to the quality of the solvent.
+pub mod swollen;
+
+/// Curves of thermodynamic functions evaluated over a range of their arguments.
+pub mod curve;
+
+/// Single-chain models tabulated for fast repeated evaluation by interpolation.
+pub mod tabulated;
+
+/// Fitting of single-chain models to force-extension data using nonlinear least squares.
+pub mod fitting;
+
+/// Bayesian inference of the parameters of single-chain models from force-extension data using Markov chain Monte Carlo.
+pub mod inference;
+
+/// Thermodynamic functions of single-chain models taking and returning quantities with units.
+pub mod units;
+
+/// Parallel evaluation of thermodynamic functions at slices of their arguments.
+#[cfg(feature = "rayon")]
+pub mod batch;
+
+use std::f64::consts::PI;
+use crate::math::float::Float;
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    domain,
+    finite
+};
+
+/// The thermodynamic functions common to single-chain models in the isometric ensemble.
+pub trait Isometric
+{
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64;
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64;
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64;
+    /// The supremum of the end-to-end lengths that can be applied.
+    fn maximum_end_to_end_length(&self) -> f64;
+    /// The name of the model, which identifies it in errors.
+    fn name(&self) -> &'static str;
+    /// The physical constants used by the model, which set its units of energy.
+    fn constants(&self) -> Constants;
+    /// The expected force, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_force(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isometric_domain(self, end_to_end_length, temperature)?;
+        finite(self.name(), "end_to_end_length", end_to_end_length, self.force(end_to_end_length, temperature))
+    }
+    /// The helmholtz free energy, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isometric_domain(self, end_to_end_length, temperature)?;
+        finite(self.name(), "end_to_end_length", end_to_end_length, self.helmholtz_free_energy(end_to_end_length, temperature))
+    }
+    /// The relative helmholtz free energy, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isometric_domain(self, end_to_end_length, temperature)?;
+        finite(self.name(), "end_to_end_length", end_to_end_length, self.relative_helmholtz_free_energy(end_to_end_length, temperature))
+    }
+}
+
+/// The thermodynamic functions common to single-chain models in the isotensional ensemble.
+pub trait Isotensional
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64;
+    /// The gibbs free energy as a function of the applied force and temperature.
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64;
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64;
+    /// The name of the model, which identifies it in errors.
+    fn name(&self) -> &'static str;
+    /// The physical constants used by the model, which set its units of energy.
+    fn constants(&self) -> Constants;
+    /// The expected end-to-end length, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_end_to_end_length(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isotensional_domain(self, force, temperature)?;
+        finite(self.name(), "force", force, self.end_to_end_length(force, temperature))
+    }
+    /// The gibbs free energy, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isotensional_domain(self, force, temperature)?;
+        finite(self.name(), "force", force, self.gibbs_free_energy(force, temperature))
+    }
+    /// The relative gibbs free energy, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> Result<f64, Error>
+    {
+        isotensional_domain(self, force, temperature)?;
+        finite(self.name(), "force", force, self.relative_gibbs_free_energy(force, temperature))
+    }
+}
+
+/// The thermodynamic functions common to single-chain models in the modified canonical ensemble, where the chain end is attached to a harmonic potential.
+pub trait ModifiedCanonical
+{
+    /// The expected end-to-end length as a function of the applied potential distance, potential stiffness, and temperature.
+    fn end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The expected force as a function of the applied potential distance, potential stiffness, and temperature.
+    fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature.
+    fn helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The relative helmholtz free energy as a function of the applied potential distance, potential stiffness, and temperature.
+    fn relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature.
+    fn gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The relative gibbs free energy as a function of the applied potential distance, potential stiffness, and temperature.
+    fn relative_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64;
+    /// The physical constants used by the model, which set its units of energy.
+    fn constants(&self) -> Constants;
+}
+
+fn isometric_domain<T: Isometric + ?Sized>(model: &T, end_to_end_length: &f64, temperature: &f64) -> Result<(), Error>
+{
+    domain(model.name(), "temperature", temperature, &f64::MIN_POSITIVE, &f64::INFINITY)?;
+    domain(model.name(), "end_to_end_length", end_to_end_length, &0.0, &model.maximum_end_to_end_length())
+}
+
+fn isotensional_domain<T: Isotensional + ?Sized>(model: &T, force: &f64, temperature: &f64) -> Result<(), Error>
+{
+    domain(model.name(), "temperature", temperature, &f64::MIN_POSITIVE, &f64::INFINITY)?;
+    domain(model.name(), "force", force, &-f64::MAX, &f64::INFINITY)
+}
+
+/// The logarithm of the partition function of the momentum and orientation of each hinge, which contributes to the absolute free energies, in any floating-point type.
+fn ln_hinge_partition_function<F: Float>(hinge_mass: &F, link_length: &F, temperature: &F, constants: &Constants) -> F
+{
+    (F::from_f64(8.0*PI.powi(2)*constants.boltzmann_constant/constants.planck_constant.powi(2))**hinge_mass*link_length.powi(2)**temperature).ln()
+}
+
 static ONE: f64 = 1.0;
+#[cfg(test)]
 static ZERO: f64 = 1e-6;
 static POINTS: u128 = 100;
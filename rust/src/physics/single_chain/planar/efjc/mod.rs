@@ -0,0 +1,66 @@
+mod test;
+
+/// The thermodynamics of the two-dimensional EFJC model.
+pub mod thermodynamics;
+
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+/// The structure of the two-dimensional extensible freely-jointed chain (EFJC) model, where each link rotates freely in the plane and stretches harmonically.
+pub struct EFJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    /// The thermodynamic functions of the model.
+    pub thermodynamics: thermodynamics::EFJC
+}
+
+/// The implemented functionality of the two-dimensional EFJC model.
+impl EFJC
+{
+    /// Initializes and returns an instance of the two-dimensional EFJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    /// Initializes and returns an instance of the two-dimensional EFJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
+    {
+        EFJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            link_stiffness,
+            thermodynamics: thermodynamics::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants)
+        }
+    }
+    /// Initializes and returns an instance of the two-dimensional EFJC model, or an error if a parameter is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> Result<EFJC, Error>
+    {
+        minimum_number_of_links("EFJC", &number_of_links, 1)?;
+        positive("EFJC", "link_length", &link_length)?;
+        positive("EFJC", "hinge_mass", &hinge_mass)?;
+        positive("EFJC", "link_stiffness", &link_stiffness)?;
+        Ok(EFJC::init(number_of_links, link_length, hinge_mass, link_stiffness))
+    }
+}
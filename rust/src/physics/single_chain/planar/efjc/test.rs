@@ -0,0 +1,14 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(EFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_stiffness_reference).is_ok());
+        assert_eq!(EFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, -1.0).err(), Some(Error::InvalidParameter{model: "EFJC", argument: "link_stiffness", value: -1.0}));
+    }
+}
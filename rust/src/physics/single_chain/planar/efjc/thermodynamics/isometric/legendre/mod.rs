@@ -0,0 +1,128 @@
+//!
+//! These approximations become accurate in the thermodynamic limit of a large number of links in the chain.
+
+mod test;
+
+use crate::physics::
+{
+    Constants,
+    single_chain::Isometric
+};
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::single_chain::planar::efjc::thermodynamics::isotensional;
+
+/// The structure of the thermodynamics of the two-dimensional EFJC model in the isometric ensemble approximated using a Legendre transformation.
+pub struct EFJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64,
+
+    isotensional: isotensional::EFJC
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional EFJC model in the isometric ensemble approximated using a Legendre transformation.
+impl EFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model in the isometric ensemble approximated using a Legendre transformation.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model in the isometric ensemble approximated using a Legendre transformation, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
+    {
+        EFJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            link_stiffness,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length,
+            isotensional: isotensional::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants)
+        }
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.contour_length), temperature)*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link and temperature.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let function = |nondimensional_force: &f64| self.isotensional.nondimensional_end_to_end_length_per_link(nondimensional_force, temperature) - nondimensional_end_to_end_length_per_link;
+        let (lower, upper) = bracket(function, &(2.0*nondimensional_end_to_end_length_per_link), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length), temperature)*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
+    pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_force = self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature);
+        nondimensional_force*nondimensional_end_to_end_length_per_link + self.isotensional.nondimensional_gibbs_free_energy_per_link(&nondimensional_force, temperature)
+    }
+    /// The nondimensional relative helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link and temperature.
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_force = self.nondimensional_force(nondimensional_end_to_end_length_per_link, temperature);
+        nondimensional_force*nondimensional_end_to_end_length_per_link + self.isotensional.nondimensional_relative_gibbs_free_energy_per_link(&nondimensional_force, temperature)
+    }
+}
+
+impl Isometric for EFJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        EFJC::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        f64::INFINITY
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
@@ -0,0 +1,121 @@
+mod test;
+
+use std::f64::consts::PI;
+use crate::physics::
+{
+    Constants,
+    single_chain::Isotensional
+};
+use crate::physics::single_chain::planar::ln_hinge_partition_function;
+use super::partition_function_per_link;
+
+/// The structure of the thermodynamics of the two-dimensional EFJC model in the isotensional ensemble.
+///
+/// The partition function of each link under an applied force is integrated numerically over the link length.
+pub struct EFJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    number_of_links_f64: f64
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional EFJC model in the isotensional ensemble.
+impl EFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model in the isotensional ensemble.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model in the isotensional ensemble, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
+    {
+        EFJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            link_stiffness,
+            number_of_links_f64: number_of_links as f64
+        }
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length_per_link(force, temperature)*self.number_of_links_f64
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature.
+    pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)*self.link_length
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force and temperature.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        partition_function_per_link(&self.nondimensional_link_stiffness(temperature), nondimensional_force).1
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature), temperature)*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional gibbs free energy per link as a function of the applied nondimensional force and temperature.
+    pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_link_stiffness = self.nondimensional_link_stiffness(temperature);
+        -partition_function_per_link(&nondimensional_link_stiffness, nondimensional_force).0 + 0.5*(2.0*PI/nondimensional_link_stiffness).ln() - 0.5*(2.0*PI*self.constants.boltzmann_constant*temperature/self.link_stiffness).ln() - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The nondimensional relative gibbs free energy per link as a function of the applied nondimensional force and temperature.
+    pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_link_stiffness = self.nondimensional_link_stiffness(temperature);
+        partition_function_per_link(&nondimensional_link_stiffness, &0.0).0 - partition_function_per_link(&nondimensional_link_stiffness, nondimensional_force).0
+    }
+    fn nondimensional_link_stiffness(&self, temperature: &f64) -> f64
+    {
+        self.link_stiffness*self.link_length.powi(2)/self.constants.boltzmann_constant/temperature
+    }
+}
+
+impl Isotensional for EFJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        EFJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "EFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
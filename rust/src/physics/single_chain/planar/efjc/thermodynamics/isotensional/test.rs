@@ -0,0 +1,46 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::planar::fjc::thermodynamics::isotensional::FJC;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn gibbs_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let link_stiffness = parameters.link_stiffness_reference + parameters.link_stiffness_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, parameters.hinge_mass_reference, link_stiffness);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            let h = 1e-6*BOLTZMANN_CONSTANT*temperature/link_length;
+            let finite_difference = (model.gibbs_free_energy(&(force + h), &temperature) - model.gibbs_free_energy(&(force - h), &temperature))/(2.0*h);
+            assert!((finite_difference + model.end_to_end_length(&force, &temperature)).abs() <= parameters.rel_tol*(model.end_to_end_length(&force, &temperature) + (number_of_links as f64)*link_length));
+            assert_eq!(model.end_to_end_length(&-force, &temperature), -model.end_to_end_length(&force, &temperature));
+        }
+    }
+    #[test]
+    fn stiff()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = EFJC::init(number_of_links, link_length, parameters.hinge_mass_reference, 1e9*BOLTZMANN_CONSTANT*temperature/link_length.powi(2));
+            let reference = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/reference.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+/// The thermodynamics of the two-dimensional EFJC model in the isometric ensemble.
+pub mod isometric;
+
+/// The thermodynamics of the two-dimensional EFJC model in the isotensional ensemble.
+pub mod isotensional;
+
+use crate::physics::Constants;
+use crate::math::special::
+{
+    bessel_i0_scaled,
+    bessel_i1_scaled
+};
+
+static POINTS: u32 = 2000;
+
+/// The structure of the thermodynamics of the two-dimensional EFJC model.
+pub struct EFJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The stiffness of each link in the chain in units of J/(mol⋅nm^2).
+    pub link_stiffness: f64,
+
+    /// The thermodynamic functions of the model in the isometric ensemble.
+    pub isometric: isometric::EFJC,
+
+    /// The thermodynamic functions of the model in the isotensional ensemble.
+    pub isotensional: isotensional::EFJC
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional EFJC model.
+impl EFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64) -> EFJC
+    {
+        EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional EFJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, link_stiffness: f64, constants: Constants) -> EFJC
+    {
+        EFJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            link_stiffness,
+            isometric: isometric::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants),
+            isotensional: isotensional::EFJC::init_with_constants(number_of_links, link_length, hinge_mass, link_stiffness, constants)
+        }
+    }
+}
+
+/// The logarithm of the partition function of each link and the expected nondimensional projection of each link along the force, as functions of the nondimensional link stiffness and force.
+///
+/// The integrand over the nondimensional link length is the product of a Gaussian centered at the mean stretched length and the scaled Bessel function, so it is integrated using Simpson's rule over twelve standard deviations either side of its maximum, beyond which the tails are negligible.
+fn partition_function_per_link(nondimensional_link_stiffness: &f64, nondimensional_force: &f64) -> (f64, f64)
+{
+    let force = nondimensional_force.abs();
+    let stretch = 1.0 + force/nondimensional_link_stiffness;
+    let width = 12.0/nondimensional_link_stiffness.sqrt();
+    let lower = (stretch - width).max(0.0);
+    let step = (stretch + width - lower)/(POINTS as f64);
+    let mut zeroth = 0.0;
+    let mut first = 0.0;
+    for index in 0..=POINTS
+    {
+        let length = lower + step*(index as f64);
+        let weight = if index == 0 || index == POINTS {1.0} else if index.is_multiple_of(2) {2.0} else {4.0};
+        let envelope = weight*length*(-0.5*nondimensional_link_stiffness*(length - stretch).powi(2)).exp();
+        zeroth += envelope*bessel_i0_scaled(&(force*length));
+        first += envelope*length*bessel_i1_scaled(&(force*length));
+    }
+    (force + 0.5*force.powi(2)/nondimensional_link_stiffness + (zeroth*step/3.0).ln(), nondimensional_force.signum()*first/zeroth)
+}
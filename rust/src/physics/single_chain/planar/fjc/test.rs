@@ -0,0 +1,22 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, 0.0).err(), Some(Error::InvalidParameter{model: "FJC", argument: "hinge_mass", value: 0.0}));
+    }
+    #[test]
+    fn thermodynamics()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        assert_eq!(model.thermodynamics.isometric.legendre.number_of_links, model.number_of_links);
+        assert_eq!(model.thermodynamics.isotensional.link_length, model.link_length);
+    }
+}
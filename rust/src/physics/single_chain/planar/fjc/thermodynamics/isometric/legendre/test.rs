@@ -0,0 +1,47 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::planar::fjc::thermodynamics::isotensional;
+mod legendre
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn free_energy()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let reference = isotensional::FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = model.maximum_end_to_end_length()*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((reference.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - reference.relative_gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.rel_tol*force*end_to_end_length);
+            assert!((model.helmholtz_free_energy(&end_to_end_length, &temperature) - reference.gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.rel_tol*force*end_to_end_length);
+        }
+    }
+    #[test]
+    fn helmholtz_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = model.maximum_end_to_end_length()*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let force = model.force(&end_to_end_length, &temperature);
+            let h = 1e-6*(number_of_links as f64)*link_length;
+            let finite_difference = (model.helmholtz_free_energy(&(end_to_end_length + h), &temperature) - model.helmholtz_free_energy(&(end_to_end_length - h), &temperature))/(2.0*h);
+            assert!((finite_difference - force).abs() <= parameters.rel_tol*(force + BOLTZMANN_CONSTANT*temperature/link_length));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+/// The thermodynamics of the two-dimensional FJC model in the isometric ensemble approximated using a Legendre transformation.
+pub mod legendre;
+
+use crate::physics::Constants;
+
+/// The structure of the thermodynamics of the two-dimensional FJC model in the isometric ensemble.
+///
+/// The exact equilibrium distribution of a planar random walk with links of fixed length has no closed form, so only the approximation using a Legendre transformation is implemented.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The thermodynamic functions of the model in the isometric ensemble approximated using a Legendre transformation.
+    pub legendre: legendre::FJC
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional FJC model in the isometric ensemble.
+impl FJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional FJC model in the isometric ensemble.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional FJC model in the isometric ensemble, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
+    {
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            legendre: legendre::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
+        }
+    }
+}
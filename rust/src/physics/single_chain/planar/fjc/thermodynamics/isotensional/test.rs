@@ -0,0 +1,45 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::planar::ideal::thermodynamics::isotensional::Ideal;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn gibbs_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = (parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>()))*BOLTZMANN_CONSTANT*temperature/link_length;
+            let h = 1e-6*BOLTZMANN_CONSTANT*temperature/link_length;
+            let finite_difference = (model.gibbs_free_energy(&(force + h), &temperature) - model.gibbs_free_energy(&(force - h), &temperature))/(2.0*h);
+            assert!((finite_difference + model.end_to_end_length(&force, &temperature)).abs() <= parameters.rel_tol*(model.end_to_end_length(&force, &temperature) + (number_of_links as f64)*link_length));
+        }
+    }
+    #[test]
+    fn small_force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let reference = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = 1e-4*rng.gen::<f64>()*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/reference.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/reference.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
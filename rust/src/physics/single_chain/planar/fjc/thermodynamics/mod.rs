@@ -0,0 +1,52 @@
+/// The thermodynamics of the two-dimensional FJC model in the isometric ensemble.
+pub mod isometric;
+
+/// The thermodynamics of the two-dimensional FJC model in the isotensional ensemble.
+pub mod isotensional;
+
+use crate::physics::Constants;
+
+/// The structure of the thermodynamics of the two-dimensional FJC model.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The thermodynamic functions of the model in the isometric ensemble.
+    pub isometric: isometric::FJC,
+
+    /// The thermodynamic functions of the model in the isotensional ensemble.
+    pub isotensional: isotensional::FJC
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional FJC model.
+impl FJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional FJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
+    {
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            isometric: isometric::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants),
+            isotensional: isotensional::FJC::init_with_constants(number_of_links, link_length, hinge_mass, constants)
+        }
+    }
+}
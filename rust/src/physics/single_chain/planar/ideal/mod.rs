@@ -0,0 +1,61 @@
+mod test;
+
+/// The thermodynamics of the two-dimensional ideal chain model.
+pub mod thermodynamics;
+
+use crate::physics::Constants;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+/// The structure of the two-dimensional ideal chain model, where the end-to-end vector is Gaussian distributed in the plane.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The thermodynamic functions of the model.
+    pub thermodynamics: thermodynamics::Ideal
+}
+
+/// The implemented functionality of the two-dimensional ideal chain model.
+impl Ideal
+{
+    /// Initializes and returns an instance of the two-dimensional ideal chain model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the two-dimensional ideal chain model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            thermodynamics: thermodynamics::Ideal::init_with_constants(number_of_links, link_length, hinge_mass, constants)
+        }
+    }
+    /// Initializes and returns an instance of the two-dimensional ideal chain model, or an error if a parameter is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Ideal, Error>
+    {
+        minimum_number_of_links("Ideal", &number_of_links, 1)?;
+        positive("Ideal", "link_length", &link_length)?;
+        positive("Ideal", "hinge_mass", &hinge_mass)?;
+        Ok(Ideal::init(number_of_links, link_length, hinge_mass))
+    }
+}
@@ -0,0 +1,23 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(Ideal::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(Ideal::try_init(0, parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "Ideal", argument: "number_of_links", value: 0.0}));
+        assert_eq!(Ideal::try_init(parameters.number_of_links_minimum, -1.0, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "Ideal", argument: "link_length", value: -1.0}));
+    }
+    #[test]
+    fn thermodynamics()
+    {
+        let parameters = Parameters::default();
+        let model = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        assert_eq!(model.thermodynamics.isometric.legendre.number_of_links, model.number_of_links);
+        assert_eq!(model.thermodynamics.isotensional.link_length, model.link_length);
+    }
+}
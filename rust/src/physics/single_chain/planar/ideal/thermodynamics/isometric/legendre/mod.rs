@@ -0,0 +1,107 @@
+//!
+//! The relative helmholtz free energy and the force coincide with the exact results, while the helmholtz free energy differs from the exact result by a term that depends only on the number of links and the link length.
+
+mod test;
+
+use crate::physics::
+{
+    Constants,
+    single_chain::Isometric
+};
+use crate::physics::single_chain::planar::ln_hinge_partition_function;
+
+/// The structure of the thermodynamics of the two-dimensional ideal chain model in the isometric ensemble approximated using a Legendre transformation.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional ideal chain model in the isometric ensemble approximated using a Legendre transformation.
+impl Ideal
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional ideal chain model in the isometric ensemble approximated using a Legendre transformation.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional ideal chain model in the isometric ensemble approximated using a Legendre transformation, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length
+        }
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        2.0*nondimensional_end_to_end_length_per_link
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.relative_helmholtz_free_energy(end_to_end_length, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(end_to_end_length/self.contour_length))*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional relative helmholtz free energy per link as a function of the applied nondimensional end-to-end length per link.
+    pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        nondimensional_end_to_end_length_per_link.powi(2)
+    }
+}
+
+impl Isometric for Ideal
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        Ideal::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        f64::INFINITY
+    }
+    fn name(&self) -> &'static str
+    {
+        "Ideal"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::planar::ideal::thermodynamics::
+{
+    isometric,
+    isotensional
+};
+mod legendre
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn free_energy()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, hinge_mass);
+            let reference = isotensional::Ideal::init(number_of_links, link_length, hinge_mass);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = parameters.nondimensional_force_small*rng.gen::<f64>()*BOLTZMANN_CONSTANT*temperature/link_length;
+            let end_to_end_length = reference.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.helmholtz_free_energy(&end_to_end_length, &temperature) - reference.gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.rel_tol*force*end_to_end_length);
+            assert!((model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - reference.relative_gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.rel_tol*force*end_to_end_length);
+        }
+    }
+    #[test]
+    fn exact()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let reference = isometric::Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = link_length*(number_of_links as f64)*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            assert!((model.force(&end_to_end_length, &temperature)/reference.force(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_helmholtz_free_energy(&end_to_end_length, &temperature)/reference.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod distribution
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn normalization()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let step = 1e-4;
+            let (normalization, second_moment) = (0..20000).map(|index| (index as f64 + 0.5)*step).fold((0.0, 0.0), |(normalization, second_moment), gamma|
+            {
+                let density = model.nondimensional_equilibrium_radial_distribution(&gamma)*step;
+                (normalization + density, second_moment + density*gamma.powi(2))
+            });
+            assert!((normalization - 1.0).abs() <= parameters.rel_tol);
+            assert!((second_moment*(number_of_links as f64) - 1.0).abs() <= parameters.rel_tol);
+            let end_to_end_length = 0.5*link_length*(number_of_links as f64).sqrt();
+            assert!((model.equilibrium_radial_distribution(&end_to_end_length)/(2.0*PI*end_to_end_length*model.equilibrium_distribution(&end_to_end_length)) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod free_energy
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn helmholtz_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length = link_length*(number_of_links as f64)*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let force = model.force(&end_to_end_length, &temperature);
+            let h = 1e-6*(number_of_links as f64)*link_length;
+            let finite_difference = (model.helmholtz_free_energy(&(end_to_end_length + h), &temperature) - model.helmholtz_free_energy(&(end_to_end_length - h), &temperature))/(2.0*h);
+            assert!((finite_difference - force).abs() <= parameters.rel_tol*(force + BOLTZMANN_CONSTANT*temperature/link_length));
+            let relative_helmholtz_free_energy = model.helmholtz_free_energy(&end_to_end_length, &temperature) - model.helmholtz_free_energy(&0.0, &temperature);
+            assert!((model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - relative_helmholtz_free_energy).abs() <= parameters.rel_tol*(relative_helmholtz_free_energy + BOLTZMANN_CONSTANT*temperature));
+        }
+    }
+}
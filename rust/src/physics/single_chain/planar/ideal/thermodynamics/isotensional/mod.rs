@@ -0,0 +1,102 @@
+mod test;
+
+use crate::physics::
+{
+    Constants,
+    single_chain::Isotensional
+};
+use crate::physics::single_chain::planar::ln_hinge_partition_function;
+
+/// The structure of the thermodynamics of the two-dimensional ideal chain model in the isotensional ensemble.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64
+}
+
+/// The implemented functionality of the thermodynamics of the two-dimensional ideal chain model in the isotensional ensemble.
+impl Ideal
+{
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional ideal chain model in the isotensional ensemble.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the thermodynamics of the two-dimensional ideal chain model in the isotensional ensemble, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64
+        }
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length_per_link(force, temperature)*self.number_of_links_f64
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature.
+    pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.link_length
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        nondimensional_force/2.0
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.number_of_links_f64*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional relative gibbs free energy per link as a function of the applied nondimensional force.
+    pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        -nondimensional_force.powi(2)/4.0
+    }
+}
+
+impl Isotensional for Ideal
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Ideal::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "Ideal"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
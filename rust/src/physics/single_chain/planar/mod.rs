@@ -0,0 +1,17 @@
+/// The two-dimensional ideal single-chain model.
+pub mod ideal;
+
+/// The two-dimensional freely-jointed chain (FJC) single-chain model.
+pub mod fjc;
+
+/// The two-dimensional extensible freely-jointed chain (EFJC) single-chain model.
+pub mod efjc;
+
+use std::f64::consts::PI;
+use crate::physics::Constants;
+
+/// The logarithm of the partition function of the momentum and orientation of each hinge rotating in the plane, which replaces the three-dimensional rotational contribution to the free energies.
+fn ln_hinge_partition_function(hinge_mass: &f64, link_length: &f64, temperature: &f64, constants: &Constants) -> f64
+{
+    0.5*(8.0*PI.powi(3)*hinge_mass*link_length.powi(2)*constants.boltzmann_constant*temperature/constants.planck_constant.powi(2)).ln()
+}
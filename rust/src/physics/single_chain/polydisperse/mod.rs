@@ -0,0 +1,322 @@
+mod test;
+use crate::physics::Constants;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::single_chain::
+{
+    Isometric,
+    Isotensional
+};
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+static CUTOFF: f64 = 1e-16;
+
+/// The distributions of the number of links in a polydisperse ensemble of chains.
+pub enum Distribution
+{
+    /// The Flory-Schulz (most probable) distribution with the given number-average number of links.
+    FlorySchulz
+    {
+        /// The number-average number of links, which is greater than one.
+        number_average_number_of_links: f64
+    },
+    /// The Poisson distribution with the given mean number of links.
+    Poisson
+    {
+        /// The mean number of links.
+        mean_number_of_links: f64
+    },
+    /// The log-normal distribution with the given median number of links and standard deviation of the logarithm of the number of links.
+    LogNormal
+    {
+        /// The median number of links.
+        median_number_of_links: f64,
+        /// The standard deviation of the logarithm of the number of links.
+        standard_deviation: f64
+    },
+    /// A user-supplied discrete distribution of pairs of the number of links and the (unnormalized) number fraction.
+    Discrete(Vec<(u8, f64)>)
+}
+
+/// The implemented functionality of the distributions of the number of links.
+impl Distribution
+{
+    /// Returns an error if a parameter of the distribution is invalid.
+    pub fn validate(&self) -> Result<(), Error>
+    {
+        match self
+        {
+            Distribution::FlorySchulz{number_average_number_of_links} =>
+            {
+                if number_average_number_of_links > &1.0 && number_average_number_of_links.is_finite()
+                {
+                    Ok(())
+                }
+                else
+                {
+                    Err(Error::InvalidParameter{model: "FlorySchulz", argument: "number_average_number_of_links", value: *number_average_number_of_links})
+                }
+            },
+            Distribution::Poisson{mean_number_of_links} =>
+            {
+                positive("Poisson", "mean_number_of_links", mean_number_of_links)
+            },
+            Distribution::LogNormal{median_number_of_links, standard_deviation} =>
+            {
+                positive("LogNormal", "median_number_of_links", median_number_of_links)?;
+                positive("LogNormal", "standard_deviation", standard_deviation)
+            },
+            Distribution::Discrete(weights) =>
+            {
+                for (_, weight) in weights.iter()
+                {
+                    if !(weight >= &0.0 && weight.is_finite())
+                    {
+                        return Err(Error::InvalidParameter{model: "Discrete", argument: "weight", value: *weight})
+                    }
+                }
+                positive("Discrete", "weight", &weights.iter().map(|(_, weight)| weight).sum())
+            }
+        }
+    }
+    /// The unnormalized number fraction of chains with the given number of links.
+    pub fn weight(&self, number_of_links: &u8) -> f64
+    {
+        let number_of_links_f64 = *number_of_links as f64;
+        match self
+        {
+            Distribution::FlorySchulz{number_average_number_of_links} =>
+            {
+                (1.0 - 1.0/number_average_number_of_links).powf(number_of_links_f64 - 1.0)/number_average_number_of_links
+            },
+            Distribution::Poisson{mean_number_of_links} =>
+            {
+                (number_of_links_f64*mean_number_of_links.ln() - mean_number_of_links - (1..=*number_of_links).map(|n| (n as f64).ln()).sum::<f64>()).exp()
+            },
+            Distribution::LogNormal{median_number_of_links, standard_deviation} =>
+            {
+                (-0.5*((number_of_links_f64/median_number_of_links).ln()/standard_deviation).powi(2)).exp()/number_of_links_f64
+            },
+            Distribution::Discrete(weights) =>
+            {
+                weights.iter().filter(|(n, _)| n == number_of_links).map(|(_, weight)| weight).sum()
+            }
+        }
+    }
+}
+
+/// The structure of a polydisperse ensemble of single-chain models.
+pub struct Polydisperse<T>
+{
+    /// The minimum number of links of chains in the ensemble.
+    pub minimum_number_of_links: u8,
+
+    /// The maximum number of links of chains in the ensemble.
+    pub maximum_number_of_links: u8,
+
+    /// The number of links of each chain in the ensemble.
+    pub numbers_of_links: Vec<u8>,
+
+    /// The normalized number fraction of each chain in the ensemble.
+    pub number_fractions: Vec<f64>,
+
+    /// The single-chain model of each chain in the ensemble.
+    pub models: Vec<T>,
+
+    number_average_number_of_links: f64
+}
+
+/// The implemented functionality of a polydisperse ensemble of single-chain models.
+impl<T> Polydisperse<T>
+{
+    /// Initializes and returns an instance of a polydisperse ensemble, truncating the distribution to the given range of the number of links and constructing the single-chain model for each number of links in the range.
+    pub fn init<F>(distribution: &Distribution, minimum_number_of_links: u8, maximum_number_of_links: u8, model: F) -> Polydisperse<T>
+    where F: Fn(u8) -> T
+    {
+        let weights = (minimum_number_of_links..=maximum_number_of_links).map(|number_of_links| (number_of_links, distribution.weight(&number_of_links))).collect::<Vec<(u8, f64)>>();
+        let maximum_weight = weights.iter().map(|(_, weight)| *weight).fold(0.0, f64::max);
+        let weights = weights.into_iter().filter(|(_, weight)| weight > &(CUTOFF*maximum_weight)).collect::<Vec<(u8, f64)>>();
+        let total_weight = weights.iter().map(|(_, weight)| weight).sum::<f64>();
+        let numbers_of_links = weights.iter().map(|(number_of_links, _)| *number_of_links).collect::<Vec<u8>>();
+        let number_fractions = weights.iter().map(|(_, weight)| weight/total_weight).collect::<Vec<f64>>();
+        let number_average_number_of_links = numbers_of_links.iter().zip(number_fractions.iter()).map(|(number_of_links, number_fraction)| (*number_of_links as f64)*number_fraction).sum();
+        Polydisperse
+        {
+            minimum_number_of_links,
+            maximum_number_of_links,
+            models: numbers_of_links.iter().map(|number_of_links| model(*number_of_links)).collect(),
+            numbers_of_links,
+            number_fractions,
+            number_average_number_of_links
+        }
+    }
+    /// Initializes and returns an instance of a polydisperse ensemble, or an error if a parameter of the distribution is invalid, the range of the number of links is empty, or the distribution has no chains in the range.
+    pub fn try_init<F>(distribution: &Distribution, minimum_number_of_links: u8, maximum_number_of_links: u8, model: F) -> Result<Polydisperse<T>, Error>
+    where F: Fn(u8) -> T
+    {
+        distribution.validate()?;
+        self::minimum_number_of_links("Polydisperse", &minimum_number_of_links, 1)?;
+        if maximum_number_of_links < minimum_number_of_links
+        {
+            return Err(Error::InvalidParameter{model: "Polydisperse", argument: "maximum_number_of_links", value: maximum_number_of_links as f64})
+        }
+        positive("Polydisperse", "number_fractions", &(minimum_number_of_links..=maximum_number_of_links).map(|number_of_links| distribution.weight(&number_of_links)).sum())?;
+        Ok(Polydisperse::init(distribution, minimum_number_of_links, maximum_number_of_links, model))
+    }
+    /// The number-average number of links of the ensemble.
+    pub fn number_average_number_of_links(&self) -> f64
+    {
+        self.number_average_number_of_links
+    }
+    /// The weight-average number of links of the ensemble.
+    pub fn weight_average_number_of_links(&self) -> f64
+    {
+        self.numbers_of_links.iter().zip(self.number_fractions.iter()).map(|(number_of_links, number_fraction)| (*number_of_links as f64).powi(2)*number_fraction).sum::<f64>()/self.number_average_number_of_links
+    }
+    /// The dispersity of the ensemble, the ratio of the weight-average to the number-average number of links.
+    pub fn dispersity(&self) -> f64
+    {
+        self.weight_average_number_of_links()/self.number_average_number_of_links
+    }
+    fn average<F>(&self, function: F) -> f64
+    where F: Fn(&u8, &T) -> f64
+    {
+        self.numbers_of_links.iter().zip(self.number_fractions.iter()).zip(self.models.iter()).map(|((number_of_links, number_fraction), model)| number_fraction*function(number_of_links, model)).sum()
+    }
+}
+
+/// The ensemble-averaged thermodynamic functions in the isotensional ensemble.
+impl<T: Isotensional> Polydisperse<T>
+{
+    /// The expected end-to-end length as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.average(|_, model| model.end_to_end_length(force, temperature))
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length(force, temperature)/self.number_average_number_of_links
+    }
+    /// The gibbs free energy as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.average(|_, model| model.gibbs_free_energy(force, temperature))
+    }
+    /// The gibbs free energy per link as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.gibbs_free_energy(force, temperature)/self.number_average_number_of_links
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.average(|_, model| model.relative_gibbs_free_energy(force, temperature))
+    }
+    /// The relative gibbs free energy per link as a function of the applied force and temperature, averaged over the ensemble.
+    pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature)/self.number_average_number_of_links
+    }
+}
+
+impl<T: Isotensional> Isotensional for Polydisperse<T>
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Polydisperse::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Polydisperse::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Polydisperse::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "Polydisperse"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.models.first().map_or(Constants::default(), |model| model.constants())
+    }
+}
+
+/// The ensemble-averaged thermodynamic functions in the isometric ensemble.
+///
+/// Under equal stretch, every chain is held at the same end-to-end length per link.
+/// Under equal force, every chain is held at the same force, chosen such that the ensemble-averaged end-to-end length per link is the applied one.
+impl<T: Isometric> Polydisperse<T>
+{
+    /// The expected force as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal stretch.
+    pub fn equal_stretch_force(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.average(|number_of_links, model| model.force(&((*number_of_links as f64)*end_to_end_length_per_link), temperature))
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal stretch.
+    pub fn equal_stretch_helmholtz_free_energy(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.average(|number_of_links, model| model.helmholtz_free_energy(&((*number_of_links as f64)*end_to_end_length_per_link), temperature))
+    }
+    /// The helmholtz free energy per link as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal stretch.
+    pub fn equal_stretch_helmholtz_free_energy_per_link(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.equal_stretch_helmholtz_free_energy(end_to_end_length_per_link, temperature)/self.number_average_number_of_links
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal stretch.
+    pub fn equal_stretch_relative_helmholtz_free_energy(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.average(|number_of_links, model| model.relative_helmholtz_free_energy(&((*number_of_links as f64)*end_to_end_length_per_link), temperature))
+    }
+    /// The relative helmholtz free energy per link as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal stretch.
+    pub fn equal_stretch_relative_helmholtz_free_energy_per_link(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.equal_stretch_relative_helmholtz_free_energy(end_to_end_length_per_link, temperature)/self.number_average_number_of_links
+    }
+    /// The force as a function of the applied end-to-end length per link and temperature, common to every chain in the ensemble under equal force.
+    pub fn equal_force_force(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let forces = self.numbers_of_links.iter().zip(self.models.iter()).map(|(number_of_links, model)| model.force(&((*number_of_links as f64)*end_to_end_length_per_link), temperature)).collect::<Vec<f64>>();
+        let lower = forces.iter().copied().fold(f64::INFINITY, f64::min);
+        let upper = forces.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        brent(|force| self.average(|number_of_links, model| self.end_to_end_length_at_force(number_of_links, model, force, end_to_end_length_per_link, temperature)) - self.number_average_number_of_links*end_to_end_length_per_link, &lower, &upper)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal force.
+    pub fn equal_force_helmholtz_free_energy(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let force = self.equal_force_force(end_to_end_length_per_link, temperature);
+        self.average(|number_of_links, model| model.helmholtz_free_energy(&self.end_to_end_length_at_force(number_of_links, model, &force, end_to_end_length_per_link, temperature), temperature))
+    }
+    /// The helmholtz free energy per link as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal force.
+    pub fn equal_force_helmholtz_free_energy_per_link(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.equal_force_helmholtz_free_energy(end_to_end_length_per_link, temperature)/self.number_average_number_of_links
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal force.
+    pub fn equal_force_relative_helmholtz_free_energy(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let force = self.equal_force_force(end_to_end_length_per_link, temperature);
+        self.average(|number_of_links, model| model.relative_helmholtz_free_energy(&self.end_to_end_length_at_force(number_of_links, model, &force, end_to_end_length_per_link, temperature), temperature))
+    }
+    /// The relative helmholtz free energy per link as a function of the applied end-to-end length per link and temperature, averaged over the ensemble under equal force.
+    pub fn equal_force_relative_helmholtz_free_energy_per_link(&self, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        self.equal_force_relative_helmholtz_free_energy(end_to_end_length_per_link, temperature)/self.number_average_number_of_links
+    }
+    fn end_to_end_length_at_force(&self, number_of_links: &u8, model: &T, force: &f64, end_to_end_length_per_link: &f64, temperature: &f64) -> f64
+    {
+        let function = |end_to_end_length: &f64| model.force(end_to_end_length, temperature) - force;
+        let (lower, upper) = bracket(function, &((*number_of_links as f64)*end_to_end_length_per_link), &0.0, &model.maximum_end_to_end_length());
+        brent(function, &lower, &upper)
+    }
+}
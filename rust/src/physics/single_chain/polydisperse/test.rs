@@ -0,0 +1,268 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::
+{
+    ideal::Ideal,
+    fjc::FJC
+};
+use crate::physics::single_chain::test::Parameters as DefaultParameters;
+pub struct Parameters
+{
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+    pub number_of_loops: u32,
+    pub hinge_mass_reference: f64,
+    pub link_length_reference: f64,
+    pub link_length_scale: f64,
+    pub number_of_links_minimum: u8,
+    pub number_of_links_maximum: u8,
+    pub nondimensional_force_reference: f64,
+    pub nondimensional_force_scale: f64,
+    pub nondimensional_end_to_end_length_per_link_reference: f64,
+    pub nondimensional_end_to_end_length_per_link_scale: f64,
+    pub temperature_reference: f64,
+    pub temperature_scale: f64,
+    pub mean_number_of_links_reference: f64,
+    pub mean_number_of_links_scale: f64,
+    pub truncation_tol: f64,
+}
+impl Default for Parameters
+{
+    fn default() -> Self
+    {
+        Self
+        {
+            abs_tol: DefaultParameters::default().abs_tol,
+            rel_tol: DefaultParameters::default().rel_tol,
+            number_of_loops: 88,
+            hinge_mass_reference: DefaultParameters::default().hinge_mass_reference,
+            link_length_reference: DefaultParameters::default().link_length_reference,
+            link_length_scale: DefaultParameters::default().link_length_scale,
+            number_of_links_minimum: DefaultParameters::default().number_of_links_minimum,
+            number_of_links_maximum: DefaultParameters::default().number_of_links_maximum,
+            nondimensional_force_reference: DefaultParameters::default().nondimensional_force_reference,
+            nondimensional_force_scale: DefaultParameters::default().nondimensional_force_scale,
+            nondimensional_end_to_end_length_per_link_reference: DefaultParameters::default().nondimensional_end_to_end_length_per_link_reference,
+            nondimensional_end_to_end_length_per_link_scale: DefaultParameters::default().nondimensional_end_to_end_length_per_link_scale,
+            temperature_reference: DefaultParameters::default().temperature_reference,
+            temperature_scale: DefaultParameters::default().temperature_scale,
+            mean_number_of_links_reference: 125e-1,
+            mean_number_of_links_scale: 5e0,
+            truncation_tol: 1e-3,
+        }
+    }
+}
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let _ = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+    }
+    #[test]
+    fn number_fractions()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let mean_number_of_links = (parameters.number_of_links_minimum as f64) + ((parameters.number_of_links_maximum - parameters.number_of_links_minimum) as f64)*rng.gen::<f64>();
+            for distribution in [
+                Distribution::FlorySchulz{number_average_number_of_links: mean_number_of_links},
+                Distribution::Poisson{mean_number_of_links},
+                Distribution::LogNormal{median_number_of_links: mean_number_of_links, standard_deviation: 0.5}
+            ]
+            {
+                let polydisperse = Polydisperse::init(&distribution, 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+                assert!((polydisperse.number_fractions.iter().sum::<f64>() - 1.0).abs() <= parameters.abs_tol);
+                assert!(polydisperse.number_fractions.iter().all(|number_fraction| number_fraction > &0.0));
+                assert_eq!(polydisperse.numbers_of_links.len(), polydisperse.models.len());
+            }
+        }
+    }
+    #[test]
+    fn monodisperse()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let polydisperse = Polydisperse::init(&Distribution::Discrete(vec![(number_of_links, 2.0)]), 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+            assert_eq!(polydisperse.numbers_of_links, vec![number_of_links]);
+            assert_eq!(polydisperse.number_average_number_of_links(), number_of_links as f64);
+            assert!((polydisperse.dispersity() - 1.0).abs() <= parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn dispersity_flory_schulz()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_average_number_of_links = parameters.mean_number_of_links_reference + parameters.mean_number_of_links_scale*(0.5 - rng.gen::<f64>());
+            let polydisperse = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links}, 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+            assert!((polydisperse.number_average_number_of_links()/number_average_number_of_links - 1.0).abs() <= parameters.truncation_tol);
+            assert!((polydisperse.dispersity()/(2.0 - 1.0/number_average_number_of_links) - 1.0).abs() <= parameters.truncation_tol);
+        }
+    }
+    #[test]
+    fn dispersity_poisson()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let mean_number_of_links = parameters.mean_number_of_links_reference + parameters.mean_number_of_links_scale*(0.5 - rng.gen::<f64>());
+            let polydisperse = Polydisperse::init(&Distribution::Poisson{mean_number_of_links}, 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+            assert!((polydisperse.number_average_number_of_links()/mean_number_of_links - 1.0).abs() <= parameters.truncation_tol);
+            assert!((polydisperse.dispersity()/(1.0 + 1.0/mean_number_of_links) - 1.0).abs() <= parameters.truncation_tol);
+        }
+    }
+    #[test]
+    fn truncation()
+    {
+        let parameters = Parameters::default();
+        let polydisperse = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, parameters.number_of_links_minimum, parameters.number_of_links_maximum, |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference));
+        assert_eq!(polydisperse.numbers_of_links.first(), Some(&parameters.number_of_links_minimum));
+        assert_eq!(polydisperse.numbers_of_links.last(), Some(&parameters.number_of_links_maximum));
+    }
+}
+mod error
+{
+    use super::*;
+    #[test]
+    fn validate()
+    {
+        assert!(Distribution::FlorySchulz{number_average_number_of_links: 1e1}.validate().is_ok());
+        assert!(Distribution::Poisson{mean_number_of_links: 1e1}.validate().is_ok());
+        assert!(Distribution::LogNormal{median_number_of_links: 1e1, standard_deviation: 0.5}.validate().is_ok());
+        assert!(Distribution::Discrete(vec![(1, 0.0), (2, 1.0)]).validate().is_ok());
+        for number_average_number_of_links in [0.5, 1.0, f64::INFINITY, f64::NAN]
+        {
+            assert!(matches!(Distribution::FlorySchulz{number_average_number_of_links}.validate(), Err(Error::InvalidParameter{model: "FlorySchulz", argument: "number_average_number_of_links", ..})));
+        }
+        assert_eq!(Distribution::Poisson{mean_number_of_links: 0.0}.validate(), Err(Error::InvalidParameter{model: "Poisson", argument: "mean_number_of_links", value: 0.0}));
+        assert_eq!(Distribution::LogNormal{median_number_of_links: 1e1, standard_deviation: -0.5}.validate(), Err(Error::InvalidParameter{model: "LogNormal", argument: "standard_deviation", value: -0.5}));
+        assert_eq!(Distribution::Discrete(vec![(1, -1.0), (2, 1.0)]).validate(), Err(Error::InvalidParameter{model: "Discrete", argument: "weight", value: -1.0}));
+        assert_eq!(Distribution::Discrete(vec![]).validate(), Err(Error::InvalidParameter{model: "Discrete", argument: "weight", value: 0.0}));
+    }
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        let model = |number_of_links| Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference);
+        assert!(Polydisperse::try_init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, 1, u8::MAX, model).is_ok());
+        assert_eq!(Polydisperse::try_init(&Distribution::FlorySchulz{number_average_number_of_links: 0.5}, 1, u8::MAX, model).err(), Some(Error::InvalidParameter{model: "FlorySchulz", argument: "number_average_number_of_links", value: 0.5}));
+        assert_eq!(Polydisperse::try_init(&Distribution::Poisson{mean_number_of_links: 1e1}, 0, u8::MAX, model).err(), Some(Error::InvalidParameter{model: "Polydisperse", argument: "number_of_links", value: 0.0}));
+        assert_eq!(Polydisperse::try_init(&Distribution::Poisson{mean_number_of_links: 1e1}, 8, 4, model).err(), Some(Error::InvalidParameter{model: "Polydisperse", argument: "maximum_number_of_links", value: 4.0}));
+        assert_eq!(Polydisperse::try_init(&Distribution::Discrete(vec![(50, 1.0)]), 1, 25, model).err(), Some(Error::InvalidParameter{model: "Polydisperse", argument: "number_fractions", value: 0.0}));
+    }
+}
+mod isotensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn monodisperse()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isotensional;
+            let polydisperse = Polydisperse::init(&Distribution::Discrete(vec![(number_of_links, 1.0)]), 1, u8::MAX, |number_of_links| FJC::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isotensional);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((polydisperse.end_to_end_length(&force, &temperature)/model.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.end_to_end_length_per_link(&force, &temperature)/model.end_to_end_length_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.gibbs_free_energy(&force, &temperature)/model.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.relative_gibbs_free_energy_per_link(&force, &temperature)/model.relative_gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn per_link()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(1, link_length, parameters.hinge_mass_reference).thermodynamics.isotensional;
+            let polydisperse = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, 1, u8::MAX, |number_of_links| FJC::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isotensional);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((polydisperse.end_to_end_length_per_link(&force, &temperature)/model.end_to_end_length_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.gibbs_free_energy_per_link(&force, &temperature)/model.gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.relative_gibbs_free_energy_per_link(&force, &temperature)/model.relative_gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod isometric
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn monodisperse()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isometric.legendre;
+            let polydisperse = Polydisperse::init(&Distribution::Discrete(vec![(number_of_links, 1.0)]), 1, u8::MAX, |number_of_links| FJC::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isometric.legendre);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length_per_link = nondimensional_end_to_end_length_per_link*link_length;
+            let end_to_end_length = end_to_end_length_per_link*(number_of_links as f64);
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((polydisperse.equal_stretch_force(&end_to_end_length_per_link, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.equal_force_force(&end_to_end_length_per_link, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.equal_stretch_helmholtz_free_energy(&end_to_end_length_per_link, &temperature)/model.helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.equal_force_relative_helmholtz_free_energy(&end_to_end_length_per_link, &temperature)/model.relative_helmholtz_free_energy(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn ideal()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let polydisperse = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, 1, u8::MAX, |number_of_links| Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference).thermodynamics.isometric);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length_per_link = nondimensional_end_to_end_length_per_link*link_length;
+            let force = 3.0*nondimensional_end_to_end_length_per_link*BOLTZMANN_CONSTANT*temperature/link_length;
+            assert!((polydisperse.equal_stretch_force(&end_to_end_length_per_link, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.equal_force_force(&end_to_end_length_per_link, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+            assert!((polydisperse.equal_force_relative_helmholtz_free_energy_per_link(&end_to_end_length_per_link, &temperature)/polydisperse.equal_stretch_relative_helmholtz_free_energy_per_link(&end_to_end_length_per_link, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn equal_force_bounds_equal_stretch()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let polydisperse = Polydisperse::init(&Distribution::FlorySchulz{number_average_number_of_links: 1e1}, parameters.number_of_links_minimum, parameters.number_of_links_maximum, |number_of_links| FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isometric.legendre);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let nondimensional_end_to_end_length_per_link = parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>());
+            let end_to_end_length_per_link = nondimensional_end_to_end_length_per_link*parameters.link_length_reference;
+            assert!(polydisperse.equal_force_relative_helmholtz_free_energy(&end_to_end_length_per_link, &temperature) <= polydisperse.equal_stretch_relative_helmholtz_free_energy(&end_to_end_length_per_link, &temperature)*(1.0 + parameters.rel_tol));
+        }
+    }
+}
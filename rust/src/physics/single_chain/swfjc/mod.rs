@@ -1,24 +1,82 @@
 mod test;
 pub mod thermodynamics;
+use crate::physics::Constants;
+use crate::math::float::Float;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
 pub struct SWFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub well_width: f64,
     pub thermodynamics: self::thermodynamics::SWFJC
 }
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameters<F = f64>
+{
+    pub number_of_links: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Constants,
+    pub link_length: F,
+    pub hinge_mass: F,
+    pub well_width: F
+}
 impl SWFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> SWFJC
+    {
+        SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64, constants: Constants) -> SWFJC
     {
         SWFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             well_width,
-            thermodynamics: self::thermodynamics::SWFJC::init(number_of_links, link_length, hinge_mass, well_width),
+            thermodynamics: self::thermodynamics::SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, constants),
+        }
+    }
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> Result<SWFJC, Error>
+    {
+        minimum_number_of_links("SWFJC", &number_of_links, 1)?;
+        positive("SWFJC", "link_length", &link_length)?;
+        positive("SWFJC", "hinge_mass", &hinge_mass)?;
+        positive("SWFJC", "well_width", &well_width)?;
+        Ok(SWFJC::init(number_of_links, link_length, hinge_mass, well_width))
+    }
+    pub fn parameters(&self) -> Parameters
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: self.link_length,
+            hinge_mass: self.hinge_mass,
+            well_width: self.well_width
+        }
+    }
+}
+impl<F: Float> Parameters<F>
+{
+    fn cast<G: Float>(&self) -> Parameters<G>
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: G::from_f64(self.link_length.to_f64()),
+            hinge_mass: G::from_f64(self.hinge_mass.to_f64()),
+            well_width: G::from_f64(self.well_width.to_f64())
         }
     }
 }
@@ -70,3 +70,62 @@ mod base
         }
     }
 }
+mod error
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(SWFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).is_ok());
+        assert_eq!(SWFJC::try_init(parameters.number_of_links_minimum, -parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).err(), Some(Error::InvalidParameter{model: "SWFJC", argument: "link_length", value: -parameters.link_length_reference}));
+        assert_eq!(SWFJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, -parameters.well_width_reference).err(), Some(Error::InvalidParameter{model: "SWFJC", argument: "well_width", value: -parameters.well_width_reference}));
+    }
+}
+mod parameters
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = SWFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).parameters();
+        assert_eq!(SWFJC::init(model_parameters.number_of_links, model_parameters.link_length, model_parameters.hinge_mass, model_parameters.well_width).parameters(), model_parameters);
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let model_parameters = SWFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).parameters();
+        let json = serde_json::to_string(&model_parameters).unwrap();
+        assert_eq!(serde_json::from_str::<super::super::Parameters>(&json).unwrap(), model_parameters);
+    }
+}
+mod constants
+{
+    use super::*;
+    use crate::physics::Constants;
+    use crate::physics::single_chain::test::check_constants;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let constants = Constants::molecular();
+        let model = SWFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference, constants);
+        assert_eq!(model.constants, constants);
+        assert_eq!(model.thermodynamics.isotensional.constants, constants);
+        assert_eq!(model.parameters().constants, constants);
+        assert_eq!(SWFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).constants, Constants::molar());
+    }
+    #[test]
+    fn conventions()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let molar = SWFJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference).thermodynamics.isotensional;
+        let molecular = SWFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference, Constants::molecular()).thermodynamics.isotensional;
+        let reduced = SWFJC::init_with_constants(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.well_width_reference, Constants::reduced(&temperature)).thermodynamics.isotensional;
+        check_constants(&molar, &molecular, &reduced, &temperature, &parameters.rel_tol);
+    }
+}
@@ -1,16 +1,28 @@
 mod test;
-use std::f64::consts::PI;
-use crate::physics::
+use crate::physics::Constants;
+use crate::physics::single_chain::Isometric;
+use crate::physics::single_chain::ln_hinge_partition_function;
+use crate::physics::single_chain::swfjc::Parameters;
+use crate::math::dual::Dual;
+use crate::math::float::Float;
+use crate::math::roots::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    bracket,
+    brent,
+    refine
+};
+use super::super::
+{
+    ln_partition_function_per_link,
+    relative_ln_partition_function_per_link,
+    mean_link_projection
 };
-use crate::physics::single_chain::ZERO;
 pub struct SWFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub well_width: f64,
     number_of_links_f64: f64,
     pub nondimensional_well_parameter: f64
@@ -18,33 +30,42 @@ pub struct SWFJC
 impl SWFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> SWFJC
+    {
+        SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64, constants: Constants) -> SWFJC
     {
         SWFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             well_width,
             number_of_links_f64: number_of_links as f64,
             nondimensional_well_parameter: 1.0 + well_width/link_length,
 
         }
     }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.parameters().force(end_to_end_length, temperature)
+    }
     pub fn helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(&(*force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy(&(*force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(&(*force/BOLTZMANN_CONSTANT/temperature*self.link_length), temperature)*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_helmholtz_free_energy_per_link(&(*force/self.constants.boltzmann_constant/temperature*self.link_length), temperature)*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy(&(*force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy(&(*force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_helmholtz_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.nondimensional_relative_helmholtz_free_energy_per_link(&(*force/BOLTZMANN_CONSTANT/temperature*self.link_length))*BOLTZMANN_CONSTANT*temperature
+        self.nondimensional_relative_helmholtz_free_energy_per_link(&(*force/self.constants.boltzmann_constant/temperature*self.link_length))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_helmholtz_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
@@ -52,14 +73,84 @@ impl SWFJC
     }
     pub fn nondimensional_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-    (self.nondimensional_well_parameter.powi(2)*nondimensional_force.powi(2)*(self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force.powi(2)*nondimensional_force.sinh())/(self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0 + 3.0*nondimensional_force.ln() - (self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+    nondimensional_force*mean_link_projection(&self.nondimensional_well_parameter, nondimensional_force) - ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force) - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_force: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy(nondimensional_force, &300.0) - self.nondimensional_helmholtz_free_energy(&ZERO, &300.0)
+        self.number_of_links_f64*self.nondimensional_relative_helmholtz_free_energy_per_link(nondimensional_force)
     }
     pub fn nondimensional_relative_helmholtz_free_energy_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        self.nondimensional_helmholtz_free_energy_per_link(nondimensional_force, &300.0) - self.nondimensional_helmholtz_free_energy_per_link(&ZERO, &300.0)
+        nondimensional_force*mean_link_projection(&self.nondimensional_well_parameter, nondimensional_force) - relative_ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force)
+    }
+    fn parameters(&self) -> Parameters
+    {
+        Parameters
+        {
+            number_of_links: self.number_of_links,
+            constants: self.constants,
+            link_length: self.link_length,
+            hinge_mass: self.hinge_mass,
+            well_width: self.well_width
+        }
+    }
+}
+
+/// The helmholtz free energies of the trait are functions of the end-to-end length, which is related to the force by inverting the expected end-to-end length in the isotensional ensemble, unlike those of the model, which are functions of the force.
+impl Isometric for SWFJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::helmholtz_free_energy(self, &SWFJC::force(self, end_to_end_length, temperature), temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::relative_helmholtz_free_energy(self, &SWFJC::force(self, end_to_end_length, temperature), temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.number_of_links_f64*self.link_length*self.nondimensional_well_parameter
+    }
+    fn name(&self) -> &'static str
+    {
+        "SWFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the SWFJC model in the isometric ensemble approximated using a Legendre transformation, with the parameters, end-to-end length, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+///
+/// The force is found in double precision and then refined with a Newton step in the floating-point type, which propagates the derivatives through the inversion.
+impl<F: Float> Parameters<F>
+{
+    pub fn force(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        let parameters: Parameters = self.cast();
+        let end_to_end_length_f64 = end_to_end_length.to_f64();
+        let temperature_f64 = temperature.to_f64();
+        let function = |force: &f64| parameters.end_to_end_length(force, &temperature_f64) - end_to_end_length_f64;
+        let guess = 3.0*end_to_end_length_f64/(parameters.number_of_links as f64)*parameters.constants.boltzmann_constant*temperature_f64/parameters.link_length.powi(2);
+        let (lower, upper) = bracket(function, &guess, &0.0, &f64::INFINITY);
+        let force = brent(function, &lower, &upper);
+        let derivative = self.cast::<Dual>().end_to_end_length(&Dual::variable(force), &Dual::constant(temperature_f64)).derivative;
+        refine(|force: &F| self.end_to_end_length(force, temperature) - *end_to_end_length, &force, &derivative)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.gibbs_free_energy(&force, temperature) + force**end_to_end_length
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &F, temperature: &F) -> F
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.relative_gibbs_free_energy(&force, temperature) + force**end_to_end_length
     }
 }
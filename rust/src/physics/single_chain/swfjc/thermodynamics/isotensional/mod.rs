@@ -1,17 +1,30 @@
 mod test;
 pub mod legendre;
-use std::f64::consts::PI;
-use crate::physics::
+use crate::math::roots::
 {
-    PLANCK_CONSTANT,
-    BOLTZMANN_CONSTANT
+    bracket,
+    brent
+};
+use crate::physics::Constants;
+use crate::physics::single_chain::
+{
+    Isotensional,
+    ln_hinge_partition_function
+};
+use crate::physics::single_chain::swfjc::Parameters;
+use crate::math::float::Float;
+use super::
+{
+    ln_partition_function_per_link,
+    relative_ln_partition_function_per_link,
+    mean_link_projection
 };
-use crate::physics::single_chain::ZERO;
 pub struct SWFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub well_width: f64,
     number_of_links_f64: f64,
     pub nondimensional_well_parameter: f64,
@@ -20,68 +33,133 @@ pub struct SWFJC
 impl SWFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> SWFJC
+    {
+        SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64, constants: Constants) -> SWFJC
     {
         SWFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             well_width,
             number_of_links_f64: number_of_links as f64,
             nondimensional_well_parameter: 1.0 + well_width/link_length,
-            legendre: self::legendre::SWFJC::init(number_of_links, link_length, hinge_mass, well_width)
+            legendre: self::legendre::SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, constants)
         }
     }
     pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*self.link_length*((self.nondimensional_well_parameter.powi(2)*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force)
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.number_of_links_f64*self.link_length*(mean_link_projection(&self.nondimensional_well_parameter, &nondimensional_force))
     }
     pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        self.link_length*((self.nondimensional_well_parameter.powi(2)*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force)
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.link_length*(mean_link_projection(&self.nondimensional_well_parameter, &nondimensional_force))
     }
     pub fn nondimensional_end_to_end_length(&self, nondimensional_force: &f64) -> f64
     {
-        self.number_of_links_f64*((self.nondimensional_well_parameter.powi(2)*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force)
+        self.number_of_links_f64*(mean_link_projection(&self.nondimensional_well_parameter, nondimensional_force))
     }
     pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        (self.nondimensional_well_parameter.powi(2)*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.sinh())/(self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()) - 3.0/nondimensional_force
+        mean_link_projection(&self.nondimensional_well_parameter, nondimensional_force)
+    }
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(end_to_end_length/self.number_of_links_f64/self.link_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    pub fn nondimensional_force(&self, nondimensional_end_to_end_length_per_link: &f64) -> f64
+    {
+        if nondimensional_end_to_end_length_per_link.abs() >= self.nondimensional_well_parameter
+        {
+            return f64::NAN
+        }
+        if nondimensional_end_to_end_length_per_link < &0.0
+        {
+            return -self.nondimensional_force(&(-nondimensional_end_to_end_length_per_link))
+        }
+        let function = |nondimensional_force: &f64| self.nondimensional_end_to_end_length_per_link(nondimensional_force) - nondimensional_end_to_end_length_per_link;
+        let (lower, upper) = bracket(function, &(3.0*nondimensional_end_to_end_length_per_link), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
     }
     pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        self.number_of_links_f64*BOLTZMANN_CONSTANT*temperature*(3.0*nondimensional_force.ln() - (self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln()) - self.number_of_links_f64*BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(-ln_partition_function_per_link(&self.nondimensional_well_parameter, &nondimensional_force)) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        let nondimensional_force = force*self.link_length/BOLTZMANN_CONSTANT/temperature;
-        BOLTZMANN_CONSTANT*temperature*(3.0*nondimensional_force.ln() - (self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln()) - BOLTZMANN_CONSTANT*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        self.constants.boltzmann_constant*temperature*(-ln_partition_function_per_link(&self.nondimensional_well_parameter, &nondimensional_force)) - self.constants.boltzmann_constant*temperature*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy(force, temperature) - self.gibbs_free_energy(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.nondimensional_relative_gibbs_free_energy(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
     }
     pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
     {
-        self.gibbs_free_energy_per_link(force, temperature) - self.gibbs_free_energy_per_link(&(ZERO*BOLTZMANN_CONSTANT*temperature/self.link_length), temperature)
+        self.nondimensional_relative_gibbs_free_energy_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
     }
     pub fn nondimensional_gibbs_free_energy(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        self.number_of_links_f64*(3.0*nondimensional_force.ln() - (self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln()) - self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        self.number_of_links_f64*(-ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force)) - self.number_of_links_f64*ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_gibbs_free_energy_per_link(&self, nondimensional_force: &f64, temperature: &f64) -> f64
     {
-        3.0*nondimensional_force.ln() - (self.nondimensional_well_parameter*nondimensional_force*(self.nondimensional_well_parameter*nondimensional_force).cosh() - (self.nondimensional_well_parameter*nondimensional_force).sinh() - nondimensional_force*nondimensional_force.cosh() + nondimensional_force.sinh()).ln() - (8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*BOLTZMANN_CONSTANT*temperature/PLANCK_CONSTANT.powi(2)).ln()
+        -ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force) - ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants)
     }
     pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy(nondimensional_force, &300.0) - self.nondimensional_gibbs_free_energy(&ZERO, &300.0)
+        -self.number_of_links_f64*relative_ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force)
     }
     pub fn nondimensional_relative_gibbs_free_energy_per_link(&self, nondimensional_force: &f64) -> f64
     {
-        self.nondimensional_gibbs_free_energy_per_link(nondimensional_force, &300.0) - self.nondimensional_gibbs_free_energy_per_link(&ZERO, &300.0)
+        -relative_ln_partition_function_per_link(&self.nondimensional_well_parameter, nondimensional_force)
+    }
+}
+impl Isotensional for SWFJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        SWFJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "SWFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+/// The generic functionality of the thermodynamics of the SWFJC model in the isotensional ensemble, with the parameters, force, and temperature in any floating-point type, so that they can be differentiated using dual numbers.
+impl<F: Float> Parameters<F>
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &F, temperature: &F) -> F
+    {
+        F::from_f64(self.number_of_links as f64)*self.link_length*mean_link_projection(&(F::from_f64(1.0) + self.well_width/self.link_length), &(*force*self.link_length/F::from_f64(self.constants.boltzmann_constant)/ *temperature))
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        -F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*(ln_partition_function_per_link(&(F::from_f64(1.0) + self.well_width/self.link_length), &(*force*self.link_length/F::from_f64(self.constants.boltzmann_constant)/ *temperature)) + ln_hinge_partition_function(&self.hinge_mass, &self.link_length, temperature, &self.constants))
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &F, temperature: &F) -> F
+    {
+        -F::from_f64(self.number_of_links as f64*self.constants.boltzmann_constant)**temperature*relative_ln_partition_function_per_link(&(F::from_f64(1.0) + self.well_width/self.link_length), &(*force*self.link_length/F::from_f64(self.constants.boltzmann_constant)/ *temperature))
     }
 }
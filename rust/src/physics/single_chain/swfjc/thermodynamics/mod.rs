@@ -1,24 +1,86 @@
 mod test;
 pub mod isotensional;
+use crate::physics::Constants;
+use crate::math::special::
+{
+    langevin,
+    langevin_derivative,
+    ln_sinhc
+};
+use crate::math::float::Float;
 pub struct SWFJC
 {
     pub hinge_mass: f64,
     pub link_length: f64,
     pub number_of_links: u8,
+    pub constants: Constants,
     pub well_width: f64,
     pub isotensional: self::isotensional::SWFJC,
 }
 impl SWFJC
 {
     pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64) -> SWFJC
+    {
+        SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, Constants::default())
+    }
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, well_width: f64, constants: Constants) -> SWFJC
     {
         SWFJC
         {
             hinge_mass,
             link_length,
             number_of_links,
+            constants,
             well_width,
-            isotensional: self::isotensional::SWFJC::init(number_of_links, link_length, hinge_mass, well_width),
+            isotensional: self::isotensional::SWFJC::init_with_constants(number_of_links, link_length, hinge_mass, well_width, constants),
         }
     }
 }
+fn ball_partition_function_series<F: Float>(x: &F, derivative: i32) -> F
+{
+    (1 + derivative..=10).map(|k: i32| F::from_f64(((2*k) as f64)/(1..=2*k + 1).map(|j| j as f64).product::<f64>()*(2*k - 2 - derivative + 1..=2*k - 2).map(|j| j as f64).product::<f64>())*x.powi(2*k - 2 - derivative)).sum()
+}
+fn ln_ball_partition_function<F: Float>(x: &F) -> F
+{
+    if x.abs() < F::from_f64(0.5)
+    {
+        ball_partition_function_series(x, 0).ln()
+    }
+    else
+    {
+        ln_sinhc(x) + (langevin(x)/ *x).ln()
+    }
+}
+fn ball_partition_function_log_derivative<F: Float>(x: &F) -> F
+{
+    if x.abs() < F::from_f64(0.5)
+    {
+        ball_partition_function_series(x, 1)/ball_partition_function_series(x, 0)
+    }
+    else
+    {
+        langevin(x) + langevin_derivative(x)/langevin(x) - F::from_f64(1.0)/ *x
+    }
+}
+fn ln_partition_function_per_link<F: Float>(nondimensional_well_parameter: &F, nondimensional_force: &F) -> F
+{
+    let ln_ratio = ln_ball_partition_function(nondimensional_force) - ln_ball_partition_function(&(*nondimensional_well_parameter**nondimensional_force)) - F::from_f64(3.0)*nondimensional_well_parameter.ln();
+    F::from_f64(3.0)*nondimensional_well_parameter.ln() + ln_ball_partition_function(&(*nondimensional_well_parameter**nondimensional_force)) + (-ln_ratio.exp()).ln_1p()
+}
+fn relative_ln_partition_function_per_link<F: Float>(nondimensional_well_parameter: &F, nondimensional_force: &F) -> F
+{
+    if (*nondimensional_well_parameter**nondimensional_force).abs() < F::from_f64(0.5)
+    {
+        let one = F::from_f64(1.0);
+        (2..=10).map(|k: i32| F::from_f64(3.0*((2*k) as f64)/(1..=2*k + 1).map(|j| j as f64).product::<f64>())*(nondimensional_well_parameter.powi(2*k + 1) - one)/(nondimensional_well_parameter.powi(3) - one)*nondimensional_force.powi(2*k - 2)).sum::<F>().ln_1p()
+    }
+    else
+    {
+        ln_partition_function_per_link(nondimensional_well_parameter, nondimensional_force) - ln_partition_function_per_link(nondimensional_well_parameter, &F::from_f64(0.0))
+    }
+}
+fn mean_link_projection<F: Float>(nondimensional_well_parameter: &F, nondimensional_force: &F) -> F
+{
+    let ratio = (ln_ball_partition_function(nondimensional_force) - ln_ball_partition_function(&(*nondimensional_well_parameter**nondimensional_force)) - F::from_f64(3.0)*nondimensional_well_parameter.ln()).exp();
+    (*nondimensional_well_parameter*ball_partition_function_log_derivative(&(*nondimensional_well_parameter**nondimensional_force)) - ratio*ball_partition_function_log_derivative(nondimensional_force))/(F::from_f64(1.0) - ratio)
+}
@@ -0,0 +1,188 @@
+mod test;
+use std::f64::consts::PI;
+use crate::physics::Constants;
+use crate::physics::single_chain::Isotensional;
+use crate::math::random::Random;
+use crate::math::special::ln_sinhc;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+static NUMBER_OF_SAMPLES: usize = 10000;
+
+static NUMBER_OF_TRIALS: usize = 8;
+
+/// The structure of the FJC model with hard-sphere hinges, a self-avoiding chain sampled using Monte Carlo.
+///
+/// Chains are grown link by link using Rosenbluth sampling, where each link is chosen among several trial orientations drawn from the distribution of a free link under the applied force, and the weight of the chain is multiplied by the fraction of trials whose hinge does not overlap any previous hinge.
+/// The mean weight of the chains is then the probability that the phantom chain under the same force does not overlap itself, and the weighted means of the end-to-end length along the force and of its square are their expected values for the self-avoiding chain.
+/// The pseudorandom number generator is reseeded upon each evaluation, so that the functions are deterministic and vary smoothly with the force apart from the sampling error.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The diameter of each hinge in units of nm, which is less than the link length.
+    pub hinge_diameter: f64,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    /// The number of chains sampled upon each evaluation.
+    pub number_of_samples: usize,
+
+    /// The seed of the pseudorandom number generator.
+    pub seed: u64,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64
+}
+
+/// The implemented functionality of the self-avoiding FJC model.
+impl FJC
+{
+    /// Initializes and returns an instance of the self-avoiding FJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64, hinge_diameter: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, hinge_diameter, Constants::default())
+    }
+    /// Initializes and returns an instance of the self-avoiding FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, hinge_diameter: f64, constants: Constants) -> FJC
+    {
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            hinge_diameter,
+            constants,
+            number_of_samples: NUMBER_OF_SAMPLES,
+            seed: 0,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length
+        }
+    }
+    /// Initializes and returns an instance of the self-avoiding FJC model, or an error if a parameter is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64, hinge_diameter: f64) -> Result<FJC, Error>
+    {
+        minimum_number_of_links("FJC", &number_of_links, 1)?;
+        positive("FJC", "link_length", &link_length)?;
+        positive("FJC", "hinge_mass", &hinge_mass)?;
+        if hinge_diameter < 0.0 || hinge_diameter >= link_length
+        {
+            return Err(Error::InvalidParameter{model: "FJC", argument: "hinge_diameter", value: hinge_diameter})
+        }
+        Ok(FJC::init(number_of_links, link_length, hinge_mass, hinge_diameter))
+    }
+    /// The probability that the phantom chain does not overlap itself.
+    pub fn survival_probability(&self) -> f64
+    {
+        self.sample(&0.0).0
+    }
+    /// The expected mean-squared end-to-end length.
+    pub fn mean_squared_end_to_end_length(&self) -> f64
+    {
+        self.sample(&0.0).2*self.contour_length.powi(2)
+    }
+    /// The ratio of the mean-squared end-to-end length to that of the phantom chain.
+    pub fn swelling_ratio(&self) -> f64
+    {
+        self.mean_squared_end_to_end_length()/self.number_of_links_f64/self.link_length.powi(2)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_end_to_end_length_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional end-to-end length per link as a function of the applied nondimensional force.
+    pub fn nondimensional_end_to_end_length_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        self.sample(nondimensional_force).1
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        let nondimensional_force = force*self.link_length/self.constants.boltzmann_constant/temperature;
+        (-self.number_of_links_f64*ln_sinhc(&nondimensional_force) - self.sample(&nondimensional_force).0.ln())*self.constants.boltzmann_constant*temperature - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
+    }
+    /// The gibbs free energy as a function of the applied force and temperature, relative to that at zero force.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional gibbs free energy as a function of the applied nondimensional force, relative to that at zero force.
+    pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
+    {
+        -self.number_of_links_f64*ln_sinhc(nondimensional_force) - (self.sample(nondimensional_force).0/self.survival_probability()).ln()
+    }
+    /// Returns the survival probability, the expected nondimensional end-to-end length per link along the force, and its expected square, given the nondimensional force.
+    fn sample(&self, nondimensional_force: &f64) -> (f64, f64, f64)
+    {
+        let mut random = Random::init(self.seed);
+        let mut trials = [[0.0; 3]; NUMBER_OF_TRIALS];
+        let mut hinges: Vec<[f64; 3]> = Vec::with_capacity(self.number_of_links as usize + 1);
+        let diameter_squared = self.hinge_diameter.powi(2);
+        let (total_weight, total_length, total_squared_length) = (0..self.number_of_samples).fold((0.0, 0.0, 0.0), |(total_weight, total_length, total_squared_length), _|
+        {
+            hinges.clear();
+            hinges.push([0.0; 3]);
+            let mut weight = 1.0;
+            for _ in 0..self.number_of_links
+            {
+                let position = hinges[hinges.len() - 1];
+                trials.iter_mut().for_each(|trial|
+                {
+                    let cosine = random.tilted_uniform(nondimensional_force);
+                    let sine = (1.0 - cosine.powi(2)).max(0.0).sqrt();
+                    let angle = 2.0*PI*random.uniform();
+                    *trial = [position[0] + self.link_length*cosine, position[1] + self.link_length*sine*angle.cos(), position[2] + self.link_length*sine*angle.sin()];
+                });
+                let allowed = trials.iter().filter(|trial| hinges[..hinges.len() - 1].iter().all(|hinge| hinge.iter().zip(trial.iter()).map(|(a, b)| (a - b).powi(2)).sum::<f64>() >= diameter_squared)).collect::<Vec<&[f64; 3]>>();
+                if allowed.is_empty()
+                {
+                    weight = 0.0;
+                    break
+                }
+                weight *= allowed.len() as f64/(NUMBER_OF_TRIALS as f64);
+                hinges.push(*allowed[random.index(allowed.len())]);
+            }
+            let end = hinges[hinges.len() - 1];
+            (total_weight + weight, total_length + weight*end[0], total_squared_length + weight*end.iter().map(|coordinate| coordinate.powi(2)).sum::<f64>())
+        });
+        (total_weight/(self.number_of_samples as f64), total_length/total_weight/self.contour_length, total_squared_length/total_weight/self.contour_length.powi(2))
+    }
+}
+
+impl Isotensional for FJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        FJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "FJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
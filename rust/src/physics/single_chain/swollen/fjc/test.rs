@@ -0,0 +1,59 @@
+#![cfg(test)]
+use super::*;
+use crate::math::special::langevin;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, 0.5*parameters.link_length_reference).is_ok());
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference, parameters.link_length_reference).err(), Some(Error::InvalidParameter{model: "FJC", argument: "hinge_diameter", value: parameters.link_length_reference}));
+    }
+}
+mod phantom
+{
+    use super::*;
+    #[test]
+    fn size()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, 0.0);
+        assert_eq!(model.survival_probability(), 1.0);
+        assert!((model.swelling_ratio() - 1.0).abs() <= 5e-2);
+    }
+    #[test]
+    fn extension()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, 0.0);
+        for nondimensional_force in [1.0, parameters.nondimensional_force_reference]
+        {
+            assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force) - langevin(&nondimensional_force)).abs() <= 1e-2);
+        }
+    }
+}
+mod self_avoiding
+{
+    use super::*;
+    #[test]
+    fn swelling()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, 0.5*parameters.link_length_reference);
+        assert!(model.survival_probability() < 1.0);
+        assert!(model.swelling_ratio() > 1.1);
+        assert!(model.nondimensional_end_to_end_length_per_link(&1.0) > langevin(&1.0));
+    }
+    #[test]
+    fn stretched()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference, 0.5*parameters.link_length_reference);
+        let nondimensional_force = parameters.nondimensional_force_reference;
+        assert!((model.nondimensional_end_to_end_length_per_link(&nondimensional_force)/langevin(&nondimensional_force) - 1.0).abs() <= parameters.rel_tol_thermodynamic_limit*1e-1);
+        assert!(model.nondimensional_relative_gibbs_free_energy(&nondimensional_force) < -model.number_of_links_f64*ln_sinhc(&nondimensional_force) + 1.0);
+    }
+}
@@ -0,0 +1,312 @@
+mod test;
+
+/// The freely-jointed chain (FJC) single-chain model with hard-sphere hinges, sampled using Monte Carlo as a reference for self-avoiding chains.
+pub mod fjc;
+
+use crate::physics::Constants;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::single_chain::Isotensional;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+static NUMBER_OF_INTERVALS: usize = 256;
+
+/// The qualities of the solvent of a chain, which set how the mean-squared end-to-end length of a subchain scales with its number of links.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Quality
+{
+    /// The theta solvent, where the chain is ideal on every scale.
+    Theta,
+    /// A solvent where the chain is self-similar with the Flory exponent on scales beyond a link, which is 3/5 in a good solvent and 1/3 in a poor solvent.
+    FloryExponent(f64),
+    /// A solvent with the excluded volume of a link in units of the link length cubed, which is positive in a good solvent and negative in a poor solvent, where the chain is ideal within thermal blobs of the inverse square of the excluded volume in links, and beyond them swollen with the Flory exponent 3/5 or collapsed with the Flory exponent 1/3.
+    ExcludedVolume(f64)
+}
+
+impl Quality
+{
+    /// Returns an error unless the Flory exponent is at least 1/3 and less than one, or the excluded volume is at most the volume of a link in magnitude.
+    pub fn validate(&self) -> Result<(), Error>
+    {
+        match self
+        {
+            Quality::Theta => Ok(()),
+            Quality::FloryExponent(flory_exponent) =>
+            {
+                if (1.0/3.0..1.0).contains(flory_exponent)
+                {
+                    Ok(())
+                }
+                else
+                {
+                    Err(Error::InvalidParameter{model: "Quality", argument: "flory_exponent", value: *flory_exponent})
+                }
+            },
+            Quality::ExcludedVolume(excluded_volume) =>
+            {
+                if excluded_volume.abs() <= 1.0
+                {
+                    Ok(())
+                }
+                else
+                {
+                    Err(Error::InvalidParameter{model: "Quality", argument: "excluded_volume", value: *excluded_volume})
+                }
+            }
+        }
+    }
+    /// The Flory exponent of long chains.
+    pub fn flory_exponent(&self) -> f64
+    {
+        match self
+        {
+            Quality::Theta => 0.5,
+            Quality::FloryExponent(flory_exponent) => *flory_exponent,
+            Quality::ExcludedVolume(excluded_volume) =>
+            {
+                if excluded_volume > &0.0
+                {
+                    0.6
+                }
+                else if excluded_volume < &0.0
+                {
+                    1.0/3.0
+                }
+                else
+                {
+                    0.5
+                }
+            }
+        }
+    }
+    /// The number of links in a thermal blob, within which the chain is ideal, which is infinite in the theta solvent.
+    pub fn thermal_blob_number_of_links(&self) -> f64
+    {
+        match self
+        {
+            Quality::Theta => f64::INFINITY,
+            Quality::FloryExponent(_) => 1.0,
+            Quality::ExcludedVolume(excluded_volume) => excluded_volume.powi(-2)
+        }
+    }
+    /// The ratio of the mean-squared end-to-end length of a subchain to that of the ideal subchain as a function of its number of links.
+    pub fn swelling_ratio(&self, number_of_links: &f64) -> f64
+    {
+        let thermal_blob_number_of_links = self.thermal_blob_number_of_links();
+        if number_of_links > &thermal_blob_number_of_links
+        {
+            (number_of_links/thermal_blob_number_of_links).powf(2.0*self.flory_exponent() - 1.0)
+        }
+        else
+        {
+            1.0
+        }
+    }
+}
+
+/// The functionality of phantom single-chain models in the isotensional ensemble that excluded volume can swell or collapse.
+pub trait Phantom: Isotensional
+{
+    /// The number of links in the chain.
+    fn number_of_links(&self) -> u8;
+    /// The length of each link in the chain, which sets the sizes of the blobs.
+    fn link_length(&self) -> f64;
+}
+
+/// The structure of a single-chain model with excluded volume.
+///
+/// The chain under a force is a string of tension blobs, each being the subchain whose root-mean-squared end-to-end length is the thermal energy divided by the force, so excluded volume swells or collapses the chain within each blob while the blobs are aligned by the force.
+/// The expected end-to-end length is therefore that of the phantom model times the swelling ratio of a blob, which is that of the whole chain at small forces, giving the linear response of the swollen or collapsed chain, and approaches one at large forces, where the blobs are smaller than a link and the phantom model is recovered, with the Pincus scaling of the end-to-end length with the force to the power of the inverse Flory exponent minus one in between.
+/// The relative gibbs free energy integrates the end-to-end length over the force using Simpson's rule, piecewise between the forces where the blob spans the chain or a thermal blob.
+pub struct Swollen<T>
+{
+    /// The phantom single-chain model, in the isotensional ensemble.
+    pub model: T,
+
+    /// The number of links in the chain, from the phantom model.
+    pub number_of_links: u8,
+
+    /// The length of each link in the chain in units of nm, from the phantom model.
+    pub link_length: f64,
+
+    /// The quality of the solvent.
+    pub quality: Quality,
+
+    /// The physical constants used by the model.
+    pub constants: Constants
+}
+
+/// The implemented functionality of single-chain models with excluded volume.
+impl<T: Phantom> Swollen<T>
+{
+    /// Initializes and returns an instance of the single-chain model with excluded volume.
+    pub fn init(model: T, quality: Quality) -> Swollen<T>
+    {
+        Swollen::init_with_constants(model, quality, Constants::default())
+    }
+    /// Initializes and returns an instance of the single-chain model with excluded volume, using the physical constants.
+    pub fn init_with_constants(model: T, quality: Quality, constants: Constants) -> Swollen<T>
+    {
+        Swollen
+        {
+            number_of_links: model.number_of_links(),
+            link_length: model.link_length(),
+            model,
+            quality,
+            constants
+        }
+    }
+    /// Initializes and returns an instance of the single-chain model with excluded volume, or an error if a parameter of the phantom model or the quality is invalid.
+    pub fn try_init(model: T, quality: Quality) -> Result<Swollen<T>, Error>
+    {
+        minimum_number_of_links("Swollen", &model.number_of_links(), 1)?;
+        positive("Swollen", "link_length", &model.link_length())?;
+        quality.validate()?;
+        Ok(Swollen::init(model, quality))
+    }
+    /// The number of links in a tension blob as a function of the applied force and temperature, which is at most the number of links in the chain.
+    pub fn blob_number_of_links(&self, force: &f64, temperature: &f64) -> f64
+    {
+        let ideal_number_of_links = (self.constants.boltzmann_constant*temperature/force/self.link_length).powi(2);
+        let thermal_blob_number_of_links = self.quality.thermal_blob_number_of_links();
+        let number_of_links = if ideal_number_of_links > thermal_blob_number_of_links
+        {
+            let flory_exponent = self.quality.flory_exponent();
+            (ideal_number_of_links*thermal_blob_number_of_links.powf(2.0*flory_exponent - 1.0)).powf(0.5/flory_exponent)
+        }
+        else
+        {
+            ideal_number_of_links
+        };
+        number_of_links.min(self.number_of_links as f64)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.model.end_to_end_length(force, temperature)*self.quality.swelling_ratio(&self.blob_number_of_links(&force.abs(), temperature))
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature, inverting the isotensional relation.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if end_to_end_length == &0.0
+        {
+            return 0.0
+        }
+        let residual = |force: &f64| self.end_to_end_length(force, temperature) - end_to_end_length.abs();
+        let (lower, upper) = bracket(residual, &(self.constants.boltzmann_constant*temperature/self.link_length), &0.0, &f64::INFINITY);
+        end_to_end_length.signum()*brent(residual, &lower, &upper)
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.model.gibbs_free_energy(&0.0, temperature) + self.relative_gibbs_free_energy(force, temperature)
+    }
+    /// The gibbs free energy as a function of the applied force and temperature, relative to that at zero force.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        let thermal_force = self.constants.boltzmann_constant*temperature/self.link_length;
+        let thermal_blob_number_of_links = self.quality.thermal_blob_number_of_links();
+        let number_of_links = self.number_of_links as f64;
+        let chain_blob_force = if number_of_links > thermal_blob_number_of_links
+        {
+            thermal_force/number_of_links.powf(self.quality.flory_exponent())*thermal_blob_number_of_links.powf(self.quality.flory_exponent() - 0.5)
+        }
+        else
+        {
+            thermal_force/number_of_links.sqrt()
+        };
+        let mut limits = vec![0.0, chain_blob_force, thermal_force/thermal_blob_number_of_links.sqrt()];
+        limits.retain(|limit| limit < &force.abs());
+        limits.push(force.abs());
+        limits.sort_by(|a, b| a.total_cmp(b));
+        -limits.windows(2).map(|limit|
+        {
+            let step = (limit[1] - limit[0])/(NUMBER_OF_INTERVALS as f64);
+            (0..=NUMBER_OF_INTERVALS).map(|index|
+            {
+                let weight = if index == 0 || index == NUMBER_OF_INTERVALS {1.0} else if index % 2 == 1 {4.0} else {2.0};
+                weight*self.end_to_end_length(&(limit[0] + index as f64*step), temperature)
+            }).sum::<f64>()*step/3.0
+        }).sum::<f64>()
+    }
+}
+
+impl<T: Phantom> Isotensional for Swollen<T>
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Swollen::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Swollen::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        Swollen::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "Swollen"
+    }
+    fn constants(&self) -> Constants
+    {
+        self.constants
+    }
+}
+
+impl Phantom for super::ideal::thermodynamics::isotensional::Ideal
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn link_length(&self) -> f64
+    {
+        self.link_length
+    }
+}
+
+impl Phantom for super::fjc::thermodynamics::isotensional::FJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn link_length(&self) -> f64
+    {
+        self.link_length
+    }
+}
+
+impl Phantom for super::efjc::thermodynamics::isotensional::EFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn link_length(&self) -> f64
+    {
+        self.link_length
+    }
+}
+
+impl Phantom for super::swfjc::thermodynamics::isotensional::SWFJC
+{
+    fn number_of_links(&self) -> u8
+    {
+        self.number_of_links
+    }
+    fn link_length(&self) -> f64
+    {
+        self.link_length
+    }
+}
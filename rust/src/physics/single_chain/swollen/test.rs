@@ -0,0 +1,120 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::single_chain::ideal::thermodynamics::isotensional::Ideal;
+use crate::physics::single_chain::fjc::thermodynamics::isotensional::FJC;
+mod base
+{
+    use super::*;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let model = Swollen::init(FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::Theta);
+        assert_eq!(model.number_of_links, parameters.number_of_links_maximum);
+        assert_eq!(model.link_length, parameters.link_length_reference);
+    }
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(Swollen::try_init(FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::ExcludedVolume(0.5)).is_ok());
+        assert_eq!(Swollen::try_init(FJC::init(0, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::Theta).err(), Some(Error::InvalidParameter{model: "Swollen", argument: "number_of_links", value: 0.0}));
+        assert_eq!(Swollen::try_init(Ideal::init(parameters.number_of_links_maximum, -1.0, parameters.hinge_mass_reference), Quality::Theta).err(), Some(Error::InvalidParameter{model: "Swollen", argument: "link_length", value: -1.0}));
+        assert_eq!(Swollen::try_init(Ideal::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::FloryExponent(1.0)).err(), Some(Error::InvalidParameter{model: "Quality", argument: "flory_exponent", value: 1.0}));
+    }
+}
+mod quality
+{
+    use super::*;
+    #[test]
+    fn validate()
+    {
+        assert!(Quality::Theta.validate().is_ok());
+        assert!(Quality::FloryExponent(0.6).validate().is_ok());
+        assert_eq!(Quality::FloryExponent(1.0).validate(), Err(Error::InvalidParameter{model: "Quality", argument: "flory_exponent", value: 1.0}));
+        assert_eq!(Quality::ExcludedVolume(-2.0).validate(), Err(Error::InvalidParameter{model: "Quality", argument: "excluded_volume", value: -2.0}));
+    }
+    #[test]
+    fn swelling_ratio()
+    {
+        let parameters = Parameters::default();
+        assert_eq!(Quality::Theta.swelling_ratio(&1e3), 1.0);
+        assert!((Quality::FloryExponent(0.6).swelling_ratio(&1e3)/1e3_f64.powf(0.2) - 1.0).abs() <= parameters.rel_tol);
+        assert_eq!(Quality::ExcludedVolume(0.1).swelling_ratio(&50.0), 1.0);
+        assert!((Quality::ExcludedVolume(0.1).swelling_ratio(&1e3)/10_f64.powf(0.2) - 1.0).abs() <= parameters.rel_tol);
+        assert!((Quality::ExcludedVolume(-0.1).swelling_ratio(&1e3)/10_f64.powf(-1.0/3.0) - 1.0).abs() <= parameters.rel_tol);
+        assert_eq!(Quality::ExcludedVolume(0.0).swelling_ratio(&1e3), 1.0);
+    }
+}
+mod isotensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn theta()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Swollen::init(FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::Theta);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let force = parameters.nondimensional_force_reference*rng.gen::<f64>()*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            assert_eq!(model.end_to_end_length(&force, &temperature), model.model.end_to_end_length(&force, &temperature));
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/model.model.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn linear_response()
+    {
+        let parameters = Parameters::default();
+        for quality in [Quality::FloryExponent(0.6), Quality::FloryExponent(1.0/3.0), Quality::ExcludedVolume(0.5)]
+        {
+            let model = Swollen::init(FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference), quality);
+            let force = 1e-6*BOLTZMANN_CONSTANT*parameters.temperature_reference/parameters.link_length_reference;
+            let ratio = model.end_to_end_length(&force, &parameters.temperature_reference)/model.model.end_to_end_length(&force, &parameters.temperature_reference);
+            assert!((ratio/quality.swelling_ratio(&(parameters.number_of_links_maximum as f64)) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn pincus()
+    {
+        let parameters = Parameters::default();
+        for flory_exponent in [0.6, 1.0/3.0]
+        {
+            let model = Swollen::init(Ideal::init(u8::MAX, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::FloryExponent(flory_exponent));
+            let force = 50_f64.powf(-flory_exponent)*BOLTZMANN_CONSTANT*parameters.temperature_reference/parameters.link_length_reference;
+            let slope = (model.end_to_end_length(&(1.01*force), &parameters.temperature_reference)/model.end_to_end_length(&(force/1.01), &parameters.temperature_reference)).ln()/(1.01_f64.powi(2)).ln();
+            assert!((slope*flory_exponent/(1.0 - flory_exponent) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn phantom()
+    {
+        let parameters = Parameters::default();
+        let model = Swollen::init(FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::ExcludedVolume(-0.5));
+        let force = parameters.nondimensional_force_reference*BOLTZMANN_CONSTANT*parameters.temperature_reference/parameters.link_length_reference;
+        assert_eq!(model.end_to_end_length(&force, &parameters.temperature_reference), model.model.end_to_end_length(&force, &parameters.temperature_reference));
+    }
+    #[test]
+    fn gibbs()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Swollen::init(FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference), Quality::ExcludedVolume(0.5*(1.0 - 2.0*rng.gen::<f64>())));
+            let temperature = parameters.temperature_reference;
+            let force = 10.0*rng.gen::<f64>()*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let h = 1e-6*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let derivative = (model.gibbs_free_energy(&(force + h), &temperature) - model.gibbs_free_energy(&(force - h), &temperature))/(2.0*h);
+            assert!((derivative/model.end_to_end_length(&force, &temperature) + 1.0).abs() <= 1e-4);
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            assert!((model.force(&end_to_end_length, &temperature)/force - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
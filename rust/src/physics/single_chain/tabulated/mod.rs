@@ -0,0 +1,361 @@
+mod test;
+use crate::physics::Constants;
+use crate::math::interpolation::Interpolant;
+use crate::physics::single_chain::
+{
+    Isometric,
+    Isotensional,
+    ModifiedCanonical
+};
+
+/// The structure of the thermodynamic functions of a single-chain model in the isometric ensemble tabulated at a temperature.
+///
+/// Arguments inside of the tabulated range at the tabulated temperature are interpolated, and all others are passed to the model.
+pub struct TabulatedIsometric<T>
+{
+    /// The single-chain model that was tabulated.
+    pub model: T,
+
+    /// The temperature at which the model was tabulated.
+    pub temperature: f64,
+
+    /// The interpolant of the expected force.
+    pub force: Interpolant,
+
+    /// The interpolant of the helmholtz free energy.
+    pub helmholtz_free_energy: Interpolant,
+
+    /// The interpolant of the relative helmholtz free energy.
+    pub relative_helmholtz_free_energy: Interpolant
+}
+
+/// The implemented functionality of tabulated models in the isometric ensemble.
+impl<T: Isometric> TabulatedIsometric<T>
+{
+    /// Initializes and returns the model tabulated at the temperature from the minimum to the maximum end-to-end length to within the tolerance.
+    pub fn init(model: T, temperature: f64, minimum: &f64, maximum: &f64, tolerance: &f64) -> TabulatedIsometric<T>
+    {
+        TabulatedIsometric
+        {
+            force: Interpolant::init(|end_to_end_length| model.force(end_to_end_length, &temperature), minimum, maximum, tolerance),
+            helmholtz_free_energy: Interpolant::init(|end_to_end_length| model.helmholtz_free_energy(end_to_end_length, &temperature), minimum, maximum, tolerance),
+            relative_helmholtz_free_energy: Interpolant::init(|end_to_end_length| model.relative_helmholtz_free_energy(end_to_end_length, &temperature), minimum, maximum, tolerance),
+            model,
+            temperature
+        }
+    }
+    /// Returns the tables in JSON format, without the model.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String
+    {
+        serde_json::to_string(&(self.temperature, &self.force, &self.helmholtz_free_energy, &self.relative_helmholtz_free_energy)).expect("interpolants always serialize")
+    }
+    /// Returns the model with the tables read from JSON format, or the error from parsing them.
+    #[cfg(feature = "serde")]
+    pub fn from_json(model: T, json: &str) -> Result<TabulatedIsometric<T>, serde_json::Error>
+    {
+        let (temperature, force, helmholtz_free_energy, relative_helmholtz_free_energy) = serde_json::from_str(json)?;
+        Ok(TabulatedIsometric
+        {
+            model,
+            temperature,
+            force,
+            helmholtz_free_energy,
+            relative_helmholtz_free_energy
+        })
+    }
+    fn tabulated(&self, end_to_end_length: &f64, temperature: &f64) -> bool
+    {
+        temperature == &self.temperature && end_to_end_length >= &self.force.minimum() && end_to_end_length <= &self.force.maximum()
+    }
+}
+
+impl<T: Isometric> Isometric for TabulatedIsometric<T>
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(end_to_end_length, temperature)
+        {
+            self.force.evaluate(end_to_end_length)
+        }
+        else
+        {
+            self.model.force(end_to_end_length, temperature)
+        }
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(end_to_end_length, temperature)
+        {
+            self.helmholtz_free_energy.evaluate(end_to_end_length)
+        }
+        else
+        {
+            self.model.helmholtz_free_energy(end_to_end_length, temperature)
+        }
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(end_to_end_length, temperature)
+        {
+            self.relative_helmholtz_free_energy.evaluate(end_to_end_length)
+        }
+        else
+        {
+            self.model.relative_helmholtz_free_energy(end_to_end_length, temperature)
+        }
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.model.maximum_end_to_end_length()
+    }
+    fn name(&self) -> &'static str
+    {
+        self.model.name()
+    }
+    fn constants(&self) -> Constants
+    {
+        self.model.constants()
+    }
+}
+
+/// The structure of the thermodynamic functions of a single-chain model in the isotensional ensemble tabulated at a temperature.
+///
+/// Arguments inside of the tabulated range at the tabulated temperature are interpolated, and all others are passed to the model.
+pub struct TabulatedIsotensional<T>
+{
+    /// The single-chain model that was tabulated.
+    pub model: T,
+
+    /// The temperature at which the model was tabulated.
+    pub temperature: f64,
+
+    /// The interpolant of the expected end-to-end length.
+    pub end_to_end_length: Interpolant,
+
+    /// The interpolant of the gibbs free energy.
+    pub gibbs_free_energy: Interpolant,
+
+    /// The interpolant of the relative gibbs free energy.
+    pub relative_gibbs_free_energy: Interpolant
+}
+
+/// The implemented functionality of tabulated models in the isotensional ensemble.
+impl<T: Isotensional> TabulatedIsotensional<T>
+{
+    /// Initializes and returns the model tabulated at the temperature from the minimum to the maximum force to within the tolerance.
+    pub fn init(model: T, temperature: f64, minimum: &f64, maximum: &f64, tolerance: &f64) -> TabulatedIsotensional<T>
+    {
+        TabulatedIsotensional
+        {
+            end_to_end_length: Interpolant::init(|force| model.end_to_end_length(force, &temperature), minimum, maximum, tolerance),
+            gibbs_free_energy: Interpolant::init(|force| model.gibbs_free_energy(force, &temperature), minimum, maximum, tolerance),
+            relative_gibbs_free_energy: Interpolant::init(|force| model.relative_gibbs_free_energy(force, &temperature), minimum, maximum, tolerance),
+            model,
+            temperature
+        }
+    }
+    /// Returns the tables in JSON format, without the model.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String
+    {
+        serde_json::to_string(&(self.temperature, &self.end_to_end_length, &self.gibbs_free_energy, &self.relative_gibbs_free_energy)).expect("interpolants always serialize")
+    }
+    /// Returns the model with the tables read from JSON format, or the error from parsing them.
+    #[cfg(feature = "serde")]
+    pub fn from_json(model: T, json: &str) -> Result<TabulatedIsotensional<T>, serde_json::Error>
+    {
+        let (temperature, end_to_end_length, gibbs_free_energy, relative_gibbs_free_energy) = serde_json::from_str(json)?;
+        Ok(TabulatedIsotensional
+        {
+            model,
+            temperature,
+            end_to_end_length,
+            gibbs_free_energy,
+            relative_gibbs_free_energy
+        })
+    }
+    fn tabulated(&self, force: &f64, temperature: &f64) -> bool
+    {
+        temperature == &self.temperature && force >= &self.end_to_end_length.minimum() && force <= &self.end_to_end_length.maximum()
+    }
+}
+
+impl<T: Isotensional> Isotensional for TabulatedIsotensional<T>
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(force, temperature)
+        {
+            self.end_to_end_length.evaluate(force)
+        }
+        else
+        {
+            self.model.end_to_end_length(force, temperature)
+        }
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(force, temperature)
+        {
+            self.gibbs_free_energy.evaluate(force)
+        }
+        else
+        {
+            self.model.gibbs_free_energy(force, temperature)
+        }
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(force, temperature)
+        {
+            self.relative_gibbs_free_energy.evaluate(force)
+        }
+        else
+        {
+            self.model.relative_gibbs_free_energy(force, temperature)
+        }
+    }
+    fn name(&self) -> &'static str
+    {
+        self.model.name()
+    }
+    fn constants(&self) -> Constants
+    {
+        self.model.constants()
+    }
+}
+
+/// The structure of the thermodynamic functions of a single-chain model in the modified canonical ensemble tabulated at a potential stiffness and temperature.
+///
+/// Arguments inside of the tabulated range at the tabulated potential stiffness and temperature are interpolated, and all others are passed to the model.
+pub struct TabulatedModifiedCanonical<T>
+{
+    /// The single-chain model that was tabulated.
+    pub model: T,
+
+    /// The potential stiffness at which the model was tabulated.
+    pub potential_stiffness: f64,
+
+    /// The temperature at which the model was tabulated.
+    pub temperature: f64,
+
+    /// The interpolant of the expected end-to-end length.
+    pub end_to_end_length: Interpolant,
+
+    /// The interpolant of the expected force.
+    pub force: Interpolant,
+
+    /// The interpolant of the helmholtz free energy.
+    pub helmholtz_free_energy: Interpolant,
+
+    /// The interpolant of the relative helmholtz free energy.
+    pub relative_helmholtz_free_energy: Interpolant
+}
+
+/// The implemented functionality of tabulated models in the modified canonical ensemble.
+///
+/// The gibbs free energies differ from the helmholtz free energies only by the energy stored in the potential, so they are evaluated from the interpolants of the helmholtz free energies.
+impl<T: ModifiedCanonical> TabulatedModifiedCanonical<T>
+{
+    /// Initializes and returns the model tabulated at the potential stiffness and temperature from the minimum to the maximum potential distance to within the tolerance.
+    pub fn init(model: T, potential_stiffness: f64, temperature: f64, minimum: &f64, maximum: &f64, tolerance: &f64) -> TabulatedModifiedCanonical<T>
+    {
+        TabulatedModifiedCanonical
+        {
+            end_to_end_length: Interpolant::init(|potential_distance| model.end_to_end_length(potential_distance, &potential_stiffness, &temperature), minimum, maximum, tolerance),
+            force: Interpolant::init(|potential_distance| model.force(potential_distance, &potential_stiffness, &temperature), minimum, maximum, tolerance),
+            helmholtz_free_energy: Interpolant::init(|potential_distance| model.helmholtz_free_energy(potential_distance, &potential_stiffness, &temperature), minimum, maximum, tolerance),
+            relative_helmholtz_free_energy: Interpolant::init(|potential_distance| model.relative_helmholtz_free_energy(potential_distance, &potential_stiffness, &temperature), minimum, maximum, tolerance),
+            model,
+            potential_stiffness,
+            temperature
+        }
+    }
+    /// Returns the tables in JSON format, without the model.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String
+    {
+        serde_json::to_string(&(self.potential_stiffness, self.temperature, &self.end_to_end_length, &self.force, &self.helmholtz_free_energy, &self.relative_helmholtz_free_energy)).expect("interpolants always serialize")
+    }
+    /// Returns the model with the tables read from JSON format, or the error from parsing them.
+    #[cfg(feature = "serde")]
+    pub fn from_json(model: T, json: &str) -> Result<TabulatedModifiedCanonical<T>, serde_json::Error>
+    {
+        let (potential_stiffness, temperature, end_to_end_length, force, helmholtz_free_energy, relative_helmholtz_free_energy) = serde_json::from_str(json)?;
+        Ok(TabulatedModifiedCanonical
+        {
+            model,
+            potential_stiffness,
+            temperature,
+            end_to_end_length,
+            force,
+            helmholtz_free_energy,
+            relative_helmholtz_free_energy
+        })
+    }
+    fn tabulated(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> bool
+    {
+        potential_stiffness == &self.potential_stiffness && temperature == &self.temperature && potential_distance >= &self.force.minimum() && potential_distance <= &self.force.maximum()
+    }
+}
+
+impl<T: ModifiedCanonical> ModifiedCanonical for TabulatedModifiedCanonical<T>
+{
+    fn end_to_end_length(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(potential_distance, potential_stiffness, temperature)
+        {
+            self.end_to_end_length.evaluate(potential_distance)
+        }
+        else
+        {
+            self.model.end_to_end_length(potential_distance, potential_stiffness, temperature)
+        }
+    }
+    fn force(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(potential_distance, potential_stiffness, temperature)
+        {
+            self.force.evaluate(potential_distance)
+        }
+        else
+        {
+            self.model.force(potential_distance, potential_stiffness, temperature)
+        }
+    }
+    fn helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(potential_distance, potential_stiffness, temperature)
+        {
+            self.helmholtz_free_energy.evaluate(potential_distance)
+        }
+        else
+        {
+            self.model.helmholtz_free_energy(potential_distance, potential_stiffness, temperature)
+        }
+    }
+    fn relative_helmholtz_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        if self.tabulated(potential_distance, potential_stiffness, temperature)
+        {
+            self.relative_helmholtz_free_energy.evaluate(potential_distance)
+        }
+        else
+        {
+            self.model.relative_helmholtz_free_energy(potential_distance, potential_stiffness, temperature)
+        }
+    }
+    fn gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        self.helmholtz_free_energy(potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)
+    }
+    fn relative_gibbs_free_energy(&self, potential_distance: &f64, potential_stiffness: &f64, temperature: &f64) -> f64
+    {
+        self.relative_helmholtz_free_energy(potential_distance, potential_stiffness, temperature) - 0.5*potential_stiffness*potential_distance.powi(2)
+    }
+    fn constants(&self) -> Constants
+    {
+        self.model.constants()
+    }
+}
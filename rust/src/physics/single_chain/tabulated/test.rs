@@ -0,0 +1,158 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::fjc::thermodynamics::
+{
+    isometric::FJC as FJCIsometric,
+    isotensional::FJC as FJCIsotensional,
+    modified_canonical::FJC as FJCModifiedCanonical
+};
+use crate::physics::single_chain::test::Parameters;
+use rand::Rng;
+static TOLERANCE: f64 = 1e-8;
+mod isometric
+{
+    use super::*;
+    #[test]
+    fn interpolated()
+    {
+        let parameters = Parameters::default();
+        let mut rng = rand::thread_rng();
+        let model = FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let maximum = 0.9*model.maximum_end_to_end_length();
+        let temperature = parameters.temperature_reference;
+        let tabulated = TabulatedIsometric::init(FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), temperature, &0.0, &maximum, &TOLERANCE);
+        for _ in 0..parameters.number_of_loops
+        {
+            let end_to_end_length = maximum*rng.gen::<f64>();
+            let force = model.force(&end_to_end_length, &temperature);
+            let helmholtz_free_energy = model.helmholtz_free_energy(&end_to_end_length, &temperature);
+            let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&end_to_end_length, &temperature);
+            assert!((Isometric::force(&tabulated, &end_to_end_length, &temperature) - force).abs() <= 1e1*TOLERANCE*force.abs().max(1.0));
+            assert!((Isometric::helmholtz_free_energy(&tabulated, &end_to_end_length, &temperature) - helmholtz_free_energy).abs() <= 1e1*TOLERANCE*helmholtz_free_energy.abs().max(1.0));
+            assert!((Isometric::relative_helmholtz_free_energy(&tabulated, &end_to_end_length, &temperature) - relative_helmholtz_free_energy).abs() <= 1e1*TOLERANCE*relative_helmholtz_free_energy.abs().max(1.0));
+        }
+    }
+    #[test]
+    fn untabulated()
+    {
+        let parameters = Parameters::default();
+        let model = FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let maximum = 0.5*model.maximum_end_to_end_length();
+        let temperature = parameters.temperature_reference;
+        let tabulated = TabulatedIsometric::init(FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), temperature, &0.0, &maximum, &TOLERANCE);
+        let end_to_end_length = 0.75*model.maximum_end_to_end_length();
+        assert_eq!(Isometric::force(&tabulated, &end_to_end_length, &temperature), model.force(&end_to_end_length, &temperature));
+        assert_eq!(Isometric::force(&tabulated, &maximum, &(2.0*temperature)), model.force(&maximum, &(2.0*temperature)));
+        assert_eq!(tabulated.maximum_end_to_end_length(), model.maximum_end_to_end_length());
+        assert!(tabulated.try_force(&model.maximum_end_to_end_length(), &temperature).is_err());
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let tabulated = TabulatedIsometric::init(FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), temperature, &0.0, &1.0, &TOLERANCE);
+        let reloaded = TabulatedIsometric::from_json(FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), &tabulated.to_json()).unwrap();
+        assert_eq!(reloaded.temperature, temperature);
+        assert_eq!(reloaded.force, tabulated.force);
+        assert_eq!(reloaded.helmholtz_free_energy, tabulated.helmholtz_free_energy);
+        assert_eq!(reloaded.relative_helmholtz_free_energy, tabulated.relative_helmholtz_free_energy);
+        assert!(TabulatedIsometric::from_json(FJCIsometric::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), "[]").is_err());
+    }
+}
+mod isotensional
+{
+    use super::*;
+    #[test]
+    fn interpolated()
+    {
+        let parameters = Parameters::default();
+        let mut rng = rand::thread_rng();
+        let model = FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let temperature = parameters.temperature_reference;
+        let maximum = parameters.nondimensional_force_reference*crate::physics::BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+        let tabulated = TabulatedIsotensional::init(FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), temperature, &0.0, &maximum, &TOLERANCE);
+        for _ in 0..parameters.number_of_loops
+        {
+            let force = maximum*rng.gen::<f64>();
+            let end_to_end_length = model.end_to_end_length(&force, &temperature);
+            let gibbs_free_energy = model.gibbs_free_energy(&force, &temperature);
+            let relative_gibbs_free_energy = model.relative_gibbs_free_energy(&force, &temperature);
+            assert!((Isotensional::end_to_end_length(&tabulated, &force, &temperature) - end_to_end_length).abs() <= 1e1*TOLERANCE*end_to_end_length.abs().max(1.0));
+            assert!((Isotensional::gibbs_free_energy(&tabulated, &force, &temperature) - gibbs_free_energy).abs() <= 1e1*TOLERANCE*gibbs_free_energy.abs().max(1.0));
+            assert!((Isotensional::relative_gibbs_free_energy(&tabulated, &force, &temperature) - relative_gibbs_free_energy).abs() <= 1e1*TOLERANCE*relative_gibbs_free_energy.abs().max(1.0));
+        }
+        let force = 2.0*maximum;
+        assert_eq!(Isotensional::end_to_end_length(&tabulated, &force, &temperature), model.end_to_end_length(&force, &temperature));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let tabulated = TabulatedIsotensional::init(FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), temperature, &0.0, &1e3, &TOLERANCE);
+        let reloaded = TabulatedIsotensional::from_json(FJCIsotensional::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), &tabulated.to_json()).unwrap();
+        assert_eq!(reloaded.temperature, temperature);
+        assert_eq!(reloaded.end_to_end_length, tabulated.end_to_end_length);
+        assert_eq!(reloaded.gibbs_free_energy, tabulated.gibbs_free_energy);
+        assert_eq!(reloaded.relative_gibbs_free_energy, tabulated.relative_gibbs_free_energy);
+    }
+}
+mod modified_canonical
+{
+    use super::*;
+    #[test]
+    fn interpolated()
+    {
+        let parameters = Parameters::default();
+        let mut rng = rand::thread_rng();
+        let model = FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let temperature = parameters.temperature_reference;
+        let potential_stiffness = parameters.nondimensional_potential_stiffness_reference*crate::physics::BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference.powi(2);
+        let maximum = parameters.nondimensional_potential_distance_reference*(parameters.number_of_links_minimum as f64)*parameters.link_length_reference;
+        let tabulated = TabulatedModifiedCanonical::init(FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), potential_stiffness, temperature, &0.0, &maximum, &TOLERANCE);
+        for _ in 0..parameters.number_of_loops
+        {
+            let potential_distance = maximum*rng.gen::<f64>();
+            let end_to_end_length = model.end_to_end_length(&potential_distance, &potential_stiffness, &temperature);
+            let force = model.force(&potential_distance, &potential_stiffness, &temperature);
+            let relative_helmholtz_free_energy = model.relative_helmholtz_free_energy(&potential_distance, &potential_stiffness, &temperature);
+            let gibbs_free_energy = model.gibbs_free_energy(&potential_distance, &potential_stiffness, &temperature);
+            assert!((ModifiedCanonical::end_to_end_length(&tabulated, &potential_distance, &potential_stiffness, &temperature) - end_to_end_length).abs() <= 1e1*TOLERANCE*end_to_end_length.abs().max(1.0));
+            assert!((ModifiedCanonical::force(&tabulated, &potential_distance, &potential_stiffness, &temperature) - force).abs() <= 1e1*TOLERANCE*force.abs().max(1.0));
+            assert!((ModifiedCanonical::relative_helmholtz_free_energy(&tabulated, &potential_distance, &potential_stiffness, &temperature) - relative_helmholtz_free_energy).abs() <= 1e1*TOLERANCE*relative_helmholtz_free_energy.abs().max(1.0));
+            assert!((ModifiedCanonical::gibbs_free_energy(&tabulated, &potential_distance, &potential_stiffness, &temperature) - gibbs_free_energy).abs() <= 1e1*TOLERANCE*gibbs_free_energy.abs().max(1.0));
+        }
+    }
+    #[test]
+    fn untabulated()
+    {
+        let parameters = Parameters::default();
+        let model = FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let temperature = parameters.temperature_reference;
+        let potential_stiffness = parameters.nondimensional_potential_stiffness_reference*crate::physics::BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference.powi(2);
+        let maximum = parameters.nondimensional_potential_distance_reference*(parameters.number_of_links_minimum as f64)*parameters.link_length_reference;
+        let tabulated = TabulatedModifiedCanonical::init(FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), potential_stiffness, temperature, &0.0, &maximum, &TOLERANCE);
+        assert_eq!(ModifiedCanonical::force(&tabulated, &(2.0*maximum), &potential_stiffness, &temperature), model.force(&(2.0*maximum), &potential_stiffness, &temperature));
+        assert_eq!(ModifiedCanonical::force(&tabulated, &maximum, &(2.0*potential_stiffness), &temperature), model.force(&maximum, &(2.0*potential_stiffness), &temperature));
+        assert_eq!(ModifiedCanonical::force(&tabulated, &maximum, &potential_stiffness, &(2.0*temperature)), model.force(&maximum, &potential_stiffness, &(2.0*temperature)));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json()
+    {
+        let parameters = Parameters::default();
+        let temperature = parameters.temperature_reference;
+        let potential_stiffness = parameters.nondimensional_potential_stiffness_reference*crate::physics::BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference.powi(2);
+        let tabulated = TabulatedModifiedCanonical::init(FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), potential_stiffness, temperature, &0.0, &1.0, &TOLERANCE);
+        let reloaded = TabulatedModifiedCanonical::from_json(FJCModifiedCanonical::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference), &tabulated.to_json()).unwrap();
+        assert_eq!(reloaded.potential_stiffness, potential_stiffness);
+        assert_eq!(reloaded.temperature, temperature);
+        assert_eq!(reloaded.end_to_end_length, tabulated.end_to_end_length);
+        assert_eq!(reloaded.force, tabulated.force);
+        assert_eq!(reloaded.helmholtz_free_energy, tabulated.helmholtz_free_energy);
+        assert_eq!(reloaded.relative_helmholtz_free_energy, tabulated.relative_helmholtz_free_energy);
+    }
+}
@@ -12,6 +12,46 @@ where F: Fn(f64) -> f64
     let dx = (upper_lim - lower_lim)/(*num_points as f64);
     (0..=num_points-1).collect::<Vec::<u128>>().iter().map(|index| function(lower_lim + (0.5 + *index as f64)*dx)).sum::<f64>()*dx
 }
+pub fn central_difference<F>(function: F, point: &[f64]) -> Vec<f64>
+where F: Fn(&[f64]) -> f64
+{
+    (0..point.len()).map(|index|
+    {
+        let step = 1e-4*point[index].abs();
+        let mut forward = point.to_vec();
+        let mut backward = point.to_vec();
+        forward[index] += step;
+        backward[index] -= step;
+        (function(&forward) - function(&backward))/(forward[index] - backward[index])
+    }).collect()
+}
+pub fn check_gradient<F, G>(dual_function: F, function: G, point: &[f64], rel_tol: &f64)
+where F: Fn(&[crate::math::dual::Dual]) -> crate::math::dual::Dual, G: Fn(&[f64]) -> f64
+{
+    let value = function(point);
+    let gradient = crate::math::dual::gradient(dual_function, point);
+    let differences = central_difference(function, point);
+    for ((derivative, difference), coordinate) in gradient.iter().zip(differences.iter()).zip(point.iter())
+    {
+        assert!((derivative - difference).abs() <= rel_tol*(derivative.abs() + (value/coordinate).abs()), "{} != {} at {:?}", derivative, difference, point);
+    }
+}
+pub fn check_constants<T: super::Isotensional>(molar: &T, molecular: &T, reduced: &T, temperature: &f64, rel_tol: &f64)
+{
+    let scale = crate::physics::AVOGADRO_NUMBER/1e21;
+    let thermal_energy = BOLTZMANN_CONSTANT*temperature;
+    let close = |value: f64, reference: f64| assert!((value/reference - 1.0).abs() <= *rel_tol, "{} != {}", value, reference);
+    for nondimensional_force in [1e-1, 1e0, 1e1]
+    {
+        let force = nondimensional_force*thermal_energy;
+        close(molecular.end_to_end_length(&(force/scale), temperature), molar.end_to_end_length(&force, temperature));
+        close(reduced.end_to_end_length(&nondimensional_force, temperature), molar.end_to_end_length(&force, temperature));
+        close(scale*molecular.gibbs_free_energy(&(force/scale), temperature), molar.gibbs_free_energy(&force, temperature));
+        close(thermal_energy*reduced.gibbs_free_energy(&nondimensional_force, temperature), molar.gibbs_free_energy(&force, temperature));
+        close(scale*molecular.relative_gibbs_free_energy(&(force/scale), temperature), molar.relative_gibbs_free_energy(&force, temperature));
+        close(thermal_energy*reduced.relative_gibbs_free_energy(&nondimensional_force, temperature), molar.relative_gibbs_free_energy(&force, temperature));
+    }
+}
 pub struct Parameters
 {
     pub abs_tol: f64,
@@ -679,6 +719,27 @@ mod fjc
                 assert!(residual_rel.abs() <= nondimensional_end_to_end_length_per_link);
             }
         }
+        #[test]
+        fn nondimensional_form_factor()
+        {
+            let mut rng = rand::thread_rng();
+            let parameters = Parameters::default();
+            for _ in 0..parameters.number_of_loops
+            {
+                let number_of_links: u8 = parameters.number_of_links_maximum;
+                let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+                let hinge_mass = parameters.hinge_mass_reference + parameters.hinge_mass_scale*(0.5 - rng.gen::<f64>());
+                let fjc = FJC::init(number_of_links, link_length, hinge_mass);
+                let ideal = Ideal::init(number_of_links, link_length, hinge_mass);
+                let nondimensional_wavenumber = parameters.nondimensional_end_to_end_length_per_link_small*(1.0 - 0.5*rng.gen::<f64>());
+                let nondimensional_form_factor_fjc = fjc.scattering.nondimensional_form_factor(&nondimensional_wavenumber);
+                let nondimensional_form_factor_ideal = ideal.scattering.nondimensional_form_factor(&nondimensional_wavenumber);
+                let residual_abs = nondimensional_form_factor_fjc - nondimensional_form_factor_ideal;
+                let residual_rel = residual_abs/nondimensional_form_factor_ideal;
+                assert!(residual_rel.abs() <= parameters.rel_tol_thermodynamic_limit);
+                assert!(residual_rel.abs() <= nondimensional_wavenumber);
+            }
+        }
     }
 }
 mod efjc
@@ -0,0 +1,163 @@
+mod test;
+use std::f64::consts::PI;
+use crate::physics::Constants;
+use crate::math::random::Random;
+use crate::math::interpolation::Interpolant;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+static POINTS_PER_LINK: usize = 256;
+
+/// The structure of the FJC model with one end grafted to an impenetrable planar surface.
+///
+/// The projection of each freely-jointed link onto the normal of the surface is uniformly distributed between plus and minus the link length, so the distance of each hinge from the surface is a random walk with uniform steps that is killed whenever a hinge crosses the surface.
+/// The density of the distance of the free end from the surface is computed by integrating this walk link by link on a grid with the trapezoidal rule, which is exact for the first two links and otherwise accurate to second order in the grid spacing.
+/// The lateral position of the free end is unconstrained, and configurations may also be sampled using Monte Carlo.
+pub struct FJC
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64,
+
+    survival_probability: f64,
+
+    ln_distribution: Interpolant,
+
+    nondimensional_force: Interpolant
+}
+
+/// The implemented functionality of the tethered FJC model.
+impl FJC
+{
+    /// Initializes and returns an instance of the tethered FJC model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> FJC
+    {
+        FJC::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the tethered FJC model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> FJC
+    {
+        let number_of_links_usize = number_of_links as usize;
+        let number_of_points = POINTS_PER_LINK*number_of_links_usize + 1;
+        let step = 1.0/(POINTS_PER_LINK as f64);
+        let last = number_of_points - 1;
+        let mut cumulative = (0..number_of_points).map(|index| 0.5*((index as f64)*step).min(1.0)).collect::<Vec<f64>>();
+        let mut density = (0..number_of_points).map(|index| if index < POINTS_PER_LINK {0.5} else {0.0}).collect::<Vec<f64>>();
+        for _ in 1..number_of_links_usize
+        {
+            density = (0..number_of_points).map(|index| 0.5*(cumulative[(index + POINTS_PER_LINK).min(last)] - cumulative[index.saturating_sub(POINTS_PER_LINK)])).collect();
+            cumulative = std::iter::once(0.0).chain(density.windows(2).scan(0.0, |sum, pair|
+            {
+                *sum += 0.5*step*(pair[0] + pair[1]);
+                Some(*sum)
+            })).collect();
+        }
+        let survival_probability = cumulative[last];
+        let resolved = density.iter().take_while(|value| value.is_normal()).count();
+        let nondimensional_distance_per_link = (0..resolved).map(|index| (index as f64)*step/(number_of_links as f64)).collect::<Vec<f64>>();
+        let ln_density = density[..resolved].iter().map(|value| value.ln()).collect::<Vec<f64>>();
+        let nondimensional_force = (0..resolved).map(|index| match index
+        {
+            0 => (3.0*ln_density[0] - 4.0*ln_density[1] + ln_density[2])/(2.0*step),
+            _ if index == resolved - 1 => (-3.0*ln_density[index] + 4.0*ln_density[index - 1] - ln_density[index - 2])/(2.0*step),
+            _ => (ln_density[index - 1] - ln_density[index + 1])/(2.0*step)
+        }).collect::<Vec<f64>>();
+        FJC
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length,
+            survival_probability,
+            ln_distribution: Interpolant::from_points(nondimensional_distance_per_link.clone(), ln_density.iter().map(|value| value + (number_of_links as f64/survival_probability).ln()).collect()),
+            nondimensional_force: Interpolant::from_points(nondimensional_distance_per_link, nondimensional_force)
+        }
+    }
+    /// Initializes and returns an instance of the tethered FJC model, or an error if a parameter is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<FJC, Error>
+    {
+        minimum_number_of_links("FJC", &number_of_links, 1)?;
+        positive("FJC", "link_length", &link_length)?;
+        positive("FJC", "hinge_mass", &hinge_mass)?;
+        Ok(FJC::init(number_of_links, link_length, hinge_mass))
+    }
+    /// The probability that a freely-jointed chain grafted to the surface does not cross the surface, which is (2N - 1)!!/(2N)!! for any number of links N.
+    pub fn survival_probability(&self) -> f64
+    {
+        self.survival_probability
+    }
+    /// The largest distance of the free end from the surface at which its density is resolved in double precision.
+    pub fn maximum_distance(&self) -> f64
+    {
+        self.ln_distribution.maximum()*self.contour_length
+    }
+    /// The expected force as a function of the applied distance of the free end from the surface and temperature.
+    pub fn force(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(distance/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional distance per link of the free end from the surface.
+    pub fn nondimensional_force(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        self.nondimensional_force.evaluate(nondimensional_distance_per_link)
+    }
+    /// The helmholtz free energy as a function of the applied distance of the free end from the surface and temperature.
+    pub fn helmholtz_free_energy(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        -self.constants.boltzmann_constant*temperature*(self.equilibrium_distribution(distance).ln() + self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())
+    }
+    /// The helmholtz free energy as a function of the applied distance of the free end from the surface and temperature, relative to its minimum at the most probable distance.
+    pub fn relative_helmholtz_free_energy(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_helmholtz_free_energy(&(distance/self.contour_length))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional helmholtz free energy as a function of the applied nondimensional distance per link of the free end from the surface, relative to its minimum at the most probable distance.
+    pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        self.ln_distribution.ordinate.iter().fold(f64::NEG_INFINITY, |maximum, value| maximum.max(*value)) - self.ln_distribution.evaluate(nondimensional_distance_per_link)
+    }
+    /// The equilibrium probability density of the distance of the free end from the surface.
+    pub fn equilibrium_distribution(&self, distance: &f64) -> f64
+    {
+        self.nondimensional_equilibrium_distribution(&(distance/self.contour_length))/self.contour_length
+    }
+    /// The nondimensional equilibrium probability density of the nondimensional distance per link of the free end from the surface.
+    pub fn nondimensional_equilibrium_distribution(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        self.ln_distribution.evaluate(nondimensional_distance_per_link).exp()
+    }
+    /// Returns distances of the free end from the surface sampled using Monte Carlo, where chains are grown from the grafted end with random link orientations and rejected if any hinge crosses the surface.
+    pub fn monte_carlo_distances(&self, number_of_samples: &usize, random: &mut Random) -> Vec<f64>
+    {
+        (0..*number_of_samples).map(|_| loop
+        {
+            let mut distance = 0.0;
+            if (0..self.number_of_links).all(|_|
+            {
+                distance += self.link_length*(2.0*random.uniform() - 1.0);
+                distance >= 0.0
+            })
+            {
+                break distance
+            }
+        }).collect()
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(FJC::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(FJC::try_init(parameters.number_of_links_minimum, -1.0, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "FJC", argument: "link_length", value: -1.0}));
+    }
+    #[test]
+    fn survival_probability()
+    {
+        let parameters = Parameters::default();
+        for number_of_links in [1, 2, 3, 8, 64, 255]
+        {
+            let exact = (1..=number_of_links).map(|index| (2.0*(index as f64) - 1.0)/(2.0*(index as f64))).product::<f64>();
+            assert!((FJC::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference).survival_probability()/exact - 1.0).abs() <= 1e-4);
+        }
+    }
+}
+mod isometric
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn two_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let model = FJC::init(2, parameters.link_length_reference, parameters.hinge_mass_reference);
+        assert!((model.survival_probability() - 0.375).abs() <= parameters.abs_tol);
+        for _ in 0..parameters.number_of_loops
+        {
+            let nondimensional_distance = rng.gen::<f64>();
+            assert!((model.nondimensional_equilibrium_distribution(&(0.5*nondimensional_distance))/(4.0/3.0) - 1.0).abs() <= parameters.rel_tol);
+            assert!(model.nondimensional_force(&(0.5*nondimensional_distance)).abs() <= parameters.rel_tol);
+            let nondimensional_distance = 1.1 + 0.7*rng.gen::<f64>();
+            assert!((model.nondimensional_equilibrium_distribution(&(0.5*nondimensional_distance))/(4.0/3.0*(2.0 - nondimensional_distance)) - 1.0).abs() <= 1e-3);
+            assert!((model.nondimensional_force(&(0.5*nondimensional_distance))*(2.0 - nondimensional_distance) - 1.0).abs() <= 1e-3);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = FJC::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let distance = model.maximum_distance()*(0.05 + 0.5*rng.gen::<f64>());
+            let h = 1e-4*distance;
+            let finite_difference = (model.relative_helmholtz_free_energy(&(distance + h), &temperature) - model.relative_helmholtz_free_energy(&(distance - h), &temperature))/(2.0*h);
+            assert!((finite_difference - model.force(&distance, &temperature)).abs() <= 1e-3*(model.force(&distance, &temperature).abs() + BOLTZMANN_CONSTANT*temperature/link_length));
+        }
+    }
+    #[test]
+    fn monte_carlo()
+    {
+        let parameters = Parameters::default();
+        let model = FJC::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let number_of_samples = 100000;
+        let distances = model.monte_carlo_distances(&number_of_samples, &mut Random::init(0));
+        assert!(distances.iter().all(|distance| distance >= &0.0 && distance <= &model.contour_length));
+        let mean = distances.iter().sum::<f64>()/(number_of_samples as f64);
+        let standard_error = (distances.iter().map(|distance| (distance - mean).powi(2)).sum::<f64>()/(number_of_samples as f64)).sqrt()/(number_of_samples as f64).sqrt();
+        let step = 1e-4;
+        let expected = (0..10000).map(|index| (index as f64 + 0.5)*step).map(|gamma| gamma*model.nondimensional_equilibrium_distribution(&gamma)*step).sum::<f64>()*model.contour_length;
+        assert!((mean - expected).abs() <= 4.0*standard_error);
+    }
+}
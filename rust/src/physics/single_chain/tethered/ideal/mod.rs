@@ -0,0 +1,156 @@
+mod test;
+use std::f64::consts::PI;
+use crate::physics::Constants;
+use crate::math::special::erfcx;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links,
+    positive
+};
+
+/// The structure of the ideal chain model with one end grafted to an impenetrable planar surface.
+///
+/// The surface absorbs the Gaussian random walk of the chain, so the distribution of the distance of the free end from the surface follows from the method of images in the limit that the grafted end approaches the surface, which is the Rayleigh distribution z/σ² exp(-z²/2σ²) with σ² = Nb²/3.
+/// The lateral position of the free end is unconstrained in both ensembles.
+pub struct Ideal
+{
+    /// The mass of each hinge in the chain in units of kg/mol.
+    pub hinge_mass: f64,
+
+    /// The length of each link in the chain in units of nm.
+    pub link_length: f64,
+
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The physical constants used by the model.
+    pub constants: Constants,
+
+    number_of_links_f64: f64,
+
+    contour_length: f64
+}
+
+/// The implemented functionality of the tethered ideal chain model.
+impl Ideal
+{
+    /// Initializes and returns an instance of the tethered ideal chain model.
+    pub fn init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Ideal
+    {
+        Ideal::init_with_constants(number_of_links, link_length, hinge_mass, Constants::default())
+    }
+    /// Initializes and returns an instance of the tethered ideal chain model, using the physical constants.
+    pub fn init_with_constants(number_of_links: u8, link_length: f64, hinge_mass: f64, constants: Constants) -> Ideal
+    {
+        Ideal
+        {
+            hinge_mass,
+            link_length,
+            number_of_links,
+            constants,
+            number_of_links_f64: number_of_links as f64,
+            contour_length: (number_of_links as f64)*link_length
+        }
+    }
+    /// Initializes and returns an instance of the tethered ideal chain model, or an error if a parameter is invalid.
+    pub fn try_init(number_of_links: u8, link_length: f64, hinge_mass: f64) -> Result<Ideal, Error>
+    {
+        minimum_number_of_links("Ideal", &number_of_links, 1)?;
+        positive("Ideal", "link_length", &link_length)?;
+        positive("Ideal", "hinge_mass", &hinge_mass)?;
+        Ok(Ideal::init(number_of_links, link_length, hinge_mass))
+    }
+    /// The most probable distance of the free end from the surface.
+    pub fn most_probable_distance(&self) -> f64
+    {
+        self.link_length*(self.number_of_links_f64/3.0).sqrt()
+    }
+    /// The expected force as a function of the applied distance of the free end from the surface and temperature.
+    pub fn force(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_force(&(distance/self.contour_length))*self.constants.boltzmann_constant*temperature/self.link_length
+    }
+    /// The expected nondimensional force as a function of the applied nondimensional distance per link of the free end from the surface.
+    pub fn nondimensional_force(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        3.0*nondimensional_distance_per_link - 1.0/self.number_of_links_f64/nondimensional_distance_per_link
+    }
+    /// The helmholtz free energy as a function of the applied distance of the free end from the surface and temperature.
+    pub fn helmholtz_free_energy(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        -self.constants.boltzmann_constant*temperature*(self.equilibrium_distribution(distance).ln() + self.number_of_links_f64*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln())
+    }
+    /// The helmholtz free energy as a function of the applied distance of the free end from the surface and temperature, relative to its minimum at the most probable distance.
+    pub fn relative_helmholtz_free_energy(&self, distance: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_helmholtz_free_energy(&(distance/self.contour_length))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional helmholtz free energy as a function of the applied nondimensional distance per link of the free end from the surface, relative to its minimum at the most probable distance.
+    pub fn nondimensional_relative_helmholtz_free_energy(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        1.5*self.number_of_links_f64*nondimensional_distance_per_link.powi(2) - 0.5 - 0.5*(3.0*self.number_of_links_f64*nondimensional_distance_per_link.powi(2)).ln()
+    }
+    /// The equilibrium probability density of the distance of the free end from the surface.
+    pub fn equilibrium_distribution(&self, distance: &f64) -> f64
+    {
+        self.nondimensional_equilibrium_distribution(&(distance/self.contour_length))/self.contour_length
+    }
+    /// The nondimensional equilibrium probability density of the nondimensional distance per link of the free end from the surface.
+    pub fn nondimensional_equilibrium_distribution(&self, nondimensional_distance_per_link: &f64) -> f64
+    {
+        3.0*self.number_of_links_f64*nondimensional_distance_per_link*(-1.5*self.number_of_links_f64*nondimensional_distance_per_link.powi(2)).exp()
+    }
+    /// The expected distance of the free end from the surface as a function of the applied force and temperature.
+    pub fn distance(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_distance_per_link(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.contour_length
+    }
+    /// The expected nondimensional distance per link of the free end from the surface as a function of the applied nondimensional force.
+    pub fn nondimensional_distance_per_link(&self, nondimensional_force: &f64) -> f64
+    {
+        let scale = (self.number_of_links_f64/3.0).sqrt();
+        ln_partition_function(&(nondimensional_force*scale)).1*scale/self.number_of_links_f64
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy(force, temperature) - self.number_of_links_f64*self.constants.boltzmann_constant*temperature*(8.0*PI.powi(2)*self.hinge_mass*self.link_length.powi(2)*self.constants.boltzmann_constant*temperature/self.constants.planck_constant.powi(2)).ln()
+    }
+    /// The gibbs free energy as a function of the applied force and temperature, relative to that at zero force.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.nondimensional_relative_gibbs_free_energy(&(force*self.link_length/self.constants.boltzmann_constant/temperature))*self.constants.boltzmann_constant*temperature
+    }
+    /// The nondimensional gibbs free energy as a function of the applied nondimensional force, relative to that at zero force.
+    pub fn nondimensional_relative_gibbs_free_energy(&self, nondimensional_force: &f64) -> f64
+    {
+        -ln_partition_function(&(nondimensional_force*(self.number_of_links_f64/3.0).sqrt())).0
+    }
+}
+
+/// The logarithm of the partition function of the distance of the free end from the surface under a force scaled by σ/kT, normalized to one at zero force, and its derivative.
+///
+/// The partition function is 1 + (π/2)^(1/2) a erfcx(-a/2^(1/2)), where the complementary error function is replaced by one for large forces to avoid overflow, and by its asymptotic series for large compressive forces to avoid cancellation.
+fn ln_partition_function(scaled_force: &f64) -> (f64, f64)
+{
+    let a = *scaled_force;
+    if a > 10.0
+    {
+        let tail = (-0.5*a.powi(2)).exp()/(2.0*PI).sqrt();
+        (0.5*a.powi(2) + ((2.0*PI).sqrt()*a).ln() + (tail/a).ln_1p(), (1.0 + a.powi(2) + a*tail)/(a + tail))
+    }
+    else if a < -40.0
+    {
+        let inverse_square = a.powi(-2);
+        let partition_function = inverse_square*(1.0 + inverse_square*(-3.0 + inverse_square*(15.0 + inverse_square*(-105.0 + inverse_square*945.0))));
+        let derivative = inverse_square/a*(-2.0 + inverse_square*(12.0 + inverse_square*(-90.0 + inverse_square*(840.0 - inverse_square*9450.0))));
+        (partition_function.ln(), derivative/partition_function)
+    }
+    else
+    {
+        let scaled = (0.5*PI).sqrt()*erfcx(&(-a/2.0_f64.sqrt()));
+        let partition_function = 1.0 + a*scaled;
+        (partition_function.ln(), (scaled*(1.0 + a.powi(2)) + a)/partition_function)
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    #[test]
+    fn try_init()
+    {
+        let parameters = Parameters::default();
+        assert!(Ideal::try_init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference).is_ok());
+        assert_eq!(Ideal::try_init(0, parameters.link_length_reference, parameters.hinge_mass_reference).err(), Some(Error::InvalidParameter{model: "Ideal", argument: "number_of_links", value: 0.0}));
+    }
+}
+mod isometric
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn distribution()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = Ideal::init(number_of_links, parameters.link_length_reference, parameters.hinge_mass_reference);
+            let step = 5e-5;
+            let (normalization, mean) = (0..100000).map(|index| (index as f64 + 0.5)*step).fold((0.0, 0.0), |(normalization, mean), gamma|
+            {
+                let density = model.nondimensional_equilibrium_distribution(&gamma)*step;
+                (normalization + density, mean + density*gamma)
+            });
+            assert!((normalization - 1.0).abs() <= parameters.rel_tol);
+            assert!((mean/model.nondimensional_distance_per_link(&0.0) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn force()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let distance = model.most_probable_distance()*(0.1 + 3.0*rng.gen::<f64>());
+            let h = 1e-6*distance;
+            let finite_difference = (model.helmholtz_free_energy(&(distance + h), &temperature) - model.helmholtz_free_energy(&(distance - h), &temperature))/(2.0*h);
+            assert!((finite_difference - model.force(&distance, &temperature)).abs() <= parameters.rel_tol*BOLTZMANN_CONSTANT*temperature/model.most_probable_distance());
+            let relative_finite_difference = (model.relative_helmholtz_free_energy(&(distance + h), &temperature) - model.relative_helmholtz_free_energy(&(distance - h), &temperature))/(2.0*h);
+            assert!((relative_finite_difference - model.force(&distance, &temperature)).abs() <= parameters.rel_tol*BOLTZMANN_CONSTANT*temperature/model.most_probable_distance());
+            assert!(model.force(&distance, &temperature)*(distance - model.most_probable_distance()) > 0.0);
+        }
+        let model = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        assert!(model.force(&model.most_probable_distance(), &parameters.temperature_reference).abs() <= parameters.abs_tol);
+        assert!(model.relative_helmholtz_free_energy(&model.most_probable_distance(), &parameters.temperature_reference).abs() <= parameters.abs_tol);
+    }
+}
+mod isotensional
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn gibbs_free_energy_derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let model = Ideal::init(number_of_links, link_length, parameters.hinge_mass_reference);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            for nondimensional_force in [-1e2, -1e1, -1e0, 1e-1, 1e0, 1e1]
+            {
+                let force = nondimensional_force*(0.5 + rng.gen::<f64>())*BOLTZMANN_CONSTANT*temperature/link_length;
+                let h = 1e-6*force.abs();
+                let finite_difference = (model.gibbs_free_energy(&(force + h), &temperature) - model.gibbs_free_energy(&(force - h), &temperature))/(2.0*h);
+                assert!((finite_difference/model.distance(&force, &temperature) + 1.0).abs() <= parameters.rel_tol);
+            }
+        }
+    }
+    #[test]
+    fn limits()
+    {
+        let parameters = Parameters::default();
+        let model = Ideal::init(parameters.number_of_links_minimum, parameters.link_length_reference, parameters.hinge_mass_reference);
+        let scale = (model.number_of_links_f64/3.0).sqrt();
+        assert!((model.nondimensional_distance_per_link(&0.0)*model.number_of_links_f64/scale/(0.5*PI).sqrt() - 1.0).abs() <= parameters.rel_tol);
+        assert_eq!(model.nondimensional_relative_gibbs_free_energy(&0.0), 0.0);
+        for scaled_force in [-40.0, 10.0]
+        {
+            let below = ln_partition_function(&(scaled_force - 1e-12));
+            let above = ln_partition_function(&(scaled_force + 1e-12));
+            assert!((below.0/above.0 - 1.0).abs() <= parameters.rel_tol);
+            assert!((below.1/above.1 - 1.0).abs() <= parameters.rel_tol);
+        }
+        let scaled_force = 1e3;
+        assert!((model.nondimensional_distance_per_link(&(scaled_force/scale))*model.number_of_links_f64/scale/(scaled_force + 1.0/scaled_force) - 1.0).abs() <= parameters.rel_tol);
+        assert!((model.nondimensional_distance_per_link(&(-scaled_force/scale))*model.number_of_links_f64/scale/(2.0/scaled_force - 6.0/scaled_force.powi(3)) - 1.0).abs() <= parameters.rel_tol);
+    }
+}
@@ -0,0 +1,5 @@
+/// The ideal single-chain model with one end grafted to an impenetrable planar surface.
+pub mod ideal;
+
+/// The freely-jointed chain (FJC) single-chain model with one end grafted to an impenetrable planar surface.
+pub mod fjc;
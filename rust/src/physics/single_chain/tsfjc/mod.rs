@@ -0,0 +1,59 @@
+mod test;
+
+/// The thermodynamics of the TSFJC model.
+pub mod thermodynamics;
+
+use crate::physics::single_chain::heterogeneous::Link;
+use crate::error::
+{
+    Error,
+    minimum_number_of_links
+};
+
+/// The structure of the two-state freely-jointed chain (TSFJC) model, where each link is either folded or unfolded.
+pub struct TSFJC
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The type of each link in the folded state.
+    pub folded_link: Link,
+
+    /// The type of each link in the unfolded state.
+    pub unfolded_link: Link,
+
+    /// The energy of unfolding each link in units of J/mol.
+    pub unfolding_energy: f64,
+
+    /// The thermodynamic functions of the model.
+    pub thermodynamics: thermodynamics::TSFJC
+}
+
+/// The implemented functionality of the TSFJC model.
+impl TSFJC
+{
+    /// Initializes and returns an instance of the TSFJC model.
+    pub fn init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> TSFJC
+    {
+        TSFJC
+        {
+            number_of_links,
+            folded_link,
+            unfolded_link,
+            unfolding_energy,
+            thermodynamics: thermodynamics::TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy)
+        }
+    }
+    /// Initializes and returns an instance of the TSFJC model, or an error if a parameter or either type of link is invalid.
+    pub fn try_init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> Result<TSFJC, Error>
+    {
+        minimum_number_of_links("TSFJC", &number_of_links, 1)?;
+        folded_link.validate()?;
+        unfolded_link.validate()?;
+        if !unfolding_energy.is_finite()
+        {
+            return Err(Error::InvalidParameter{model: "TSFJC", argument: "unfolding_energy", value: unfolding_energy})
+        }
+        Ok(TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy))
+    }
+}
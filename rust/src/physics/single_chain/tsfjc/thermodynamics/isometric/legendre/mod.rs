@@ -0,0 +1,111 @@
+mod test;
+use crate::physics::Constants;
+use crate::physics::single_chain::
+{
+    Isometric,
+    heterogeneous::Link,
+    tsfjc::thermodynamics::isotensional
+};
+
+/// The structure of the thermodynamics of the TSFJC model in the isometric ensemble approximated using a Legendre transformation.
+pub struct TSFJC
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The type of each link in the folded state.
+    pub folded_link: Link,
+
+    /// The type of each link in the unfolded state.
+    pub unfolded_link: Link,
+
+    /// The energy of unfolding each link in units of J/mol.
+    pub unfolding_energy: f64,
+
+    number_of_links_f64: f64,
+
+    isotensional: isotensional::TSFJC
+}
+
+/// The implemented functionality of the thermodynamics of the TSFJC model in the isometric ensemble approximated using a Legendre transformation.
+impl TSFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the TSFJC model in the isometric ensemble approximated using a Legendre transformation.
+    pub fn init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> TSFJC
+    {
+        TSFJC
+        {
+            number_of_links,
+            folded_link,
+            unfolded_link,
+            unfolding_energy,
+            number_of_links_f64: number_of_links as f64,
+            isotensional: isotensional::TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy)
+        }
+    }
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.isotensional.force(end_to_end_length, temperature)
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.isotensional.end_to_end_length(force, temperature)
+    }
+    /// The expected fraction of unfolded links as a function of the applied end-to-end length and temperature.
+    pub fn fraction_unfolded(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.isotensional.fraction_unfolded(&self.force(end_to_end_length, temperature), temperature)
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.isotensional.gibbs_free_energy(&force, temperature) + force*end_to_end_length
+    }
+    /// The helmholtz free energy per link as a function of the applied end-to-end length and temperature.
+    pub fn helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.helmholtz_free_energy(end_to_end_length, temperature)/self.number_of_links_f64
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        let force = self.force(end_to_end_length, temperature);
+        self.isotensional.relative_gibbs_free_energy(&force, temperature) + force*end_to_end_length
+    }
+    /// The relative helmholtz free energy per link as a function of the applied end-to-end length and temperature.
+    pub fn relative_helmholtz_free_energy_per_link(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        self.relative_helmholtz_free_energy(end_to_end_length, temperature)/self.number_of_links_f64
+    }
+}
+
+impl Isometric for TSFJC
+{
+    fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::force(self, end_to_end_length, temperature)
+    }
+    fn helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn relative_helmholtz_free_energy(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::relative_helmholtz_free_energy(self, end_to_end_length, temperature)
+    }
+    fn maximum_end_to_end_length(&self) -> f64
+    {
+        self.number_of_links_f64*self.folded_link.maximum_link_length().max(self.unfolded_link.maximum_link_length())
+    }
+    fn name(&self) -> &'static str
+    {
+        "TSFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        Constants::default()
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        let _ = TSFJC::init(parameters.number_of_links_minimum, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference).number_of_links);
+        }
+    }
+    #[test]
+    fn maximum_end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let folded_link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference};
+            let unfolded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, well_width: parameters.well_width_reference};
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference);
+            assert!((model.maximum_end_to_end_length()/((number_of_links as f64)*(2.0*parameters.link_length_reference + parameters.well_width_reference)) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod legendre
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn inverse()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, well_width: parameters.well_width_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*temperature);
+            let end_to_end_length = model.maximum_end_to_end_length()*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let force = model.force(&end_to_end_length, &temperature);
+            assert!((model.isotensional.end_to_end_length(&force, &temperature)/end_to_end_length - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.fraction_unfolded(&end_to_end_length, &temperature) - model.isotensional.fraction_unfolded(&force, &temperature)).abs() <= parameters.abs_tol);
+            assert!((model.helmholtz_free_energy_per_link(&end_to_end_length, &temperature)*(number_of_links as f64) - model.isotensional.gibbs_free_energy(&force, &temperature) - force*end_to_end_length).abs() <= parameters.abs_tol);
+        }
+    }
+    #[test]
+    fn derivative()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*temperature);
+            let contour_length = (number_of_links as f64)*parameters.link_length_reference;
+            let end_to_end_length = contour_length*(parameters.nondimensional_end_to_end_length_per_link_reference + parameters.nondimensional_end_to_end_length_per_link_scale*(0.5 - rng.gen::<f64>()));
+            let h = parameters.rel_tol*contour_length;
+            let derivative = (model.relative_helmholtz_free_energy(&(end_to_end_length + 0.5*h), &temperature) - model.relative_helmholtz_free_energy(&(end_to_end_length - 0.5*h), &temperature))/h;
+            assert!((derivative/model.force(&end_to_end_length, &temperature) - 1.0).abs() <= parameters.rel_tol.sqrt());
+            assert!(model.fraction_unfolded(&(end_to_end_length + contour_length), &temperature) > model.fraction_unfolded(&end_to_end_length, &temperature));
+        }
+    }
+}
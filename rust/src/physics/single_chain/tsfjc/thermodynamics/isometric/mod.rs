@@ -0,0 +1,42 @@
+mod test;
+
+/// The thermodynamics of the TSFJC model in the isometric ensemble approximated using a Legendre transformation.
+pub mod legendre;
+
+use crate::physics::single_chain::heterogeneous::Link;
+
+/// The structure of the thermodynamics of the TSFJC model in the isometric ensemble.
+pub struct TSFJC
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The type of each link in the folded state.
+    pub folded_link: Link,
+
+    /// The type of each link in the unfolded state.
+    pub unfolded_link: Link,
+
+    /// The energy of unfolding each link in units of J/mol.
+    pub unfolding_energy: f64,
+
+    /// The thermodynamic functions of the model in the isometric ensemble approximated using a Legendre transformation.
+    pub legendre: legendre::TSFJC
+}
+
+/// The implemented functionality of the thermodynamics of the TSFJC model in the isometric ensemble.
+impl TSFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the TSFJC model in the isometric ensemble.
+    pub fn init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> TSFJC
+    {
+        TSFJC
+        {
+            number_of_links,
+            folded_link,
+            unfolded_link,
+            unfolding_energy,
+            legendre: legendre::TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy)
+        }
+    }
+}
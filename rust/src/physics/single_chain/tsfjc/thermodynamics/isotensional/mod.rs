@@ -0,0 +1,136 @@
+mod test;
+use crate::physics::Constants;
+use crate::math::roots::
+{
+    bracket,
+    brent
+};
+use crate::physics::
+{
+    BOLTZMANN_CONSTANT,
+    single_chain::
+    {
+        Isotensional,
+        heterogeneous::Link
+    }
+};
+
+/// The structure of the thermodynamics of the TSFJC model in the isotensional ensemble.
+///
+/// The links are independent under an applied force, so each link is in equilibrium between its folded and unfolded states and the chain functions are exact sums over the links.
+pub struct TSFJC
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The type of each link in the folded state.
+    pub folded_link: Link,
+
+    /// The type of each link in the unfolded state.
+    pub unfolded_link: Link,
+
+    /// The energy of unfolding each link in units of J/mol.
+    pub unfolding_energy: f64,
+
+    number_of_links_f64: f64,
+
+    folded: Box<dyn Isotensional>,
+
+    unfolded: Box<dyn Isotensional>
+}
+
+/// The implemented functionality of the thermodynamics of the TSFJC model in the isotensional ensemble.
+impl TSFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the TSFJC model in the isotensional ensemble.
+    pub fn init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> TSFJC
+    {
+        TSFJC
+        {
+            number_of_links,
+            folded_link,
+            unfolded_link,
+            unfolding_energy,
+            number_of_links_f64: number_of_links as f64,
+            folded: folded_link.isotensional(),
+            unfolded: unfolded_link.isotensional()
+        }
+    }
+    /// The expected fraction of unfolded links as a function of the applied force and temperature.
+    pub fn fraction_unfolded(&self, force: &f64, temperature: &f64) -> f64
+    {
+        1.0/(1.0 + ((self.unfolded.gibbs_free_energy(force, temperature) + self.unfolding_energy - self.folded.gibbs_free_energy(force, temperature))/BOLTZMANN_CONSTANT/temperature).exp())
+    }
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    pub fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.end_to_end_length_per_link(force, temperature)*self.number_of_links_f64
+    }
+    /// The expected end-to-end length per link as a function of the applied force and temperature.
+    pub fn end_to_end_length_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        let fraction_unfolded = self.fraction_unfolded(force, temperature);
+        (1.0 - fraction_unfolded)*self.folded.end_to_end_length(force, temperature) + fraction_unfolded*self.unfolded.end_to_end_length(force, temperature)
+    }
+    /// The force as a function of the expected end-to-end length and temperature, which is not a number at or beyond the maximum end-to-end length of the chain.
+    pub fn force(&self, end_to_end_length: &f64, temperature: &f64) -> f64
+    {
+        if end_to_end_length.abs() >= self.number_of_links_f64*self.folded_link.maximum_link_length().max(self.unfolded_link.maximum_link_length())
+        {
+            return f64::NAN
+        }
+        if end_to_end_length < &0.0
+        {
+            return -self.force(&(-end_to_end_length), temperature)
+        }
+        let function = |force: &f64| self.end_to_end_length(force, temperature) - end_to_end_length;
+        let (lower, upper) = bracket(function, &(3.0*end_to_end_length/self.number_of_links_f64/self.folded_link.link_length().powi(2)*BOLTZMANN_CONSTANT*temperature), &0.0, &f64::INFINITY);
+        brent(function, &lower, &upper)
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    pub fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.gibbs_free_energy_per_link(force, temperature)*self.number_of_links_f64
+    }
+    /// The gibbs free energy per link as a function of the applied force and temperature.
+    pub fn gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        let folded_gibbs_free_energy = self.folded.gibbs_free_energy(force, temperature);
+        let unfolded_gibbs_free_energy = self.unfolded.gibbs_free_energy(force, temperature) + self.unfolding_energy;
+        folded_gibbs_free_energy.min(unfolded_gibbs_free_energy) - BOLTZMANN_CONSTANT*temperature*(-(folded_gibbs_free_energy - unfolded_gibbs_free_energy).abs()/BOLTZMANN_CONSTANT/temperature).exp().ln_1p()
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.relative_gibbs_free_energy_per_link(force, temperature)*self.number_of_links_f64
+    }
+    /// The relative gibbs free energy per link as a function of the applied force and temperature.
+    pub fn relative_gibbs_free_energy_per_link(&self, force: &f64, temperature: &f64) -> f64
+    {
+        self.gibbs_free_energy_per_link(force, temperature) - self.gibbs_free_energy_per_link(&0.0, temperature)
+    }
+}
+
+impl Isotensional for TSFJC
+{
+    fn end_to_end_length(&self, force: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::end_to_end_length(self, force, temperature)
+    }
+    fn gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::gibbs_free_energy(self, force, temperature)
+    }
+    fn relative_gibbs_free_energy(&self, force: &f64, temperature: &f64) -> f64
+    {
+        TSFJC::relative_gibbs_free_energy(self, force, temperature)
+    }
+    fn name(&self) -> &'static str
+    {
+        "TSFJC"
+    }
+    fn constants(&self) -> Constants
+    {
+        Constants::default()
+    }
+}
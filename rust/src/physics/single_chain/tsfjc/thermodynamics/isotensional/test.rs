@@ -0,0 +1,140 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::single_chain::heterogeneous::thermodynamics::isotensional::Heterogeneous;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        let _ = TSFJC::init(parameters.number_of_links_minimum, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference).number_of_links);
+        }
+    }
+}
+mod limits
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn folded()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e6*BOLTZMANN_CONSTANT*temperature);
+            let reference = Heterogeneous::init(vec![(number_of_links, folded_link)]);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            assert!(model.fraction_unfolded(&force, &temperature) <= parameters.abs_tol);
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.gibbs_free_energy(&force, &temperature)/reference.gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!((model.relative_gibbs_free_energy(&force, &temperature)/reference.relative_gibbs_free_energy(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+    #[test]
+    fn unfolded()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let unfolding_energy = -1e6*BOLTZMANN_CONSTANT*temperature;
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy);
+            let reference = Heterogeneous::init(vec![(number_of_links, unfolded_link)]);
+            let nondimensional_force = parameters.nondimensional_force_reference + parameters.nondimensional_force_scale*(0.5 - rng.gen::<f64>());
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            assert!(1.0 - model.fraction_unfolded(&force, &temperature) <= parameters.abs_tol);
+            assert!((model.end_to_end_length(&force, &temperature)/reference.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+            assert!(((model.gibbs_free_energy_per_link(&force, &temperature) - unfolding_energy)/reference.gibbs_free_energy_per_link(&force, &temperature) - 1.0).abs() <= parameters.rel_tol);
+        }
+    }
+}
+mod unfolding
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn fraction_unfolded()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = TSFJC::init(parameters.number_of_links_minimum, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*temperature);
+            let nondimensional_force = parameters.nondimensional_force_small*rng.gen::<f64>();
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let fraction_unfolded = model.fraction_unfolded(&force, &temperature);
+            assert!(fraction_unfolded > 0.0 && fraction_unfolded < 1.0);
+            assert!(model.fraction_unfolded(&(2.0*force), &temperature) > fraction_unfolded);
+        }
+    }
+    #[test]
+    fn end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*temperature);
+            let nondimensional_force = parameters.nondimensional_force_small + rng.gen::<f64>();
+            let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+            let h = parameters.rel_tol*force;
+            let derivative = -(model.gibbs_free_energy(&(force + 0.5*h), &temperature) - model.gibbs_free_energy(&(force - 0.5*h), &temperature))/h;
+            assert!((derivative/model.end_to_end_length(&force, &temperature) - 1.0).abs() <= parameters.rel_tol.sqrt());
+        }
+    }
+}
+mod inverse
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn beyond_maximum_end_to_end_length()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            let model = TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference);
+            let end_to_end_length = (number_of_links as f64)*(2.0*parameters.link_length_reference + parameters.well_width_reference)*(1.0 + rng.gen::<f64>());
+            assert!(model.force(&end_to_end_length, &parameters.temperature_reference).is_nan());
+            assert!(model.force(&(-end_to_end_length), &parameters.temperature_reference).is_nan());
+        }
+    }
+}
@@ -0,0 +1,49 @@
+mod test;
+
+/// The thermodynamics of the TSFJC model in the isometric ensemble.
+pub mod isometric;
+
+/// The thermodynamics of the TSFJC model in the isotensional ensemble.
+pub mod isotensional;
+
+use crate::physics::single_chain::heterogeneous::Link;
+
+/// The structure of the thermodynamics of the TSFJC model.
+pub struct TSFJC
+{
+    /// The number of links in the chain.
+    pub number_of_links: u8,
+
+    /// The type of each link in the folded state.
+    pub folded_link: Link,
+
+    /// The type of each link in the unfolded state.
+    pub unfolded_link: Link,
+
+    /// The energy of unfolding each link in units of J/mol.
+    pub unfolding_energy: f64,
+
+    /// The thermodynamic functions of the model in the isometric ensemble.
+    pub isometric: isometric::TSFJC,
+
+    /// The thermodynamic functions of the model in the isotensional ensemble.
+    pub isotensional: isotensional::TSFJC
+}
+
+/// The implemented functionality of the thermodynamics of the TSFJC model.
+impl TSFJC
+{
+    /// Initializes and returns an instance of the thermodynamics of the TSFJC model.
+    pub fn init(number_of_links: u8, folded_link: Link, unfolded_link: Link, unfolding_energy: f64) -> TSFJC
+    {
+        TSFJC
+        {
+            number_of_links,
+            folded_link,
+            unfolded_link,
+            unfolding_energy,
+            isometric: isometric::TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy),
+            isotensional: isotensional::TSFJC::init(number_of_links, folded_link, unfolded_link, unfolding_energy)
+        }
+    }
+}
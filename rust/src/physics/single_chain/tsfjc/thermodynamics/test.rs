@@ -0,0 +1,47 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::test::Parameters;
+mod base
+{
+    use super::*;
+    use rand::Rng;
+    #[test]
+    fn init()
+    {
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        let _ = TSFJC::init(parameters.number_of_links_minimum, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference);
+    }
+    #[test]
+    fn number_of_links()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        let folded_link = Link::SWFJC{hinge_mass: parameters.hinge_mass_reference, link_length: parameters.link_length_reference, well_width: parameters.well_width_reference};
+        let unfolded_link = Link::EFJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*parameters.link_length_reference, link_stiffness: parameters.link_stiffness_reference};
+        for _ in 0..parameters.number_of_loops
+        {
+            let number_of_links: u8 = rng.gen_range(parameters.number_of_links_minimum..parameters.number_of_links_maximum);
+            assert_eq!(number_of_links, TSFJC::init(number_of_links, folded_link, unfolded_link, 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference).number_of_links);
+        }
+    }
+    #[test]
+    fn links_and_unfolding_energy()
+    {
+        let mut rng = rand::thread_rng();
+        let parameters = Parameters::default();
+        for _ in 0..parameters.number_of_loops
+        {
+            let link_length = parameters.link_length_reference + parameters.link_length_scale*(0.5 - rng.gen::<f64>());
+            let folded_link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length};
+            let unfolded_link = Link::FJC{hinge_mass: parameters.hinge_mass_reference, link_length: 2.0*link_length};
+            let unfolding_energy = 1e1*BOLTZMANN_CONSTANT*parameters.temperature_reference*rng.gen::<f64>();
+            let model = TSFJC::init(parameters.number_of_links_minimum, folded_link, unfolded_link, unfolding_energy);
+            assert_eq!(folded_link, model.folded_link);
+            assert_eq!(unfolded_link, model.unfolded_link);
+            assert_eq!(unfolding_energy, model.unfolding_energy);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+mod test;
+use crate::error::Error;
+use crate::physics::units::
+{
+    Energy,
+    Force,
+    Length,
+    Temperature
+};
+use crate::physics::single_chain::
+{
+    Isometric,
+    Isotensional
+};
+
+/// The thermodynamic functions in the isometric ensemble taking and returning quantities with units.
+pub trait IsometricUnits: Isometric
+{
+    /// The expected force as a function of the applied end-to-end length and temperature.
+    fn force_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Force
+    {
+        Force::from_constants(self.force(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()), &self.constants())
+    }
+    /// The helmholtz free energy as a function of the applied end-to-end length and temperature.
+    fn helmholtz_free_energy_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Energy
+    {
+        Energy::from_constants(self.helmholtz_free_energy(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()), &self.constants())
+    }
+    /// The relative helmholtz free energy as a function of the applied end-to-end length and temperature.
+    fn relative_helmholtz_free_energy_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Energy
+    {
+        Energy::from_constants(self.relative_helmholtz_free_energy(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()), &self.constants())
+    }
+    /// The supremum of the end-to-end lengths that can be applied.
+    fn maximum_end_to_end_length_with_units(&self) -> Length
+    {
+        Length::from_nanometers(self.maximum_end_to_end_length())
+    }
+    /// The expected force, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_force_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Result<Force, Error>
+    {
+        self.try_force(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()).map(|force| Force::from_constants(force, &self.constants()))
+    }
+    /// The helmholtz free energy, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_helmholtz_free_energy_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Result<Energy, Error>
+    {
+        self.try_helmholtz_free_energy(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()).map(|energy| Energy::from_constants(energy, &self.constants()))
+    }
+    /// The relative helmholtz free energy, or an error if the end-to-end length or temperature is outside of the domain or the evaluation fails.
+    fn try_relative_helmholtz_free_energy_with_units(&self, end_to_end_length: &Length, temperature: &Temperature) -> Result<Energy, Error>
+    {
+        self.try_relative_helmholtz_free_energy(&end_to_end_length.in_nanometers(), &temperature.in_kelvin()).map(|energy| Energy::from_constants(energy, &self.constants()))
+    }
+}
+
+impl<T: Isometric + ?Sized> IsometricUnits for T {}
+
+/// The thermodynamic functions in the isotensional ensemble taking and returning quantities with units.
+pub trait IsotensionalUnits: Isotensional
+{
+    /// The expected end-to-end length as a function of the applied force and temperature.
+    fn end_to_end_length_with_units(&self, force: &Force, temperature: &Temperature) -> Length
+    {
+        Length::from_nanometers(self.end_to_end_length(&force.in_constants(&self.constants()), &temperature.in_kelvin()))
+    }
+    /// The gibbs free energy as a function of the applied force and temperature.
+    fn gibbs_free_energy_with_units(&self, force: &Force, temperature: &Temperature) -> Energy
+    {
+        Energy::from_constants(self.gibbs_free_energy(&force.in_constants(&self.constants()), &temperature.in_kelvin()), &self.constants())
+    }
+    /// The relative gibbs free energy as a function of the applied force and temperature.
+    fn relative_gibbs_free_energy_with_units(&self, force: &Force, temperature: &Temperature) -> Energy
+    {
+        Energy::from_constants(self.relative_gibbs_free_energy(&force.in_constants(&self.constants()), &temperature.in_kelvin()), &self.constants())
+    }
+    /// The expected end-to-end length, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_end_to_end_length_with_units(&self, force: &Force, temperature: &Temperature) -> Result<Length, Error>
+    {
+        self.try_end_to_end_length(&force.in_constants(&self.constants()), &temperature.in_kelvin()).map(Length::from_nanometers)
+    }
+    /// The gibbs free energy, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_gibbs_free_energy_with_units(&self, force: &Force, temperature: &Temperature) -> Result<Energy, Error>
+    {
+        self.try_gibbs_free_energy(&force.in_constants(&self.constants()), &temperature.in_kelvin()).map(|energy| Energy::from_constants(energy, &self.constants()))
+    }
+    /// The relative gibbs free energy, or an error if the force or temperature is outside of the domain or the evaluation fails.
+    fn try_relative_gibbs_free_energy_with_units(&self, force: &Force, temperature: &Temperature) -> Result<Energy, Error>
+    {
+        self.try_relative_gibbs_free_energy(&force.in_constants(&self.constants()), &temperature.in_kelvin()).map(|energy| Energy::from_constants(energy, &self.constants()))
+    }
+}
+
+impl<T: Isotensional + ?Sized> IsotensionalUnits for T {}
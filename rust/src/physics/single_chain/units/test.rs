@@ -0,0 +1,80 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::BOLTZMANN_CONSTANT;
+use crate::physics::single_chain::
+{
+    fjc::FJC,
+    ideal::Ideal
+};
+use crate::physics::single_chain::test::Parameters;
+use crate::physics::Constants;
+use rand::Rng;
+#[test]
+fn isometric()
+{
+    let parameters = Parameters::default();
+    let mut rng = rand::thread_rng();
+    let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isometric.legendre;
+    for _ in 0..parameters.number_of_loops
+    {
+        let end_to_end_length = parameters.nondimensional_end_to_end_length_per_link_reference*(parameters.number_of_links_maximum as f64)*parameters.link_length_reference;
+        let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+        let (length, kelvin) = (Length::from_nanometers(end_to_end_length), Temperature::from_kelvin(temperature));
+        assert_eq!(model.force_with_units(&length, &kelvin).in_molar(), model.force(&end_to_end_length, &temperature));
+        assert_eq!(model.helmholtz_free_energy_with_units(&length, &kelvin).in_molar(), model.helmholtz_free_energy(&end_to_end_length, &temperature));
+        assert_eq!(model.relative_helmholtz_free_energy_with_units(&length, &kelvin).in_molar(), model.relative_helmholtz_free_energy(&end_to_end_length, &temperature));
+        assert_eq!(model.try_force_with_units(&length, &kelvin).unwrap(), model.force_with_units(&length, &kelvin));
+        assert!(model.try_force_with_units(&(model.maximum_end_to_end_length_with_units()*2.0), &kelvin).is_err());
+    }
+}
+#[test]
+fn isotensional()
+{
+    let parameters = Parameters::default();
+    let mut rng = rand::thread_rng();
+    let model = FJC::init(parameters.number_of_links_maximum, parameters.link_length_reference, parameters.hinge_mass_reference).thermodynamics.isotensional;
+    for _ in 0..parameters.number_of_loops
+    {
+        let temperature = parameters.temperature_reference + parameters.temperature_scale*(0.5 - rng.gen::<f64>());
+        let nondimensional_force = parameters.nondimensional_force_reference*rng.gen::<f64>();
+        let force = nondimensional_force*BOLTZMANN_CONSTANT*temperature/parameters.link_length_reference;
+        let kelvin = Temperature::from_kelvin(temperature);
+        let thermal = Force::from_thermal(nondimensional_force/parameters.link_length_reference, &kelvin, &model.constants);
+        assert!((thermal.in_molar()/force - 1.0).abs() <= parameters.rel_tol);
+        assert_eq!(model.end_to_end_length_with_units(&Force::from_molar(force), &kelvin).in_nanometers(), model.end_to_end_length(&force, &temperature));
+        assert_eq!(model.gibbs_free_energy_with_units(&Force::from_molar(force), &kelvin).in_molar(), model.gibbs_free_energy(&force, &temperature));
+        assert_eq!(model.relative_gibbs_free_energy_with_units(&Force::from_molar(force), &kelvin).in_molar(), model.relative_gibbs_free_energy(&force, &temperature));
+        assert!(model.try_end_to_end_length_with_units(&Force::from_molar(force), &Temperature::from_kelvin(-temperature)).is_err());
+    }
+}
+#[test]
+fn piconewtons()
+{
+    let model = Ideal::init(100, 0.38, 1.0).thermodynamics.isotensional;
+    let temperature = Temperature::from_celsius(25.0);
+    let force = Force::from_piconewtons(1.0);
+    let end_to_end_length = model.end_to_end_length_with_units(&force, &temperature);
+    let thermal_energy = temperature.thermal_energy().in_piconewton_nanometers();
+    assert!((end_to_end_length.in_nanometers()/(100.0*0.38_f64.powi(2)*1.0/thermal_energy/3.0) - 1.0).abs() <= 1e-12);
+    assert!((model.relative_gibbs_free_energy_with_units(&force, &temperature)/(force*end_to_end_length) + 0.5).abs() <= 1e-12);
+}
+#[test]
+fn constants()
+{
+    let temperature = Temperature::from_kelvin(300.0);
+    let force = Force::from_piconewtons(4.0);
+    let molar = FJC::init(8, 1.0, 1.0).thermodynamics.isotensional;
+    for constants in [Constants::molecular(), Constants::reduced(&300.0)]
+    {
+        let model = FJC::init_with_constants(8, 1.0, 1.0, constants).thermodynamics.isotensional;
+        assert_eq!(model.constants(), constants);
+        assert!((model.end_to_end_length_with_units(&force, &temperature).in_nanometers()/molar.end_to_end_length_with_units(&force, &temperature).in_nanometers() - 1.0).abs() <= 1e-12);
+        assert!((model.relative_gibbs_free_energy_with_units(&force, &temperature)/molar.relative_gibbs_free_energy_with_units(&force, &temperature) - 1.0).abs() <= 1e-12);
+        assert!((model.end_to_end_length_with_units(&force, &temperature).in_nanometers() - 2.43).abs() <= 1e-2);
+        assert!((model.relative_gibbs_free_energy_with_units(&force, &temperature).in_piconewton_nanometers() + 5.0).abs() <= 5e-2);
+        let isometric = FJC::init_with_constants(8, 1.0, 1.0, constants).thermodynamics.isometric.legendre;
+        let length = Length::from_nanometers(4.0);
+        let molar_isometric = FJC::init(8, 1.0, 1.0).thermodynamics.isometric.legendre;
+        assert!((isometric.force_with_units(&length, &temperature)/molar_isometric.force_with_units(&length, &temperature) - 1.0).abs() <= 1e-12);
+    }
+}
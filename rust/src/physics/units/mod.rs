@@ -0,0 +1,441 @@
+mod test;
+use std::ops::
+{
+    Add,
+    Div,
+    Mul,
+    Neg,
+    Sub
+};
+use crate::physics::
+{
+    AVOGADRO_NUMBER,
+    BOLTZMANN_CONSTANT,
+    Constants
+};
+
+macro_rules! quantity
+{
+    ($quantity:ident) =>
+    {
+        impl Add for $quantity
+        {
+            type Output = $quantity;
+            fn add(self, other: $quantity) -> $quantity
+            {
+                $quantity(self.0 + other.0)
+            }
+        }
+        impl Sub for $quantity
+        {
+            type Output = $quantity;
+            fn sub(self, other: $quantity) -> $quantity
+            {
+                $quantity(self.0 - other.0)
+            }
+        }
+        impl Neg for $quantity
+        {
+            type Output = $quantity;
+            fn neg(self) -> $quantity
+            {
+                $quantity(-self.0)
+            }
+        }
+        impl Mul<f64> for $quantity
+        {
+            type Output = $quantity;
+            fn mul(self, scale: f64) -> $quantity
+            {
+                $quantity(self.0*scale)
+            }
+        }
+        impl Mul<$quantity> for f64
+        {
+            type Output = $quantity;
+            fn mul(self, quantity: $quantity) -> $quantity
+            {
+                $quantity(self*quantity.0)
+            }
+        }
+        impl Div<f64> for $quantity
+        {
+            type Output = $quantity;
+            fn div(self, scale: f64) -> $quantity
+            {
+                $quantity(self.0/scale)
+            }
+        }
+        impl Div for $quantity
+        {
+            type Output = f64;
+            fn div(self, other: $quantity) -> f64
+            {
+                self.0/other.0
+            }
+        }
+    };
+}
+
+macro_rules! product
+{
+    ($left:ident * $right:ident = $output:ident) =>
+    {
+        impl Mul<$right> for $left
+        {
+            type Output = $output;
+            fn mul(self, other: $right) -> $output
+            {
+                $output(self.0*other.0)
+            }
+        }
+        impl Mul<$left> for $right
+        {
+            type Output = $output;
+            fn mul(self, other: $left) -> $output
+            {
+                $output(self.0*other.0)
+            }
+        }
+        impl Div<$right> for $output
+        {
+            type Output = $left;
+            fn div(self, other: $right) -> $left
+            {
+                $left(self.0/other.0)
+            }
+        }
+        impl Div<$left> for $output
+        {
+            type Output = $right;
+            fn div(self, other: $left) -> $right
+            {
+                $right(self.0/other.0)
+            }
+        }
+    };
+}
+
+/// A length, such as an end-to-end length or a link length.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Length(f64);
+
+/// The implemented functionality of lengths.
+impl Length
+{
+    /// The length of the value in units of m.
+    pub fn from_meters(value: f64) -> Length
+    {
+        Length(1e9*value)
+    }
+    /// The length of the value in units of μm.
+    pub fn from_micrometers(value: f64) -> Length
+    {
+        Length(1e3*value)
+    }
+    /// The length of the value in units of nm, which are those of the models.
+    pub fn from_nanometers(value: f64) -> Length
+    {
+        Length(value)
+    }
+    /// The length of the value in units of Å.
+    pub fn from_angstroms(value: f64) -> Length
+    {
+        Length(1e-1*value)
+    }
+    /// The value of the length in units of m.
+    pub fn in_meters(&self) -> f64
+    {
+        1e-9*self.0
+    }
+    /// The value of the length in units of μm.
+    pub fn in_micrometers(&self) -> f64
+    {
+        1e-3*self.0
+    }
+    /// The value of the length in units of nm, which are those of the models.
+    pub fn in_nanometers(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the length in units of Å.
+    pub fn in_angstroms(&self) -> f64
+    {
+        1e1*self.0
+    }
+}
+
+/// A force, such as one applied to the ends of a chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Force(f64);
+
+/// The implemented functionality of forces.
+impl Force
+{
+    /// The force of the value in units of N.
+    pub fn from_newtons(value: f64) -> Force
+    {
+        Force(1e-9*AVOGADRO_NUMBER*value)
+    }
+    /// The force of the value in units of nN.
+    pub fn from_nanonewtons(value: f64) -> Force
+    {
+        Force(1e-18*AVOGADRO_NUMBER*value)
+    }
+    /// The force of the value in units of pN.
+    pub fn from_piconewtons(value: f64) -> Force
+    {
+        Force(1e-21*AVOGADRO_NUMBER*value)
+    }
+    /// The force of the value in units of J/(mol⋅nm), which are those of the models.
+    pub fn from_molar(value: f64) -> Force
+    {
+        Force(value)
+    }
+    /// The force of the value in the units of energy per nm of the physical constants, which are those of a model initialized with them.
+    pub fn from_constants(value: f64, constants: &Constants) -> Force
+    {
+        Force(constants.energy_unit*value)
+    }
+    /// The force of the value in units of the thermal energy per nm at the temperature, given by the Boltzmann constant of the physical constants.
+    pub fn from_thermal(value: f64, temperature: &Temperature, constants: &Constants) -> Force
+    {
+        Force(constants.energy_unit*constants.boltzmann_constant*temperature.0*value)
+    }
+    /// The value of the force in units of N.
+    pub fn in_newtons(&self) -> f64
+    {
+        1e9*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the force in units of nN.
+    pub fn in_nanonewtons(&self) -> f64
+    {
+        1e18*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the force in units of pN.
+    pub fn in_piconewtons(&self) -> f64
+    {
+        1e21*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the force in units of J/(mol⋅nm), which are those of the models.
+    pub fn in_molar(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the force in the units of energy per nm of the physical constants, which are those of a model initialized with them.
+    pub fn in_constants(&self, constants: &Constants) -> f64
+    {
+        self.0/constants.energy_unit
+    }
+    /// The value of the force in units of the thermal energy per nm at the temperature, given by the Boltzmann constant of the physical constants.
+    pub fn in_thermal(&self, temperature: &Temperature, constants: &Constants) -> f64
+    {
+        self.0/constants.energy_unit/constants.boltzmann_constant/temperature.0
+    }
+}
+
+/// An energy, such as a free energy of a chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Energy(f64);
+
+/// The implemented functionality of energies.
+impl Energy
+{
+    /// The energy of the value in units of J.
+    pub fn from_joules(value: f64) -> Energy
+    {
+        Energy(AVOGADRO_NUMBER*value)
+    }
+    /// The energy of the value in units of zJ.
+    pub fn from_zeptojoules(value: f64) -> Energy
+    {
+        Energy(1e-21*AVOGADRO_NUMBER*value)
+    }
+    /// The energy of the value in units of pN⋅nm.
+    pub fn from_piconewton_nanometers(value: f64) -> Energy
+    {
+        Energy(1e-21*AVOGADRO_NUMBER*value)
+    }
+    /// The energy of the value in units of kJ/mol.
+    pub fn from_kilojoules_per_mole(value: f64) -> Energy
+    {
+        Energy(1e3*value)
+    }
+    /// The energy of the value in units of J/mol, which are those of the models.
+    pub fn from_molar(value: f64) -> Energy
+    {
+        Energy(value)
+    }
+    /// The energy of the value in the units of energy of the physical constants, which are those of a model initialized with them.
+    pub fn from_constants(value: f64, constants: &Constants) -> Energy
+    {
+        Energy(constants.energy_unit*value)
+    }
+    /// The energy of the value in units of the thermal energy at the temperature, given by the Boltzmann constant of the physical constants.
+    pub fn from_thermal(value: f64, temperature: &Temperature, constants: &Constants) -> Energy
+    {
+        Energy(constants.energy_unit*constants.boltzmann_constant*temperature.0*value)
+    }
+    /// The value of the energy in units of J.
+    pub fn in_joules(&self) -> f64
+    {
+        self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the energy in units of zJ.
+    pub fn in_zeptojoules(&self) -> f64
+    {
+        1e21*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the energy in units of pN⋅nm.
+    pub fn in_piconewton_nanometers(&self) -> f64
+    {
+        1e21*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the energy in units of kJ/mol.
+    pub fn in_kilojoules_per_mole(&self) -> f64
+    {
+        1e-3*self.0
+    }
+    /// The value of the energy in units of J/mol, which are those of the models.
+    pub fn in_molar(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the energy in the units of energy of the physical constants, which are those of a model initialized with them.
+    pub fn in_constants(&self, constants: &Constants) -> f64
+    {
+        self.0/constants.energy_unit
+    }
+    /// The value of the energy in units of the thermal energy at the temperature, given by the Boltzmann constant of the physical constants.
+    pub fn in_thermal(&self, temperature: &Temperature, constants: &Constants) -> f64
+    {
+        self.0/constants.energy_unit/constants.boltzmann_constant/temperature.0
+    }
+}
+
+/// A stiffness, such as that of a link or of the potential of an instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stiffness(f64);
+
+/// The implemented functionality of stiffnesses.
+impl Stiffness
+{
+    /// The stiffness of the value in units of N/m.
+    pub fn from_newtons_per_meter(value: f64) -> Stiffness
+    {
+        Stiffness(1e-18*AVOGADRO_NUMBER*value)
+    }
+    /// The stiffness of the value in units of pN/nm.
+    pub fn from_piconewtons_per_nanometer(value: f64) -> Stiffness
+    {
+        Stiffness(1e-21*AVOGADRO_NUMBER*value)
+    }
+    /// The stiffness of the value in units of J/(mol⋅nm²), which are those of the models.
+    pub fn from_molar(value: f64) -> Stiffness
+    {
+        Stiffness(value)
+    }
+    /// The stiffness of the value in the units of energy per nm² of the physical constants, which are those of a model initialized with them.
+    pub fn from_constants(value: f64, constants: &Constants) -> Stiffness
+    {
+        Stiffness(constants.energy_unit*value)
+    }
+    /// The value of the stiffness in units of N/m.
+    pub fn in_newtons_per_meter(&self) -> f64
+    {
+        1e18*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the stiffness in units of pN/nm.
+    pub fn in_piconewtons_per_nanometer(&self) -> f64
+    {
+        1e21*self.0/AVOGADRO_NUMBER
+    }
+    /// The value of the stiffness in units of J/(mol⋅nm²), which are those of the models.
+    pub fn in_molar(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the stiffness in the units of energy per nm² of the physical constants, which are those of a model initialized with them.
+    pub fn in_constants(&self, constants: &Constants) -> f64
+    {
+        self.0/constants.energy_unit
+    }
+}
+
+/// An absolute temperature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Temperature(f64);
+
+/// The implemented functionality of temperatures.
+impl Temperature
+{
+    /// The temperature of the value in units of K, which are those of the models.
+    pub fn from_kelvin(value: f64) -> Temperature
+    {
+        Temperature(value)
+    }
+    /// The temperature of the value in degrees Celsius.
+    pub fn from_celsius(value: f64) -> Temperature
+    {
+        Temperature(value + 273.15)
+    }
+    /// The value of the temperature in units of K, which are those of the models.
+    pub fn in_kelvin(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the temperature in degrees Celsius.
+    pub fn in_celsius(&self) -> f64
+    {
+        self.0 - 273.15
+    }
+    /// The thermal energy at the temperature.
+    pub fn thermal_energy(&self) -> Energy
+    {
+        Energy(BOLTZMANN_CONSTANT*self.0)
+    }
+}
+
+/// A molar mass, such as that of a hinge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mass(f64);
+
+/// The implemented functionality of molar masses.
+impl Mass
+{
+    /// The molar mass of the value in units of kg/mol, which are those of the models.
+    pub fn from_kilograms_per_mole(value: f64) -> Mass
+    {
+        Mass(value)
+    }
+    /// The molar mass of the value in units of g/mol, or equivalently the mass of a molecule in Da.
+    pub fn from_grams_per_mole(value: f64) -> Mass
+    {
+        Mass(1e-3*value)
+    }
+    /// The value of the molar mass in units of kg/mol, which are those of the models.
+    pub fn in_kilograms_per_mole(&self) -> f64
+    {
+        self.0
+    }
+    /// The value of the molar mass in units of g/mol, or equivalently the mass of a molecule in Da.
+    pub fn in_grams_per_mole(&self) -> f64
+    {
+        1e3*self.0
+    }
+}
+
+quantity!(Length);
+quantity!(Force);
+quantity!(Energy);
+quantity!(Stiffness);
+quantity!(Temperature);
+quantity!(Mass);
+product!(Force * Length = Energy);
+product!(Stiffness * Length = Force);
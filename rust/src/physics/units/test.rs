@@ -0,0 +1,76 @@
+#![cfg(test)]
+use super::*;
+use crate::physics::Constants;
+static REL_TOL: f64 = 1e-12;
+fn close(value: f64, reference: f64) -> bool
+{
+    (value/reference - 1.0).abs() <= REL_TOL
+}
+#[test]
+fn round_trips()
+{
+    let value = 1.234;
+    let temperature = Temperature::from_kelvin(310.0);
+    assert!(close(Length::from_meters(value).in_meters(), value));
+    assert!(close(Length::from_micrometers(value).in_micrometers(), value));
+    assert!(close(Length::from_angstroms(value).in_angstroms(), value));
+    assert!(close(Force::from_newtons(value).in_newtons(), value));
+    assert!(close(Force::from_nanonewtons(value).in_nanonewtons(), value));
+    assert!(close(Force::from_piconewtons(value).in_piconewtons(), value));
+    assert!(close(Force::from_thermal(value, &temperature, &Constants::default()).in_thermal(&temperature, &Constants::default()), value));
+    assert!(close(Energy::from_joules(value).in_joules(), value));
+    assert!(close(Energy::from_zeptojoules(value).in_zeptojoules(), value));
+    assert!(close(Energy::from_kilojoules_per_mole(value).in_kilojoules_per_mole(), value));
+    assert!(close(Energy::from_thermal(value, &temperature, &Constants::default()).in_thermal(&temperature, &Constants::default()), value));
+    assert!(close(Stiffness::from_newtons_per_meter(value).in_newtons_per_meter(), value));
+    assert!(close(Stiffness::from_piconewtons_per_nanometer(value).in_piconewtons_per_nanometer(), value));
+    assert!(close(Temperature::from_celsius(value).in_celsius(), value));
+    assert!(close(Mass::from_grams_per_mole(value).in_grams_per_mole(), value));
+}
+#[test]
+fn conversions()
+{
+    assert!(close(Length::from_nanometers(1.0).in_angstroms(), 1e1));
+    assert!(close(Force::from_piconewtons(1.0).in_molar(), 602.214076));
+    assert!(close(Force::from_nanonewtons(1.0).in_piconewtons(), 1e3));
+    assert!(close(Energy::from_piconewton_nanometers(1.0).in_zeptojoules(), 1.0));
+    assert!(close(Stiffness::from_newtons_per_meter(1.0).in_piconewtons_per_nanometer(), 1e3));
+    assert!(close(Temperature::from_celsius(25.0).in_kelvin(), 298.15));
+    assert!(close(Mass::from_grams_per_mole(18.0).in_kilograms_per_mole(), 1.8e-2));
+    assert!((Temperature::from_kelvin(300.0).thermal_energy().in_piconewton_nanometers() - 4.142).abs() <= 1e-3);
+}
+#[test]
+fn constants()
+{
+    let value = 1.234;
+    let temperature = Temperature::from_kelvin(310.0);
+    for constants in [Constants::molar(), Constants::molecular(), Constants::reduced(&310.0)]
+    {
+        assert!(close(Force::from_constants(value, &constants).in_constants(&constants), value));
+        assert!(close(Energy::from_constants(value, &constants).in_constants(&constants), value));
+        assert!(close(Stiffness::from_constants(value, &constants).in_constants(&constants), value));
+        assert!(close(Energy::from_thermal(value, &temperature, &constants).in_molar(), value*temperature.thermal_energy().in_molar()));
+        assert!(close(Force::from_thermal(value, &temperature, &constants).in_thermal(&temperature, &constants), value));
+    }
+    assert!(close(Force::from_constants(1.0, &Constants::molecular()).in_piconewtons(), 1.0));
+    assert!(close(Energy::from_constants(1.0, &Constants::molecular()).in_piconewton_nanometers(), 1.0));
+    assert!(close(Energy::from_constants(1.0, &Constants::reduced(&310.0)).in_molar(), temperature.thermal_energy().in_molar()));
+}
+#[test]
+fn dimensions()
+{
+    let force = Force::from_piconewtons(10.0);
+    let length = Length::from_nanometers(2.0);
+    let stiffness = Stiffness::from_piconewtons_per_nanometer(5.0);
+    assert!(close((force*length).in_piconewton_nanometers(), 20.0));
+    assert!(close((length*force).in_piconewton_nanometers(), 20.0));
+    assert!(close((force*length/length).in_piconewtons(), 10.0));
+    assert!(close((force*length/force).in_nanometers(), 2.0));
+    assert!(close((stiffness*length).in_piconewtons(), 10.0));
+    assert!(close((force/length).in_piconewtons_per_nanometer(), 5.0));
+    assert!(close((force/stiffness).in_nanometers(), 2.0));
+    assert!(close(force/Force::from_piconewtons(4.0), 2.5));
+    assert!(close((force + 2.0*force - force/2.0).in_piconewtons(), 25.0));
+    assert!(close((-length*3.0).in_nanometers(), -6.0));
+    assert!(Length::from_nanometers(1.0) < Length::from_angstroms(11.0));
+}